@@ -0,0 +1,9 @@
+//! A module for responding to Actions sent by WorkOS.
+//!
+//! [WorkOS Docs: Actions](https://workos.com/docs/authkit/actions)
+
+mod action;
+mod types;
+
+pub use action::*;
+pub use types::*;
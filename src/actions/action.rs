@@ -0,0 +1,255 @@
+use std::time::Duration;
+
+use chrono::Utc;
+use thiserror::Error;
+
+use crate::Timestamp;
+use crate::actions::{
+    ActionContext, ActionResponse, ActionResponseObject, ActionResponsePayload, ActionVerdict,
+};
+use crate::webhooks::{VerifyWebhookError, Webhook, WebhookSecret, WebhookSignature};
+
+/// An error returned from [`Action::construct_context`].
+#[derive(Debug, Error)]
+pub enum ConstructActionContextError {
+    /// The action could not be verified.
+    #[error(transparent)]
+    Verification(#[from] VerifyWebhookError),
+
+    /// The payload could not be deserialized into an [`ActionContext`].
+    #[error("invalid payload")]
+    InvalidPayload(#[from] serde_json::Error),
+}
+
+/// Verification of and responses to actions sent by WorkOS.
+///
+/// Actions are signed the same way as webhooks, but use their own secret.
+///
+/// [WorkOS Docs: Actions](https://workos.com/docs/authkit/actions)
+pub struct Action;
+
+impl Action {
+    /// The default tolerance for the timestamp of an action signature.
+    pub const DEFAULT_TOLERANCE: Duration = Duration::from_secs(30);
+
+    /// Verifies that the payload was signed by WorkOS and deserializes it into an [`ActionContext`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos_sdk::actions::*;
+    /// # use workos_sdk::webhooks::WebhookSecret;
+    /// # fn run(payload: &str, signature_header: &str) -> Result<(), ConstructActionContextError> {
+    /// let context = Action::construct_context(
+    ///     payload,
+    ///     signature_header,
+    ///     &WebhookSecret::from("action_secret_123456789"),
+    ///     Action::DEFAULT_TOLERANCE,
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn construct_context(
+        payload: &str,
+        signature_header: &str,
+        secret: &WebhookSecret,
+        tolerance: Duration,
+    ) -> Result<ActionContext, ConstructActionContextError> {
+        Webhook::verify(payload, signature_header, secret, tolerance)?;
+
+        Ok(serde_json::from_str(payload)?)
+    }
+
+    /// Builds a signed response that allows the action.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos_sdk::actions::*;
+    /// # use workos_sdk::webhooks::WebhookSecret;
+    /// # fn run(context: &ActionContext) -> Result<(), serde_json::Error> {
+    /// let response = Action::allow(context, &WebhookSecret::from("action_secret_123456789"));
+    /// let body = serde_json::to_string(&response)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn allow(context: &ActionContext, secret: &WebhookSecret) -> ActionResponse {
+        Self::respond(context, ActionVerdict::Allow, None, secret)
+    }
+
+    /// Builds a signed response that denies the action, showing the error message to the user.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos_sdk::actions::*;
+    /// # use workos_sdk::webhooks::WebhookSecret;
+    /// # fn run(context: &ActionContext) -> Result<(), serde_json::Error> {
+    /// let response = Action::deny(
+    ///     context,
+    ///     "Sign ups from this domain are not allowed.",
+    ///     &WebhookSecret::from("action_secret_123456789"),
+    /// );
+    /// let body = serde_json::to_string(&response)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn deny(
+        context: &ActionContext,
+        error_message: impl Into<String>,
+        secret: &WebhookSecret,
+    ) -> ActionResponse {
+        Self::respond(
+            context,
+            ActionVerdict::Deny,
+            Some(error_message.into()),
+            secret,
+        )
+    }
+
+    fn respond(
+        context: &ActionContext,
+        verdict: ActionVerdict,
+        error_message: Option<String>,
+        secret: &WebhookSecret,
+    ) -> ActionResponse {
+        let timestamp = Timestamp(Utc::now().fixed_offset());
+
+        let payload = ActionResponsePayload {
+            timestamp: timestamp.0.timestamp_millis(),
+            verdict,
+            error_message,
+        };
+
+        let serialized =
+            serde_json::to_string(&payload).expect("action response payload is serializable");
+        let signature = WebhookSignature::compute(&timestamp, &serialized, secret);
+
+        ActionResponse {
+            object: match context {
+                ActionContext::Authentication(_) => ActionResponseObject::Authentication,
+                ActionContext::UserRegistration(_) => ActionResponseObject::UserRegistration,
+            },
+            payload,
+            signature: hex::encode(signature.signature),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+    use serde_json::json;
+
+    use crate::actions::ActionContextId;
+    use crate::webhooks::SignedWebhook;
+
+    use super::*;
+
+    fn payload() -> String {
+        json!({
+            "id": "01JATCMZJY26PQ59XT9BNT0FNN",
+            "object": "user_registration_action_context",
+            "user_data": {
+                "object": "user_data",
+                "email": "marcelina@example.com",
+                "first_name": "Marcelina",
+                "last_name": "Davis"
+            },
+            "invitation": null,
+            "ip_address": "192.0.2.1",
+            "user_agent": "Mozilla/5.0"
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn it_constructs_a_context_from_a_verified_payload() {
+        let secret = WebhookSecret::from("action_secret_123456789");
+        let signed = SignedWebhook::from_json(payload(), &secret);
+
+        let context = Action::construct_context(
+            &signed.body,
+            &signed.signature_header,
+            &secret,
+            Action::DEFAULT_TOLERANCE,
+        )
+        .unwrap();
+
+        assert_matches!(
+            context,
+            ActionContext::UserRegistration(ref context)
+                if context.id == ActionContextId::from("01JATCMZJY26PQ59XT9BNT0FNN")
+                    && context.user_data.email == "marcelina@example.com"
+        )
+    }
+
+    #[test]
+    fn it_rejects_a_payload_signed_with_a_different_secret() {
+        let signed = SignedWebhook::from_json(payload(), &WebhookSecret::from("another_secret"));
+
+        let result = Action::construct_context(
+            &signed.body,
+            &signed.signature_header,
+            &WebhookSecret::from("action_secret_123456789"),
+            Action::DEFAULT_TOLERANCE,
+        );
+
+        assert_matches!(
+            result,
+            Err(ConstructActionContextError::Verification(
+                VerifyWebhookError::SignatureMismatch
+            ))
+        )
+    }
+
+    #[test]
+    fn it_builds_a_signed_deny_response() {
+        let secret = WebhookSecret::from("action_secret_123456789");
+        let context: ActionContext = serde_json::from_str(&payload()).unwrap();
+
+        let response = Action::deny(&context, "Not allowed", &secret);
+
+        assert_eq!(response.object, ActionResponseObject::UserRegistration);
+        assert_eq!(response.payload.verdict, ActionVerdict::Deny);
+
+        let serialized = serde_json::to_string(&response.payload).unwrap();
+        let signature_header = format!(
+            "t={}, v1={}",
+            response.payload.timestamp, response.signature
+        );
+        assert_matches!(
+            Webhook::verify(
+                &serialized,
+                &signature_header,
+                &secret,
+                Action::DEFAULT_TOLERANCE
+            ),
+            Ok(())
+        );
+
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&serialized).unwrap(),
+            json!({
+                "timestamp": response.payload.timestamp,
+                "verdict": "Deny",
+                "error_message": "Not allowed"
+            })
+        )
+    }
+
+    #[test]
+    fn it_omits_the_error_message_from_an_allow_response() {
+        let context: ActionContext = serde_json::from_str(&payload()).unwrap();
+
+        let response = Action::allow(&context, &WebhookSecret::from("action_secret_123456789"));
+
+        assert_eq!(
+            serde_json::to_value(&response.payload).unwrap(),
+            json!({
+                "timestamp": response.payload.timestamp,
+                "verdict": "Allow"
+            })
+        )
+    }
+}
@@ -0,0 +1,5 @@
+mod action_context;
+mod action_response;
+
+pub use action_context::*;
+pub use action_response::*;
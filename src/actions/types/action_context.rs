@@ -0,0 +1,88 @@
+use std::net::IpAddr;
+
+use derive_more::{Deref, Display, From};
+use serde::{Deserialize, Serialize};
+
+use crate::organizations::Organization;
+use crate::user_management::{Invitation, OrganizationMembership, User};
+
+/// The ID of an [`ActionContext`].
+#[derive(
+    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[from(forward)]
+pub struct ActionContextId(String);
+
+/// The context of an authentication action.
+///
+/// [WorkOS Docs: Authentication actions](https://workos.com/docs/authkit/actions/action-types/authentication-actions)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthenticationActionContext {
+    /// The unique ID of the action.
+    pub id: ActionContextId,
+
+    /// The user that is authenticating.
+    pub user: User,
+
+    /// The organization the user is authenticating into, if any.
+    pub organization: Option<Organization>,
+
+    /// The organization membership of the user, if any.
+    pub organization_membership: Option<OrganizationMembership>,
+
+    /// The IP address of the request.
+    pub ip_address: Option<IpAddr>,
+
+    /// The user agent of the request.
+    pub user_agent: Option<String>,
+
+    /// The issuer of the authentication, if any.
+    pub issuer: Option<String>,
+}
+
+/// The data of the user that is registering.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserRegistrationData {
+    /// The email address of the user.
+    pub email: String,
+
+    /// The first name of the user.
+    pub first_name: Option<String>,
+
+    /// The last name of the user.
+    pub last_name: Option<String>,
+}
+
+/// The context of a user registration action.
+///
+/// [WorkOS Docs: User registration actions](https://workos.com/docs/authkit/actions/action-types/user-registration-actions)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserRegistrationActionContext {
+    /// The unique ID of the action.
+    pub id: ActionContextId,
+
+    /// The data of the user that is registering.
+    pub user_data: UserRegistrationData,
+
+    /// The invitation the user is accepting, if any.
+    pub invitation: Option<Invitation>,
+
+    /// The IP address of the request.
+    pub ip_address: Option<IpAddr>,
+
+    /// The user agent of the request.
+    pub user_agent: Option<String>,
+}
+
+/// The context of an action sent by WorkOS.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "object")]
+pub enum ActionContext {
+    /// An authentication action.
+    #[serde(rename = "authentication_action_context")]
+    Authentication(AuthenticationActionContext),
+
+    /// A user registration action.
+    #[serde(rename = "user_registration_action_context")]
+    UserRegistration(UserRegistrationActionContext),
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+/// The verdict of an action.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ActionVerdict {
+    /// The action is allowed.
+    Allow,
+
+    /// The action is denied.
+    Deny,
+}
+
+/// The type of an [`ActionResponse`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ActionResponseObject {
+    /// A response to an authentication action.
+    #[serde(rename = "authentication_action_response")]
+    Authentication,
+
+    /// A response to a user registration action.
+    #[serde(rename = "user_registration_action_response")]
+    UserRegistration,
+}
+
+/// The payload of an [`ActionResponse`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionResponsePayload {
+    /// The timestamp of the response, in milliseconds since the Unix epoch.
+    pub timestamp: i64,
+
+    /// The verdict of the action.
+    pub verdict: ActionVerdict,
+
+    /// The error message shown to the user when the action is denied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+}
+
+/// A signed response to an action.
+///
+/// [WorkOS Docs: Responding to actions](https://workos.com/docs/authkit/actions/responding-to-actions)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionResponse {
+    /// The type of the response.
+    pub object: ActionResponseObject,
+
+    /// The payload of the response.
+    pub payload: ActionResponsePayload,
+
+    /// The hex-encoded signature of the payload.
+    pub signature: String,
+}
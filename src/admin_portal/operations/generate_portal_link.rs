@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::admin_portal::AdminPortal;
 use crate::organizations::OrganizationId;
-use crate::{ResponseExt, WorkOsResult};
+use crate::{RequestBuilderExt, ResponseExt, WorkOsResult};
 
 /// The intent of an Admin Portal session.
 #[derive(Clone, Copy, Debug, Serialize)]
@@ -106,7 +106,7 @@ impl GeneratePortalLink for AdminPortal<'_> {
             .post(url)
             .bearer_auth(self.workos.key())
             .json(&params)
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
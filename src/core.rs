@@ -1,7 +1,19 @@
+mod deadline;
 mod error;
+mod error_response;
+mod paginate;
+mod redact;
+mod request;
 mod response;
 mod types;
+mod wire_format;
 
+pub use deadline::*;
 pub use error::*;
+pub use error_response::*;
+pub use paginate::*;
+pub(crate) use redact::*;
+pub(crate) use request::*;
 pub(crate) use response::*;
 pub use types::*;
+pub use wire_format::*;
@@ -0,0 +1,115 @@
+use std::pin::pin;
+use std::time::{Duration, Instant};
+
+use futures_util::future::{Either, select};
+
+use crate::{WorkOsError, WorkOsResult};
+
+/// An extension trait to bound WorkOS operations by a deadline or an external cancellation.
+///
+/// The whole operation is bounded, so a latency budget covers every request it sends.
+///
+/// # Examples
+///
+/// ```
+/// # use std::time::Duration;
+/// # use workos_sdk::WorkOsResult;
+/// # use workos_sdk::organizations::*;
+/// use workos_sdk::{ApiKey, WorkOs, WorkOsFutureExt};
+///
+/// # async fn run() -> WorkOsResult<(), GetOrganizationError> {
+/// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+///
+/// let organization = workos
+///     .organizations()
+///     .get_organization(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+///     .timeout(Duration::from_millis(500))
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait WorkOsFutureExt<T, E>: Future<Output = WorkOsResult<T, E>> + Sized {
+    /// Fails the operation with [`WorkOsError::DeadlineExceeded`] if it has not completed
+    /// by the provided deadline.
+    fn deadline(self, deadline: Instant) -> impl Future<Output = WorkOsResult<T, E>> {
+        async move {
+            tokio::time::timeout_at(deadline.into(), self)
+                .await
+                .unwrap_or(Err(WorkOsError::DeadlineExceeded))
+        }
+    }
+
+    /// Fails the operation with [`WorkOsError::DeadlineExceeded`] if it has not completed
+    /// within the provided duration.
+    fn timeout(self, timeout: Duration) -> impl Future<Output = WorkOsResult<T, E>> {
+        async move {
+            tokio::time::timeout(timeout, self)
+                .await
+                .unwrap_or(Err(WorkOsError::DeadlineExceeded))
+        }
+    }
+
+    /// Fails the operation with [`WorkOsError::Cancelled`] if the provided cancellation
+    /// future, e.g. `CancellationToken::cancelled`, completes first.
+    fn cancel_on<C>(self, cancellation: C) -> impl Future<Output = WorkOsResult<T, E>>
+    where
+        C: Future<Output = ()>,
+    {
+        async move {
+            match select(pin!(self), pin!(cancellation)).await {
+                Either::Left((result, _)) => result,
+                Either::Right(((), _)) => Err(WorkOsError::Cancelled),
+            }
+        }
+    }
+}
+
+impl<T, E, F> WorkOsFutureExt<T, E> for F where F: Future<Output = WorkOsResult<T, E>> {}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use matches::assert_matches;
+
+    use crate::{WorkOsError, WorkOsFutureExt, WorkOsResult};
+
+    async fn slow_operation() -> WorkOsResult<(), ()> {
+        tokio::time::sleep(Duration::from_secs(60)).await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_fails_operations_exceeding_the_deadline() {
+        let result = slow_operation()
+            .deadline(Instant::now() + Duration::from_millis(10))
+            .await;
+
+        assert_matches!(result, Err(WorkOsError::DeadlineExceeded));
+
+        let result = slow_operation().timeout(Duration::from_millis(10)).await;
+
+        assert_matches!(result, Err(WorkOsError::DeadlineExceeded));
+    }
+
+    #[tokio::test]
+    async fn it_returns_the_result_of_operations_completing_in_time() {
+        let result = async { WorkOsResult::<_, ()>::Ok(42) }
+            .timeout(Duration::from_secs(60))
+            .await;
+
+        assert_matches!(result, Ok(42));
+    }
+
+    #[tokio::test]
+    async fn it_fails_cancelled_operations() {
+        let (sender, receiver) = tokio::sync::oneshot::channel::<()>();
+
+        let operation = tokio::spawn(slow_operation().cancel_on(async {
+            let _ = receiver.await;
+        }));
+        sender.send(()).unwrap();
+
+        assert_matches!(operation.await.unwrap(), Err(WorkOsError::Cancelled));
+    }
+}
@@ -1,4 +1,8 @@
+use std::fmt::{self, Display};
+use std::time::Duration;
+
 use thiserror::Error;
+use url::Url;
 
 /// A WorkOS SDK error.
 #[derive(Debug, Error)]
@@ -11,6 +15,23 @@ pub enum WorkOsError<E> {
     #[error("unauthorized")]
     Unauthorized,
 
+    /// A not found response was received from the WorkOS API.
+    #[error("not found")]
+    NotFound {
+        /// The error body of the response, if it was JSON.
+        body: Option<serde_json::Value>,
+    },
+
+    /// A rate limited response was received from the WorkOS API.
+    #[error("rate limited")]
+    RateLimited {
+        /// How long to wait before retrying, from the `Retry-After` header.
+        retry_after: Option<Duration>,
+
+        /// The error body of the response, if it was JSON.
+        body: Option<serde_json::Value>,
+    },
+
     /// An error occurred while parsing a URL.
     #[error("URL parse error")]
     UrlParseError(#[from] url::ParseError),
@@ -26,6 +47,37 @@ pub enum WorkOsError<E> {
     /// The API responded with an error.
     #[error("API error")]
     ApiError(serde_json::Value),
+
+    /// The client is in dry-run mode and the mutating request was not sent.
+    #[error("dry run: {0} not sent")]
+    DryRun(Box<DryRunRequest>),
+
+    /// The deadline of the operation passed before it completed.
+    #[error("deadline exceeded")]
+    DeadlineExceeded,
+
+    /// The operation was cancelled before it completed.
+    #[error("cancelled")]
+    Cancelled,
+}
+
+/// A request that was not sent because the client is in dry-run mode.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DryRunRequest {
+    /// The HTTP method of the request.
+    pub method: String,
+
+    /// The URL of the request, with sensitive query parameters and path segments redacted.
+    pub url: Url,
+
+    /// The body of the request, with sensitive fields redacted.
+    pub body: Option<serde_json::Value>,
+}
+
+impl Display for DryRunRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.method, self.url)
+    }
 }
 
 /// A WorkOS SDK result.
@@ -0,0 +1,193 @@
+use std::time::Duration;
+
+use reqwest::StatusCode;
+
+use crate::WorkOsError;
+
+/// A client-facing HTTP response for a [`WorkOsError`].
+///
+/// The message never includes details of the underlying error, so it is safe to return to
+/// clients of a service that proxies WorkOS operations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorResponse {
+    /// The HTTP status to respond with.
+    pub status: StatusCode,
+
+    /// The message to respond with.
+    pub message: &'static str,
+
+    /// How long the client should wait before retrying, for the `Retry-After` header.
+    pub retry_after: Option<Duration>,
+}
+
+impl ErrorResponse {
+    fn new(status: StatusCode, message: &'static str) -> Self {
+        Self {
+            status,
+            message,
+            retry_after: None,
+        }
+    }
+}
+
+impl<E> WorkOsError<E> {
+    /// Returns the [`ErrorResponse`] a downstream service should respond with.
+    ///
+    /// - Unauthorized responses map to `401 Unauthorized`.
+    /// - Not found responses map to `404 Not Found`.
+    /// - Rate limited responses map to `429 Too Many Requests`, forwarding the `Retry-After` delay.
+    /// - Exceeded deadlines map to `504 Gateway Timeout`.
+    /// - Any other error maps to `502 Bad Gateway`.
+    pub fn to_error_response(&self) -> ErrorResponse {
+        match self {
+            WorkOsError::Unauthorized => {
+                ErrorResponse::new(StatusCode::UNAUTHORIZED, "Unauthorized.")
+            }
+            WorkOsError::NotFound { .. } => ErrorResponse::new(StatusCode::NOT_FOUND, "Not found."),
+            WorkOsError::RateLimited { retry_after, .. } => ErrorResponse {
+                retry_after: *retry_after,
+                ..ErrorResponse::new(StatusCode::TOO_MANY_REQUESTS, "Too many requests.")
+            },
+            WorkOsError::DeadlineExceeded => {
+                ErrorResponse::new(StatusCode::GATEWAY_TIMEOUT, "Gateway timeout.")
+            }
+            WorkOsError::RequestError(err) => match err.status() {
+                Some(StatusCode::UNAUTHORIZED) => {
+                    ErrorResponse::new(StatusCode::UNAUTHORIZED, "Unauthorized.")
+                }
+                Some(StatusCode::NOT_FOUND) => {
+                    ErrorResponse::new(StatusCode::NOT_FOUND, "Not found.")
+                }
+                Some(StatusCode::TOO_MANY_REQUESTS) => {
+                    ErrorResponse::new(StatusCode::TOO_MANY_REQUESTS, "Too many requests.")
+                }
+                _ => ErrorResponse::new(StatusCode::BAD_GATEWAY, "Bad gateway."),
+            },
+            _ => ErrorResponse::new(StatusCode::BAD_GATEWAY, "Bad gateway."),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+    use std::time::Duration;
+
+    use matches::assert_matches;
+    use reqwest::StatusCode;
+    use serde_json::json;
+
+    use crate::organizations::{
+        CreateOrganization, CreateOrganizationParams, GetOrganization, OrganizationId,
+    };
+    use crate::{ApiKey, ErrorResponse, WorkOs, WorkOsError};
+
+    #[test]
+    fn it_maps_unauthorized_errors() {
+        assert_eq!(
+            WorkOsError::<()>::Unauthorized.to_error_response(),
+            ErrorResponse {
+                status: StatusCode::UNAUTHORIZED,
+                message: "Unauthorized.",
+                retry_after: None,
+            }
+        )
+    }
+
+    #[test]
+    fn it_maps_exceeded_deadlines_to_gateway_timeout() {
+        assert_eq!(
+            WorkOsError::<()>::DeadlineExceeded
+                .to_error_response()
+                .status,
+            StatusCode::GATEWAY_TIMEOUT
+        )
+    }
+
+    #[test]
+    fn it_maps_other_errors_to_bad_gateway() {
+        assert_eq!(
+            WorkOsError::<()>::ApiError(json!({ "message": "Internal error" }))
+                .to_error_response()
+                .status,
+            StatusCode::BAD_GATEWAY
+        );
+        assert_eq!(
+            WorkOsError::Operation(()).to_error_response().status,
+            StatusCode::BAD_GATEWAY
+        );
+    }
+
+    #[tokio::test]
+    async fn it_maps_not_found_responses() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        server
+            .mock("GET", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(json!({ "message": "Not Found" }).to_string())
+            .create_async()
+            .await;
+
+        let error = workos
+            .organizations()
+            .get_organization(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+            .await
+            .unwrap_err();
+
+        assert_matches!(
+            error,
+            WorkOsError::NotFound { body: Some(ref body) } if *body == json!({ "message": "Not Found" })
+        );
+        assert_eq!(
+            error.to_error_response(),
+            ErrorResponse {
+                status: StatusCode::NOT_FOUND,
+                message: "Not found.",
+                retry_after: None,
+            }
+        )
+    }
+
+    #[tokio::test]
+    async fn it_maps_rate_limited_responses_with_the_retry_after_delay() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        server
+            .mock("POST", "/organizations")
+            .with_status(429)
+            .with_header("retry-after", "30")
+            .create_async()
+            .await;
+
+        let error = workos
+            .organizations()
+            .create_organization(&CreateOrganizationParams {
+                name: "Foo Corp",
+                allow_profiles_outside_organization: None,
+                domains: HashSet::from(["foo-corp.com"]),
+            })
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            error.to_error_response(),
+            ErrorResponse {
+                status: StatusCode::TOO_MANY_REQUESTS,
+                message: "Too many requests.",
+                retry_after: Some(Duration::from_secs(30)),
+            }
+        )
+    }
+}
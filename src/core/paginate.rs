@@ -0,0 +1,233 @@
+use futures_util::{Stream, TryStreamExt, stream};
+
+use crate::{PageCursor, PaginatedList, WorkOsError, WorkOsResult};
+
+/// Returns a stream of every record of a paginated list, starting at the provided cursor.
+///
+/// The list is walked forwards from [`PageCursor::First`] and [`PageCursor::After`], following
+/// the `after` cursors. It is walked backwards from [`PageCursor::Before`], following the
+/// `before` cursors, in which case the records of each page are yielded in reverse so the
+/// stream stays in walking order. With the default descending order, walking backwards yields
+/// the records from oldest to newest.
+///
+/// Pages are only retrieved once the records of the previous page have been consumed.
+///
+/// # Examples
+///
+/// ```
+/// # use workos_sdk::WorkOsResult;
+/// # use workos_sdk::user_management::*;
+/// use futures_util::TryStreamExt;
+/// use workos_sdk::{ApiKey, PageCursor, PaginationParams, WorkOs, paginate};
+///
+/// # async fn run() -> WorkOsResult<(), ListUsersError> {
+/// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+///
+/// let users = paginate(
+///     PageCursor::Before("user_01E4ZCR3C56J083X43JQXF3JK5".to_string()),
+///     |cursor| {
+///         let workos = &workos;
+///
+///         async move {
+///             workos
+///                 .user_management()
+///                 .list_users(&ListUsersParams {
+///                     pagination: cursor.apply(PaginationParams::default()),
+///                     ..Default::default()
+///                 })
+///                 .await
+///         }
+///     },
+/// )
+/// .try_collect::<Vec<_>>()
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn paginate<T, E, F, Fut>(start: PageCursor, fetch: F) -> impl Stream<Item = WorkOsResult<T, E>>
+where
+    F: FnMut(PageCursor) -> Fut,
+    Fut: Future<Output = WorkOsResult<PaginatedList<T>, E>>,
+{
+    let backwards = matches!(start, PageCursor::Before(_));
+
+    stream::try_unfold(
+        (Some(start), fetch),
+        move |(cursor, mut fetch)| async move {
+            let Some(cursor) = cursor else {
+                return Ok::<_, WorkOsError<E>>(None);
+            };
+
+            let page = fetch(cursor).await?;
+            let (next, mut data) = if backwards {
+                (page.previous_page(), page.data)
+            } else {
+                (page.next_page(), page.data)
+            };
+
+            if backwards {
+                data.reverse();
+            }
+
+            Ok(Some((
+                stream::iter(data.into_iter().map(Ok)),
+                (next, fetch),
+            )))
+        },
+    )
+    .try_flatten()
+}
+
+#[cfg(test)]
+mod test {
+    use futures_util::TryStreamExt;
+    use mockito::Matcher;
+    use serde_json::json;
+
+    use crate::organizations::{ListOrganizations, ListOrganizationsParams, Organization};
+    use crate::{ApiKey, PaginationParams, WorkOs};
+
+    use super::*;
+
+    fn organization(id: &str) -> serde_json::Value {
+        json!({
+            "id": id,
+            "object": "organization",
+            "name": "Foo Corp",
+            "allow_profiles_outside_organization": false,
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z",
+            "domains": []
+        })
+    }
+
+    async fn list_organizations(
+        workos: &WorkOs,
+        start: PageCursor,
+    ) -> WorkOsResult<Vec<Organization>, ()> {
+        paginate(start, |cursor| async move {
+            workos
+                .organizations()
+                .list_organizations(&ListOrganizationsParams {
+                    pagination: cursor.apply(PaginationParams::default()),
+                    domains: None,
+                })
+                .await
+        })
+        .try_collect()
+        .await
+    }
+
+    #[tokio::test]
+    async fn it_walks_a_list_forwards() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        server
+            .mock("GET", "/organizations")
+            .match_query(Matcher::UrlEncoded("order".to_string(), "desc".to_string()))
+            .expect(1)
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": [organization("org_3"), organization("org_2")],
+                    "list_metadata": { "before": null, "after": "org_2" }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        server
+            .mock("GET", "/organizations")
+            .match_query(Matcher::UrlEncoded(
+                "after".to_string(),
+                "org_2".to_string(),
+            ))
+            .expect(1)
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": [organization("org_1")],
+                    "list_metadata": { "before": "org_1", "after": null }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let organizations = list_organizations(&workos, PageCursor::First)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            organizations
+                .into_iter()
+                .map(|organization| organization.id.to_string())
+                .collect::<Vec<_>>(),
+            vec!["org_3", "org_2", "org_1"]
+        );
+    }
+
+    #[tokio::test]
+    async fn it_walks_a_list_backwards() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        server
+            .mock("GET", "/organizations")
+            .match_query(Matcher::UrlEncoded(
+                "before".to_string(),
+                "org_1".to_string(),
+            ))
+            .expect(1)
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": [organization("org_3"), organization("org_2")],
+                    "list_metadata": { "before": "org_3", "after": "org_2" }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        server
+            .mock("GET", "/organizations")
+            .match_query(Matcher::UrlEncoded(
+                "before".to_string(),
+                "org_3".to_string(),
+            ))
+            .expect(1)
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": [organization("org_4")],
+                    "list_metadata": { "before": null, "after": "org_4" }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let organizations = list_organizations(&workos, PageCursor::Before("org_1".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            organizations
+                .into_iter()
+                .map(|organization| organization.id.to_string())
+                .collect::<Vec<_>>(),
+            vec!["org_2", "org_3", "org_4"]
+        );
+    }
+}
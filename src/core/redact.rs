@@ -0,0 +1,216 @@
+use reqwest::header::HeaderMap;
+use serde_json::Value;
+use url::Url;
+
+/// The value that replaces redacted fields.
+pub(crate) const REDACTED: &str = "[REDACTED]";
+
+/// Returns whether the value of the field with the provided name must not be logged.
+pub(crate) fn is_sensitive_field(name: &str) -> bool {
+    let name = name.to_lowercase();
+
+    name == "code"
+        || name == "authorization"
+        || ["password", "secret", "token", "code_verifier", "api_key"]
+            .iter()
+            .any(|sensitive| name.contains(sensitive))
+}
+
+/// Replaces the values of sensitive fields in the provided JSON value, recursively.
+pub(crate) fn redact_json(value: &mut Value) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object.iter_mut() {
+                if is_sensitive_field(key) {
+                    *value = Value::String(REDACTED.to_string());
+                } else {
+                    redact_json(value);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(redact_json),
+        _ => {}
+    }
+}
+
+/// Parses the provided request body as JSON or as a URL-encoded form, and redacts it.
+pub(crate) fn redacted_body(body: &[u8]) -> Value {
+    let mut value = serde_json::from_slice(body).unwrap_or_else(|_| {
+        Value::Object(
+            url::form_urlencoded::parse(body)
+                .map(|(key, value)| (key.into_owned(), Value::String(value.into_owned())))
+                .collect(),
+        )
+    });
+    redact_json(&mut value);
+
+    value
+}
+
+/// Returns the provided URL, with sensitive query parameters and path segments redacted.
+///
+/// A path segment is sensitive when it follows a segment with a sensitive name, e.g. the token in
+/// `/user_management/invitations/by_token/{token}`.
+pub(crate) fn redacted_url(url: &Url) -> Url {
+    let mut redacted = url.clone();
+    if let Some(segments) = url.path_segments() {
+        let mut sensitive = false;
+        let segments = segments
+            .map(|segment| {
+                let segment = if sensitive { REDACTED } else { segment };
+                sensitive = is_sensitive_field(segment);
+                segment
+            })
+            .collect::<Vec<_>>();
+
+        if let Ok(mut path) = redacted.path_segments_mut() {
+            path.clear().extend(segments);
+        }
+    }
+    if url.query().is_some() {
+        redacted
+            .query_pairs_mut()
+            .clear()
+            .extend_pairs(url.query_pairs().map(|(key, value)| {
+                if is_sensitive_field(&key) {
+                    (key, REDACTED.into())
+                } else {
+                    (key, value)
+                }
+            }));
+    }
+
+    redacted
+}
+
+/// Returns the path and query of the provided URL, redacted with [`redacted_url`].
+pub(crate) fn redacted_path(url: &Url) -> String {
+    let redacted = redacted_url(url);
+
+    match redacted.query() {
+        Some(query) => format!("{}?{}", redacted.path(), query),
+        None => redacted.path().to_string(),
+    }
+}
+
+/// Formats the provided headers, with the values of sensitive headers redacted.
+pub(crate) fn redacted_headers(headers: &HeaderMap) -> String {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            if is_sensitive_field(name.as_str()) {
+                format!("{name}: {REDACTED}")
+            } else {
+                format!("{name}: {}", value.to_str().unwrap_or("<binary>"))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn it_redacts_sensitive_fields_in_json_bodies() {
+        assert_eq!(
+            redacted_body(
+                json!({
+                    "client_id": "client_123456789",
+                    "client_secret": "sk_example_123456789",
+                    "email": "marcelina@example.com",
+                    "password": "i8uv6g34kd490s",
+                    "nested": [{ "refresh_token": "abc123", "name": "Foo Corp" }]
+                })
+                .to_string()
+                .as_bytes()
+            ),
+            json!({
+                "client_id": "client_123456789",
+                "client_secret": REDACTED,
+                "email": "marcelina@example.com",
+                "password": REDACTED,
+                "nested": [{ "refresh_token": REDACTED, "name": "Foo Corp" }]
+            })
+        )
+    }
+
+    #[test]
+    fn it_redacts_sensitive_fields_in_form_bodies() {
+        assert_eq!(
+            redacted_body(b"client_id=client_123456789&code=abc123&grant_type=authorization_code"),
+            json!({
+                "client_id": "client_123456789",
+                "code": REDACTED,
+                "grant_type": "authorization_code"
+            })
+        )
+    }
+
+    #[test]
+    fn it_redacts_sensitive_query_parameters() {
+        let url = Url::parse(
+            "https://api.workos.com/sso/authorize?client_id=client_123456789&state=abc&code=123",
+        )
+        .unwrap();
+
+        assert_eq!(
+            redacted_path(&url),
+            "/sso/authorize?client_id=client_123456789&state=abc&code=%5BREDACTED%5D"
+        );
+        assert_eq!(
+            redacted_path(&Url::parse("https://api.workos.com/organizations").unwrap()),
+            "/organizations"
+        );
+    }
+
+    #[test]
+    fn it_redacts_sensitive_path_segments() {
+        let url = Url::parse(
+            "https://api.workos.com/user_management/invitations/by_token/Z1uX3RbwcIl5fIGJJJCXXisdI",
+        )
+        .unwrap();
+
+        assert_eq!(
+            redacted_path(&url),
+            "/user_management/invitations/by_token/[REDACTED]"
+        );
+        assert_eq!(
+            redacted_path(
+                &Url::parse("https://api.workos.com/user_management/users/user_123").unwrap()
+            ),
+            "/user_management/users/user_123"
+        );
+    }
+
+    #[test]
+    fn it_redacts_sensitive_parts_of_urls() {
+        let url = Url::parse(
+            "https://api.workos.com/user_management/invitations/by_token/Z1uX3RbwcIl5fIGJJJCXXisdI?code=123",
+        )
+        .unwrap();
+
+        assert_eq!(
+            redacted_url(&url).as_str(),
+            "https://api.workos.com/user_management/invitations/by_token/[REDACTED]?code=%5BREDACTED%5D"
+        );
+    }
+
+    #[test]
+    fn it_redacts_sensitive_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "authorization",
+            "Bearer sk_example_123456789".parse().unwrap(),
+        );
+        headers.insert("content-type", "application/json".parse().unwrap());
+
+        assert_eq!(
+            redacted_headers(&headers),
+            "authorization: [REDACTED], content-type: application/json"
+        );
+    }
+}
@@ -1,3 +1,6 @@
+use std::time::Duration;
+
+use reqwest::header::RETRY_AFTER;
 use reqwest::{Response, StatusCode};
 
 use crate::{WorkOsError, WorkOsResult};
@@ -11,13 +14,32 @@ where
     fn handle_unauthorized_error<E>(self) -> WorkOsResult<Self, E>;
 
     /// Handles a generic error from the WorkOS API by converting it into a
-    /// [`WorkOsError::RequestError`] response.
+    /// [`WorkOsError::NotFound`], [`WorkOsError::RateLimited`], [`WorkOsError::ApiError`]
+    /// or [`WorkOsError::RequestError`] response.
     async fn handle_generic_error<E>(self) -> WorkOsResult<Self, E>;
 
     /// Handles an unauthorized or generic error from the WorkOS API.
     async fn handle_unauthorized_or_generic_error<E>(self) -> WorkOsResult<Self, E>;
 }
 
+/// Returns whether the response has a JSON content type.
+fn is_json(response: &Response) -> bool {
+    response
+        .headers()
+        .get("content-type")
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.to_lowercase().starts_with("application/json"))
+}
+
+/// Returns the JSON body of the response, if it has one.
+async fn json_body(response: Response) -> Option<serde_json::Value> {
+    if is_json(&response) {
+        response.json().await.ok()
+    } else {
+        None
+    }
+}
+
 impl ResponseExt for Response {
     fn handle_unauthorized_error<E>(self) -> WorkOsResult<Self, E> {
         if self.status() == StatusCode::UNAUTHORIZED {
@@ -29,13 +51,24 @@ impl ResponseExt for Response {
 
     async fn handle_generic_error<E>(self) -> WorkOsResult<Self, E> {
         let status = self.status();
-        if status.is_client_error() || status.is_server_error() {
-            if self
+        if status == StatusCode::NOT_FOUND {
+            Err(WorkOsError::NotFound {
+                body: json_body(self).await,
+            })
+        } else if status == StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = self
                 .headers()
-                .get("content-type")
+                .get(RETRY_AFTER)
                 .and_then(|value| value.to_str().ok())
-                .is_some_and(|value| value.to_lowercase().starts_with("application/json"))
-            {
+                .and_then(|value| value.trim().parse().ok())
+                .map(Duration::from_secs);
+
+            Err(WorkOsError::RateLimited {
+                retry_after,
+                body: json_body(self).await,
+            })
+        } else if status.is_client_error() || status.is_server_error() {
+            if is_json(&self) {
                 match self.json().await {
                     Ok(value) => Err(WorkOsError::ApiError(value)),
                     Err(err) => Err(WorkOsError::RequestError(err)),
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::PageCursor;
+
 /// A paginated list of records.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PaginatedList<T> {
@@ -11,6 +13,18 @@ pub struct PaginatedList<T> {
     pub metadata: ListMetadata,
 }
 
+impl<T> PaginatedList<T> {
+    /// Returns the cursor of the next page, or `None` if this is the last page.
+    pub fn next_page(&self) -> Option<PageCursor> {
+        self.metadata.after.clone().map(PageCursor::After)
+    }
+
+    /// Returns the cursor of the previous page, or `None` if this is the first page.
+    pub fn previous_page(&self) -> Option<PageCursor> {
+        self.metadata.before.clone().map(PageCursor::Before)
+    }
+}
+
 /// The metadata for a [`PaginatedList`].
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ListMetadata {
@@ -27,6 +27,42 @@ impl Default for PaginationParams<'_> {
     }
 }
 
+/// The position in a paginated list from which a page is retrieved.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PageCursor {
+    /// The first page of the list.
+    First,
+
+    /// The page of records after the cursor.
+    After(String),
+
+    /// The page of records before the cursor.
+    Before(String),
+}
+
+impl PageCursor {
+    /// Returns the provided pagination parameters with the cursor applied.
+    pub fn apply<'a>(&'a self, pagination: PaginationParams<'a>) -> PaginationParams<'a> {
+        match self {
+            Self::First => PaginationParams {
+                after: None,
+                before: None,
+                ..pagination
+            },
+            Self::After(after) => PaginationParams {
+                after: Some(after),
+                before: None,
+                ..pagination
+            },
+            Self::Before(before) => PaginationParams {
+                after: None,
+                before: Some(before),
+                ..pagination
+            },
+        }
+    }
+}
+
 /// The order in which records should be returned when paginating.
 #[derive(Clone, Copy, Debug, Serialize)]
 #[serde(rename_all = "snake_case")]
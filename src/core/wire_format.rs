@@ -0,0 +1,49 @@
+use thiserror::Error;
+
+/// An error returned when parsing a string that is not a known value of an enum.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+#[error("unknown {type_name} value: {value}")]
+pub struct ParseEnumError {
+    /// The name of the enum.
+    pub type_name: &'static str,
+
+    /// The string that could not be parsed.
+    pub value: String,
+}
+
+/// Implements `ALL`, `as_str` and [`FromStr`](std::str::FromStr) for an enum, mapping each
+/// variant to the exact string used by the WorkOS API.
+///
+/// The strings must match the serde representation of the enum, which is verified by the
+/// round-trip tests of each enum.
+macro_rules! wire_format {
+    ($name:ident { $($variant:ident => $value:literal),* $(,)? }) => {
+        impl $name {
+            /// All variants, in declaration order.
+            pub const ALL: &'static [Self] = &[$(Self::$variant),*];
+
+            /// Returns the exact string used for the value by the WorkOS API.
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $value),*
+                }
+            }
+        }
+
+        impl ::std::str::FromStr for $name {
+            type Err = $crate::ParseEnumError;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                match value {
+                    $($value => Ok(Self::$variant),)*
+                    _ => Err($crate::ParseEnumError {
+                        type_name: stringify!($name),
+                        value: value.to_string(),
+                    }),
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use wire_format;
@@ -3,7 +3,7 @@ use serde::Serialize;
 use thiserror::Error;
 
 use crate::directory_sync::{DirectoryId, DirectorySync};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{RequestBuilderExt, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`DeleteDirectory`].
 #[derive(Debug, Serialize)]
@@ -68,7 +68,7 @@ impl DeleteDirectory for DirectorySync<'_> {
             .client()
             .delete(url)
             .bearer_auth(self.workos.key())
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_or_generic_error()
             .await?;
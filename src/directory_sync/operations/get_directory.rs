@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use thiserror::Error;
 
 use crate::directory_sync::{Directory, DirectoryId, DirectorySync};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{RequestBuilderExt, ResponseExt, WorkOsError, WorkOsResult};
 
 /// An error returned from [`GetDirectory`].
 #[derive(Debug, Error)]
@@ -50,7 +50,7 @@ impl GetDirectory for DirectorySync<'_> {
             .client()
             .get(url)
             .bearer_auth(self.workos.key())
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
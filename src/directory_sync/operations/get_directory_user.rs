@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use thiserror::Error;
 
 use crate::directory_sync::{DirectorySync, DirectoryUser, DirectoryUserId};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{RequestBuilderExt, ResponseExt, WorkOsError, WorkOsResult};
 
 /// An error returned from [`GetDirectoryUser`].
 #[derive(Debug, Error)]
@@ -61,7 +61,7 @@ impl GetDirectoryUser for DirectorySync<'_> {
             .client()
             .get(url)
             .bearer_auth(self.workos.key())
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
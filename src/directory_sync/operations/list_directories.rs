@@ -3,7 +3,9 @@ use serde::Serialize;
 
 use crate::directory_sync::{Directory, DirectorySync, DirectoryType};
 use crate::organizations::OrganizationId;
-use crate::{KnownOrUnknown, PaginatedList, PaginationParams, ResponseExt, WorkOsResult};
+use crate::{
+    KnownOrUnknown, PaginatedList, PaginationParams, RequestBuilderExt, ResponseExt, WorkOsResult,
+};
 
 /// The parameters for [`ListDirectories`].
 #[derive(Debug, Default, Serialize)]
@@ -70,8 +72,9 @@ impl ListDirectories for DirectorySync<'_> {
             .client()
             .get(url)
             .query(&params)
+            .default_limit(self.workos, &params.pagination)
             .bearer_auth(self.workos.key())
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
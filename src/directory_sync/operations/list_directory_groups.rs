@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use serde::Serialize;
 
 use crate::directory_sync::{DirectoryGroup, DirectoryId, DirectorySync, DirectoryUserId};
-use crate::{PaginatedList, PaginationParams, ResponseExt, WorkOsResult};
+use crate::{PaginatedList, PaginationParams, RequestBuilderExt, ResponseExt, WorkOsResult};
 
 /// A filter for [`ListDirectoryGroups`].
 #[derive(Debug, Serialize)]
@@ -78,8 +78,9 @@ impl ListDirectoryGroups for DirectorySync<'_> {
             .client()
             .get(url)
             .query(&params)
+            .default_limit(self.workos, &params.pagination)
             .bearer_auth(self.workos.key())
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
@@ -21,12 +21,24 @@ pub enum DirectoryType {
     #[serde(rename = "breathe hr")]
     BreatheHr,
 
+    /// Cezanne HR.
+    ///
+    /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/cezanne-hr)
+    #[serde(rename = "cezanne hr")]
+    CezanneHr,
+
     /// CyberArk SCIM v2.0.
     ///
     /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/cyberark-scim)
     #[serde(rename = "cyberark scim v2.0")]
     CyberArkScimV2_0,
 
+    /// Fourth HR.
+    ///
+    /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/fourth)
+    #[serde(rename = "fourth hr")]
+    FourthHr,
+
     /// Generic SCIM v1.1.
     ///
     /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/scim-v1-1)
@@ -81,6 +93,12 @@ pub enum DirectoryType {
     #[serde(rename = "people hr")]
     PeopleHr,
 
+    /// Personio.
+    ///
+    /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/personio)
+    #[serde(rename = "personio")]
+    Personio,
+
     /// PingFederate SCIM v2.0.
     ///
     /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/pingfederate-scim)
@@ -93,9 +111,78 @@ pub enum DirectoryType {
     #[serde(rename = "rippling")]
     Rippling,
 
+    /// Rippling SCIM v2.0.
+    ///
+    /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/rippling-scim)
+    #[serde(rename = "rippling scim v2.0")]
+    RipplingScimV2_0,
+
+    /// Amazon S3.
+    ///
+    /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/s3)
+    #[serde(rename = "s3")]
+    S3,
+
+    /// SFTP.
+    ///
+    /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/sftp)
+    #[serde(rename = "sftp")]
+    Sftp,
+
+    /// SFTP Workday.
+    ///
+    /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/sftp-workday)
+    #[serde(rename = "sftp workday")]
+    SftpWorkday,
+
     /// Workday.
     ///
     /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/workday)
     #[serde(rename = "workday")]
     Workday,
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::DirectoryType;
+
+    #[test]
+    fn it_round_trips_all_directory_types() {
+        for (directory_type, value) in [
+            (DirectoryType::AzureScimV2_0, "azure scim v2.0"),
+            (DirectoryType::BambooHr, "bamboohr"),
+            (DirectoryType::BreatheHr, "breathe hr"),
+            (DirectoryType::CezanneHr, "cezanne hr"),
+            (DirectoryType::CyberArkScimV2_0, "cyberark scim v2.0"),
+            (DirectoryType::FourthHr, "fourth hr"),
+            (DirectoryType::GenericScimV1_1, "generic scim v1.1"),
+            (DirectoryType::GenericScimV2_0, "generic scim v2.0"),
+            (DirectoryType::GoogleWorkspace, "gsuite directory"),
+            (DirectoryType::Hibob, "hibob"),
+            (DirectoryType::JumpCloudScimV2_0, "jump cloud scim v2.0"),
+            (DirectoryType::OktaScimV1_1, "okta scim v1.1"),
+            (DirectoryType::OktaScimV2_0, "okta scim v2.0"),
+            (DirectoryType::OneLoginScimV2_0, "onelogin scim v2.0"),
+            (DirectoryType::PeopleHr, "people hr"),
+            (DirectoryType::Personio, "personio"),
+            (
+                DirectoryType::PingFederateScimV2_0,
+                "pingfederate scim v2.0",
+            ),
+            (DirectoryType::Rippling, "rippling"),
+            (DirectoryType::RipplingScimV2_0, "rippling scim v2.0"),
+            (DirectoryType::S3, "s3"),
+            (DirectoryType::Sftp, "sftp"),
+            (DirectoryType::SftpWorkday, "sftp workday"),
+            (DirectoryType::Workday, "workday"),
+        ] {
+            assert_eq!(serde_json::to_value(directory_type).unwrap(), json!(value));
+            assert_eq!(
+                serde_json::from_value::<DirectoryType>(json!(value)).unwrap(),
+                directory_type
+            );
+        }
+    }
+}
@@ -5,7 +5,8 @@ use thiserror::Error;
 use crate::events::{Event, EventName, Events};
 use crate::organizations::OrganizationId;
 use crate::{
-    PaginatedList, PaginationParams, ResponseExt, UrlEncodableVec, WorkOsError, WorkOsResult,
+    PaginatedList, PaginationParams, RequestBuilderExt, ResponseExt, UrlEncodableVec, WorkOsError,
+    WorkOsResult,
 };
 
 /// Filter to only return events of particular types.
@@ -101,8 +102,9 @@ impl ListEvents for Events<'_> {
             .client()
             .get(url)
             .query(&params)
+            .default_limit(self.workos, &params.pagination)
             .bearer_auth(self.workos.key())
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
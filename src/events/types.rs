@@ -1,5 +1,7 @@
+mod any_event;
 mod event;
 mod events;
 
+pub use any_event::*;
 pub use event::*;
 pub use events::*;
@@ -0,0 +1,119 @@
+use chrono::Utc;
+
+use crate::events::Event;
+use crate::webhooks::WebhookSignature;
+use crate::{PageCursor, Timestamp};
+
+/// The transport an [`AnyEvent`] was received through.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EventSource {
+    /// The event was delivered to a webhook endpoint.
+    Webhook {
+        /// The verified `WorkOS-Signature` header of the delivery.
+        signature: WebhookSignature,
+    },
+
+    /// The event was retrieved from the Events API.
+    EventsApi {
+        /// The cursor to resume listing events after this event.
+        cursor: PageCursor,
+    },
+}
+
+/// An [`Event`] along with how and when it was received.
+///
+/// This allows events to be consumed by the same handlers, e.g. of a
+/// [`WebhookRouter<AnyEvent>`](crate::webhooks::WebhookRouter), regardless of whether they were
+/// delivered by webhook or retrieved from the Events API.
+///
+/// # Examples
+///
+/// ```
+/// # use workos_sdk::WorkOsResult;
+/// # use workos_sdk::events::*;
+/// # use workos_sdk::webhooks::WebhookRouter;
+/// use workos_sdk::{ApiKey, WorkOs};
+///
+/// # async fn run(router: WebhookRouter<AnyEvent>) -> WorkOsResult<(), ()> {
+/// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+///
+/// let events = workos
+///     .events()
+///     .list_events(&ListEventsParams {
+///         pagination: Default::default(),
+///         events: vec![EventName::UserCreated].into(),
+///         organization_id: None,
+///         range_start: None,
+///         range_end: None,
+///     })
+///     .await?;
+///
+/// for event in events.data.into_iter().map(AnyEvent::from_events_api) {
+///     let outcome = router.dispatch(event).await;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnyEvent {
+    /// The event.
+    pub event: Event,
+
+    /// The transport the event was received through.
+    pub source: EventSource,
+
+    /// The timestamp at which the event was received.
+    pub received_at: Timestamp,
+}
+
+impl AnyEvent {
+    /// Returns an [`AnyEvent`] for an event delivered by webhook and verified with the signature.
+    pub fn from_webhook(event: Event, signature: WebhookSignature) -> Self {
+        Self {
+            event,
+            source: EventSource::Webhook { signature },
+            received_at: now(),
+        }
+    }
+
+    /// Returns an [`AnyEvent`] for an event retrieved from the Events API.
+    pub fn from_events_api(event: Event) -> Self {
+        let cursor = PageCursor::After(event.id.to_string());
+
+        Self {
+            event,
+            source: EventSource::EventsApi { cursor },
+            received_at: now(),
+        }
+    }
+}
+
+impl From<AnyEvent> for Event {
+    fn from(event: AnyEvent) -> Self {
+        event.event
+    }
+}
+
+fn now() -> Timestamp {
+    Timestamp(Utc::now().fixed_offset())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::webhooks::testing::event;
+
+    use super::*;
+
+    #[test]
+    fn it_records_the_cursor_of_events_from_the_events_api() {
+        let any_event = AnyEvent::from_events_api(event());
+
+        assert_eq!(
+            any_event.source,
+            EventSource::EventsApi {
+                cursor: PageCursor::After("event_01H2GNQD5D7ZE06FDDS75NFPHY".to_string())
+            }
+        );
+        assert_eq!(Event::from(any_event), event());
+    }
+}
@@ -1,9 +1,10 @@
 use std::collections::HashMap;
+use std::fmt;
 
 use derive_more::{Deref, Display, From};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::{Timestamp, events::*};
+use crate::{KnownOrUnknown, Timestamp, events::*, wire_format};
 
 /// The ID of an [`Event`].
 #[derive(
@@ -17,292 +18,301 @@ pub struct EventId(String);
 pub struct EventContext(pub HashMap<String, String>);
 
 /// The type of an [`Event`].
-#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum EventName {
     /// [WorkOS Docs: `authentication.email_verification_failed` event](https://workos.com/docs/events/authentication).
-    #[display("authentication.email_verification_failed")]
     #[serde(rename = "authentication.email_verification_failed")]
     AuthenticationEmailVerificationFailed,
 
     /// [WorkOS Docs: `authentication.email_verification_succeeded` event](https://workos.com/docs/events/authentication)
-    #[display("authentication.email_verification_succeeded")]
     #[serde(rename = "authentication.email_verification_succeeded")]
     AuthenticationEmailVerificationSucceeded,
 
     /// [WorkOS Docs: `authentication.magic_auth_failed` event](https://workos.com/docs/events/authentication)
-    #[display("authentication.magic_auth_failed")]
     #[serde(rename = "authentication.magic_auth_failed")]
     AuthenticationMagicAuthFailed,
 
     /// [WorkOS Docs: `authentication.magic_auth_succeeded` event](https://workos.com/docs/events/authentication)
-    #[display("authentication.magic_auth_succeeded")]
     #[serde(rename = "authentication.magic_auth_succeeded")]
     AuthenticationMagicAuthSucceeded,
 
     /// [WorkOS Docs: `authentication.mfa_failed` event](https://workos.com/docs/events/authentication)
-    #[display("authentication.mfa_failed")]
     #[serde(rename = "authentication.mfa_failed")]
     AuthenticationMfaFailed,
 
     /// [WorkOS Docs: `authentication.mfa_succeeded` event](https://workos.com/docs/events/authentication)
-    #[display("authentication.mfa_succeeded")]
     #[serde(rename = "authentication.mfa_succeeded")]
     AuthenticationMfaSucceeded,
 
     /// [WorkOS Docs: `authentication.oauth_failed` event](https://workos.com/docs/events/authentication)
-    #[display("authentication.oauth_failed")]
     #[serde(rename = "authentication.oauth_failed")]
     AuthenticationOauthFailed,
 
     /// [WorkOS Docs: `authentication.oauth_succeeded` event](https://workos.com/docs/events/authentication)
-    #[display("authentication.oauth_succeeded")]
     #[serde(rename = "authentication.oauth_succeeded")]
     AuthenticationOauthSucceeded,
 
     /// [WorkOS Docs: `authentication.password_failed` event](https://workos.com/docs/events/authentication)
-    #[display("authentication.password_failed")]
     #[serde(rename = "authentication.password_failed")]
     AuthenticationPasswordFailed,
 
     /// [WorkOS Docs: `authentication.password_succeeded` event](https://workos.com/docs/events/authentication)
-    #[display("authentication.password_succeeded")]
     #[serde(rename = "authentication.password_succeeded")]
     AuthenticationPasswordSucceeded,
 
     /// [WorkOS Docs: `authentication.passkey_failed` event](https://workos.com/docs/events/authentication)
-    #[display("authentication.passkey_failed")]
     #[serde(rename = "authentication.passkey_failed")]
     AuthenticationPasskeyFailed,
 
     /// [WorkOS Docs: `authentication.passkey_succeeded` event](https://workos.com/docs/events/authentication)
-    #[display("authentication.passkey_succeeded")]
     #[serde(rename = "authentication.passkey_succeeded")]
     AuthenticationPasskeySucceeded,
 
     /// [WorkOS Docs: `authentication.sso_failed` event](https://workos.com/docs/events/authentication)
-    #[display("authentication.sso_failed")]
     #[serde(rename = "authentication.sso_failed")]
     AuthenticationSsoFailed,
 
     /// [WorkOS Docs: `authentication.sso_succeeded` event](https://workos.com/docs/events/authentication)
-    #[display("authentication.sso_succeeded")]
     #[serde(rename = "authentication.sso_succeeded")]
     AuthenticationSsoSucceeded,
 
     /// [WorkOS Docs: `authentication.radar_risk_detected` event](https://workos.com/docs/events/authentication)
-    #[display("authentication.radar_risk_detected")]
     #[serde(rename = "authentication.radar_risk_detected")]
     AuthenticationRadarRiskDetected,
 
     /// [WorkOS Docs: `connection.activated` event](https://workos.com/docs/events/connection)
-    #[display("connection.activated")]
     #[serde(rename = "connection.activated")]
     ConnectionActivated,
 
     /// [WorkOS Docs: `connection.deactivated` event](https://workos.com/docs/events/connection)
-    #[display("connection.deactivated")]
     #[serde(rename = "connection.deactivated")]
     ConnectionDeactivated,
 
     /// [WorkOS Docs: `connection.deleted` event](https://workos.com/docs/events/connection)
-    #[display("connection.deleted")]
     #[serde(rename = "connection.deleted")]
     ConnectionDeleted,
 
     /// [WorkOS Docs: `connection.saml_certificate_renewed` event](https://workos.com/docs/events/connection)
-    #[display("connection.saml_certificate_renewed")]
     #[serde(rename = "connection.saml_certificate_renewed")]
     ConnectionSamlCertificateRenewed,
 
     /// [WorkOS Docs: `connection.saml_certificate_renewal_required` event](https://workos.com/docs/events/connection)
-    #[display("connection.saml_certificate_renewal_required")]
     #[serde(rename = "connection.saml_certificate_renewal_required")]
     ConnectionSamlCertificateRenewalRequired,
 
     /// [WorkOS Docs: `dsync.activated` event](https://workos.com/docs/events/directory-sync)
-    #[display("dsync.activated")]
     #[serde(rename = "dsync.activated")]
     DsyncActivated,
 
     /// [WorkOS Docs: `dsync.deleted` event](https://workos.com/docs/events/directory-sync)
-    #[display("dsync.deleted")]
     #[serde(rename = "dsync.deleted")]
     DsyncDeleted,
 
     /// [WorkOS Docs: `dsync.group.created` event](https://workos.com/docs/events/directory-sync)
-    #[display("dsync.group.created")]
     #[serde(rename = "dsync.group.created")]
     DsyncGroupCreated,
 
     /// [WorkOS Docs: `dsync.group.deleted` event](https://workos.com/docs/events/directory-sync)
-    #[display("dsync.group.deleted")]
     #[serde(rename = "dsync.group.deleted")]
     DsyncGroupDeleted,
 
     /// [WorkOS Docs: `dsync.group.updated` event](https://workos.com/docs/events/directory-sync)
-    #[display("dsync.group.updated")]
     #[serde(rename = "dsync.group.updated")]
     DsyncGroupUpdated,
 
     /// [WorkOS Docs: `dsync.group.user_added` event](https://workos.com/docs/events/directory-sync)
-    #[display("dsync.group.user_added")]
     #[serde(rename = "dsync.group.user_added")]
     DsyncGroupUserAdded,
 
     /// [WorkOS Docs: `dsync.group.user_removed` event](https://workos.com/docs/events/directory-sync)
-    #[display("dsync.group.user_removed")]
     #[serde(rename = "dsync.group.user_removed")]
     DsyncGroupUserRemoved,
 
     /// [WorkOS Docs: `dsync.user.created` event](https://workos.com/docs/events/directory-sync)
-    #[display("dsync.user.created")]
     #[serde(rename = "dsync.user.created")]
     DsyncUserCreated,
 
     /// [WorkOS Docs: `dsync.user.deleted` event](https://workos.com/docs/events/directory-sync)
-    #[display("dsync.user.deleted")]
     #[serde(rename = "dsync.user.deleted")]
     DsyncUserDeleted,
 
     /// [WorkOS Docs: `dsync.user.updated` event](https://workos.com/docs/events/directory-sync)
-    #[display("dsync.user.updated")]
     #[serde(rename = "dsync.user.updated")]
     DsyncUserUpdated,
 
     /// [WorkOS Docs: `email_verification.created` event](https://workos.com/docs/events/email-verification)
-    #[display("email_verification.created")]
     #[serde(rename = "email_verification.created")]
     EmailVerificationCreated,
 
     /// [WorkOS Docs: `invitation.accepted` event](https://workos.com/docs/events/invitation)
-    #[display("invitation.accepted")]
     #[serde(rename = "invitation.accepted")]
     InvitationAccepted,
 
     /// [WorkOS Docs: `invitation.created` event](https://workos.com/docs/events/invitation)
-    #[display("invitation.created")]
     #[serde(rename = "invitation.created")]
     InvitationCreated,
 
     /// [WorkOS Docs: `invitation.revoked` event](https://workos.com/docs/events/invitation)
-    #[display("invitation.revoked")]
     #[serde(rename = "invitation.revoked")]
     InvitationRevoked,
 
     /// [WorkOS Docs: `magic_auth.created` event](https://workos.com/docs/events/magic-auth)
-    #[display("magic_auth.created")]
     #[serde(rename = "magic_auth.created")]
     MagicAuthCreated,
 
     /// [WorkOS Docs: `organization.created` event](https://workos.com/docs/events/organization)
-    #[display("organization.created")]
     #[serde(rename = "organization.created")]
     OrganizationCreated,
 
     /// [WorkOS Docs: `organization.updated` event](https://workos.com/docs/events/organization)
-    #[display("organization.updated")]
     #[serde(rename = "organization.updated")]
     OrganizationUpdated,
 
     /// [WorkOS Docs: `organization.deleted` event](https://workos.com/docs/events/organization)
-    #[display("organization.deleted")]
     #[serde(rename = "organization.deleted")]
     OrganizationDeleted,
 
     /// [WorkOS Docs: `organization_domain.created` event](https://workos.com/docs/events/organization-domain)
-    #[display("organization_domain.created")]
     #[serde(rename = "organization_domain.created")]
     OrganizationDomainCreated,
 
     /// [WorkOS Docs: `organization_domain.updated` event](https://workos.com/docs/events/organization-domain)
-    #[display("organization_domain.updated")]
     #[serde(rename = "organization_domain.updated")]
     OrganizationDomainUpdated,
 
     /// [WorkOS Docs: `organization_domain.deleted` event](https://workos.com/docs/events/organization-domain)
-    #[display("organization_domain.deleted")]
     #[serde(rename = "organization_domain.deleted")]
     OrganizationDomainDeleted,
 
     /// [WorkOS Docs: `organization_domain.verified` event](https://workos.com/docs/events/organization-domain)
-    #[display("organization_domain.verified")]
     #[serde(rename = "organization_domain.verified")]
     OrganizationDomainVerified,
 
     /// [WorkOS Docs: `organization_domain.verification_failed` event](https://workos.com/docs/events/organization-domain)
-    #[display("organization_domain.verification_failed")]
     #[serde(rename = "organization_domain.verification_failed")]
     OrganizationDomainVerificationFailed,
 
     /// [WorkOS Docs: `organization_membership.created` event](https://workos.com/docs/events/organization-membership)
-    #[display("organization_membership.created")]
     #[serde(rename = "organization_membership.created")]
     OrganizationMembershipCreated,
 
     /// [WorkOS Docs: `organization_membership.deleted` event](https://workos.com/docs/events/organization-membership)
-    #[display("organization_membership.deleted")]
     #[serde(rename = "organization_membership.deleted")]
     OrganizationMembershipDeleted,
 
     /// [WorkOS Docs: `organization_membership.updated` event](https://workos.com/docs/events/organization-membership)
-    #[display("organization_membership.updated")]
     #[serde(rename = "organization_membership.updated")]
     OrganizationMembershipUpdated,
 
     /// [WorkOS Docs: `password_reset.created` event](https://workos.com/docs/events/password-reset)
-    #[display("password_reset.created")]
     #[serde(rename = "password_reset.created")]
     PasswordResetCreated,
 
     /// [WorkOS Docs: `password_reset.succeeded` event](https://workos.com/docs/events/password-reset)
-    #[display("password_reset.succeeded")]
     #[serde(rename = "password_reset.succeeded")]
     PasswordResetSucceeded,
 
     /// [WorkOS Docs: `role.created` event](https://workos.com/docs/events/role)
-    #[display("role.created")]
     #[serde(rename = "role.created")]
     RoleCreated,
 
     /// [WorkOS Docs: `role.deleted` event](https://workos.com/docs/events/role)
-    #[display("role.deleted")]
     #[serde(rename = "role.deleted")]
     RoleDeleted,
 
     /// [WorkOS Docs: `role.updated` event](https://workos.com/docs/events/role)
-    #[display("role.updated")]
     #[serde(rename = "role.updated")]
     RoleUpdated,
 
     /// [WorkOS Docs: `session.created` event](https://workos.com/docs/events/session)
-    #[display("session.created")]
     #[serde(rename = "session.created")]
     SessionCreated,
 
     /// [WorkOS Docs: `session.revoked` event](https://workos.com/docs/events/session)
-    #[display("session.revoked")]
     #[serde(rename = "session.revoked")]
     SessionRevoked,
 
     /// [WorkOS Docs: `user.created` event](https://workos.com/docs/events/user)
-    #[display("user.created")]
     #[serde(rename = "user.created")]
     UserCreated,
 
     /// [WorkOS Docs: `user.deleted` event](https://workos.com/docs/events/user)
-    #[display("user.deleted")]
     #[serde(rename = "user.deleted")]
     UserDeleted,
 
     /// [WorkOS Docs: `user.updated` event](https://workos.com/docs/events/user)
-    #[display("user.updated")]
     #[serde(rename = "user.updated")]
     UserUpdated,
 }
 
+wire_format!(EventName {
+    AuthenticationEmailVerificationFailed => "authentication.email_verification_failed",
+    AuthenticationEmailVerificationSucceeded => "authentication.email_verification_succeeded",
+    AuthenticationMagicAuthFailed => "authentication.magic_auth_failed",
+    AuthenticationMagicAuthSucceeded => "authentication.magic_auth_succeeded",
+    AuthenticationMfaFailed => "authentication.mfa_failed",
+    AuthenticationMfaSucceeded => "authentication.mfa_succeeded",
+    AuthenticationOauthFailed => "authentication.oauth_failed",
+    AuthenticationOauthSucceeded => "authentication.oauth_succeeded",
+    AuthenticationPasswordFailed => "authentication.password_failed",
+    AuthenticationPasswordSucceeded => "authentication.password_succeeded",
+    AuthenticationPasskeyFailed => "authentication.passkey_failed",
+    AuthenticationPasskeySucceeded => "authentication.passkey_succeeded",
+    AuthenticationSsoFailed => "authentication.sso_failed",
+    AuthenticationSsoSucceeded => "authentication.sso_succeeded",
+    AuthenticationRadarRiskDetected => "authentication.radar_risk_detected",
+    ConnectionActivated => "connection.activated",
+    ConnectionDeactivated => "connection.deactivated",
+    ConnectionDeleted => "connection.deleted",
+    ConnectionSamlCertificateRenewed => "connection.saml_certificate_renewed",
+    ConnectionSamlCertificateRenewalRequired => "connection.saml_certificate_renewal_required",
+    DsyncActivated => "dsync.activated",
+    DsyncDeleted => "dsync.deleted",
+    DsyncGroupCreated => "dsync.group.created",
+    DsyncGroupDeleted => "dsync.group.deleted",
+    DsyncGroupUpdated => "dsync.group.updated",
+    DsyncGroupUserAdded => "dsync.group.user_added",
+    DsyncGroupUserRemoved => "dsync.group.user_removed",
+    DsyncUserCreated => "dsync.user.created",
+    DsyncUserDeleted => "dsync.user.deleted",
+    DsyncUserUpdated => "dsync.user.updated",
+    EmailVerificationCreated => "email_verification.created",
+    InvitationAccepted => "invitation.accepted",
+    InvitationCreated => "invitation.created",
+    InvitationRevoked => "invitation.revoked",
+    MagicAuthCreated => "magic_auth.created",
+    OrganizationCreated => "organization.created",
+    OrganizationUpdated => "organization.updated",
+    OrganizationDeleted => "organization.deleted",
+    OrganizationDomainCreated => "organization_domain.created",
+    OrganizationDomainUpdated => "organization_domain.updated",
+    OrganizationDomainDeleted => "organization_domain.deleted",
+    OrganizationDomainVerified => "organization_domain.verified",
+    OrganizationDomainVerificationFailed => "organization_domain.verification_failed",
+    OrganizationMembershipCreated => "organization_membership.created",
+    OrganizationMembershipDeleted => "organization_membership.deleted",
+    OrganizationMembershipUpdated => "organization_membership.updated",
+    PasswordResetCreated => "password_reset.created",
+    PasswordResetSucceeded => "password_reset.succeeded",
+    RoleCreated => "role.created",
+    RoleDeleted => "role.deleted",
+    RoleUpdated => "role.updated",
+    SessionCreated => "session.created",
+    SessionRevoked => "session.revoked",
+    UserCreated => "user.created",
+    UserDeleted => "user.deleted",
+    UserUpdated => "user.updated",
+});
+
+impl fmt::Display for EventName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// The data of the [`Event`].
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(tag = "event", content = "data")]
+#[serde(remote = "Self", tag = "event", content = "data")]
 pub enum EventData {
     /// [WorkOS Docs: `authentication.email_verification_failed` event](https://workos.com/docs/events/authentication).
     #[serde(rename = "authentication.email_verification_failed")]
@@ -527,6 +537,129 @@ pub enum EventData {
     /// [WorkOS Docs: `user.updated` event](https://workos.com/docs/events/user)
     #[serde(rename = "user.updated")]
     UserUpdated(UserUpdatedEvent),
+
+    /// An event that is not modeled by this crate.
+    ///
+    /// Only events with an unknown [`EventName`] are returned as unknown events. Known events whose data does not
+    /// match the modeled shape fail to deserialize.
+    #[serde(untagged, skip_deserializing)]
+    Unknown(UnknownEvent),
+}
+
+impl Serialize for EventData {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        EventData::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for EventData {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let event = UnknownEvent::deserialize(deserializer)?;
+
+        if event.event.parse::<EventName>().is_err() {
+            return Ok(EventData::Unknown(event));
+        }
+
+        let value = serde_json::json!({ "event": event.event, "data": event.data });
+
+        EventData::deserialize(value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl EventData {
+    /// The [`EventName`] of the event data, or the raw event name for an [`UnknownEvent`].
+    pub fn name(&self) -> KnownOrUnknown<EventName, &str> {
+        KnownOrUnknown::Known(match self {
+            EventData::AuthenticationEmailVerificationFailed(_) => {
+                EventName::AuthenticationEmailVerificationFailed
+            }
+            EventData::AuthenticationEmailVerificationSucceeded(_) => {
+                EventName::AuthenticationEmailVerificationSucceeded
+            }
+            EventData::AuthenticationMagicAuthFailed(_) => EventName::AuthenticationMagicAuthFailed,
+            EventData::AuthenticationMagicAuthSucceeded(_) => {
+                EventName::AuthenticationMagicAuthSucceeded
+            }
+            EventData::AuthenticationMfaFailed(_) => EventName::AuthenticationMfaFailed,
+            EventData::AuthenticationMfaSucceeded(_) => EventName::AuthenticationMfaSucceeded,
+            EventData::AuthenticationOauthFailed(_) => EventName::AuthenticationOauthFailed,
+            EventData::AuthenticationOauthSucceeded(_) => EventName::AuthenticationOauthSucceeded,
+            EventData::AuthenticationPasswordFailed(_) => EventName::AuthenticationPasswordFailed,
+            EventData::AuthenticationPasswordSucceeded(_) => {
+                EventName::AuthenticationPasswordSucceeded
+            }
+            EventData::AuthenticationPasskeyFailed(_) => EventName::AuthenticationPasskeyFailed,
+            EventData::AuthenticationPasskeySucceeded(_) => {
+                EventName::AuthenticationPasskeySucceeded
+            }
+            EventData::AuthenticationSsoFailed(_) => EventName::AuthenticationSsoFailed,
+            EventData::AuthenticationSsoSucceeded(_) => EventName::AuthenticationSsoSucceeded,
+            EventData::AuthenticationRadarRiskDetected(_) => {
+                EventName::AuthenticationRadarRiskDetected
+            }
+            EventData::ConnectionActivated(_) => EventName::ConnectionActivated,
+            EventData::ConnectionDeactivated(_) => EventName::ConnectionDeactivated,
+            EventData::ConnectionDeleted(_) => EventName::ConnectionDeleted,
+            EventData::ConnectionSamlCertificateRenewed(_) => {
+                EventName::ConnectionSamlCertificateRenewed
+            }
+            EventData::ConnectionSamlCertificateRenewalRequired(_) => {
+                EventName::ConnectionSamlCertificateRenewalRequired
+            }
+            EventData::DsyncActivated(_) => EventName::DsyncActivated,
+            EventData::DsyncDeleted(_) => EventName::DsyncDeleted,
+            EventData::DsyncGroupCreated(_) => EventName::DsyncGroupCreated,
+            EventData::DsyncGroupDeleted(_) => EventName::DsyncGroupDeleted,
+            EventData::DsyncGroupUpdated(_) => EventName::DsyncGroupUpdated,
+            EventData::DsyncGroupUserAdded(_) => EventName::DsyncGroupUserAdded,
+            EventData::DsyncGroupUserRemoved(_) => EventName::DsyncGroupUserRemoved,
+            EventData::DsyncUserCreated(_) => EventName::DsyncUserCreated,
+            EventData::DsyncUserDeleted(_) => EventName::DsyncUserDeleted,
+            EventData::DsyncUserUpdated(_) => EventName::DsyncUserUpdated,
+            EventData::EmailVerificationCreated(_) => EventName::EmailVerificationCreated,
+            EventData::InvitationAccepted(_) => EventName::InvitationAccepted,
+            EventData::InvitationCreated(_) => EventName::InvitationCreated,
+            EventData::InvitationRevoked(_) => EventName::InvitationRevoked,
+            EventData::MagicAuthCreated(_) => EventName::MagicAuthCreated,
+            EventData::OrganizationCreated(_) => EventName::OrganizationCreated,
+            EventData::OrganizationUpdated(_) => EventName::OrganizationUpdated,
+            EventData::OrganizationDeleted(_) => EventName::OrganizationDeleted,
+            EventData::OrganizationDomainCreated(_) => EventName::OrganizationDomainCreated,
+            EventData::OrganizationDomainUpdated(_) => EventName::OrganizationDomainUpdated,
+            EventData::OrganizationDomainDeleted(_) => EventName::OrganizationDomainDeleted,
+            EventData::OrganizationDomainVerified(_) => EventName::OrganizationDomainVerified,
+            EventData::OrganizationDomainVerificationFailed(_) => {
+                EventName::OrganizationDomainVerificationFailed
+            }
+            EventData::OrganizationMembershipCreated(_) => EventName::OrganizationMembershipCreated,
+            EventData::OrganizationMembershipDeleted(_) => EventName::OrganizationMembershipDeleted,
+            EventData::OrganizationMembershipUpdated(_) => EventName::OrganizationMembershipUpdated,
+            EventData::PasswordResetCreated(_) => EventName::PasswordResetCreated,
+            EventData::PasswordResetSucceeded(_) => EventName::PasswordResetSucceeded,
+            EventData::RoleCreated(_) => EventName::RoleCreated,
+            EventData::RoleDeleted(_) => EventName::RoleDeleted,
+            EventData::RoleUpdated(_) => EventName::RoleUpdated,
+            EventData::SessionCreated(_) => EventName::SessionCreated,
+            EventData::SessionRevoked(_) => EventName::SessionRevoked,
+            EventData::UserCreated(_) => EventName::UserCreated,
+            EventData::UserDeleted(_) => EventName::UserDeleted,
+            EventData::UserUpdated(_) => EventName::UserUpdated,
+            EventData::Unknown(event) => return KnownOrUnknown::Unknown(&event.event),
+        })
+    }
+}
+
+/// An event with a type that is not modeled by this crate.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnknownEvent {
+    /// The type of the event.
+    pub event: String,
+
+    /// The raw event data.
+    ///
+    /// This is a [`serde_json::Value`] rather than a [`RawValue`](serde_json::value::RawValue), because the event
+    /// data is flattened into [`Event`] and raw values cannot be deserialized from flattened fields.
+    pub data: serde_json::Value,
 }
 
 /// [WorkOS Docs: Event](https://workos.com/docs/reference/event)
@@ -545,3 +678,52 @@ pub struct Event {
     /// An optional object of extra information relevant to the event.
     pub context: Option<EventContext>,
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn it_round_trips_the_wire_format_of_all_event_names() {
+        for value in EventName::ALL {
+            assert_eq!(serde_json::to_value(value).unwrap(), json!(value.as_str()));
+            assert_eq!(
+                serde_json::from_value::<EventName>(json!(value.as_str())).unwrap(),
+                *value
+            );
+            assert_eq!(value.as_str().parse::<EventName>(), Ok(*value));
+            assert_eq!(value.to_string(), value.as_str());
+        }
+    }
+
+    #[test]
+    fn it_deserializes_an_unknown_event_name_as_an_unknown_event() {
+        let data = serde_json::from_value::<EventData>(json!({
+            "event": "organization.renamed",
+            "data": { "id": "org_01EHZNVPK3SFK441A1RGBFSHRT" }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            data,
+            EventData::Unknown(UnknownEvent {
+                event: "organization.renamed".to_string(),
+                data: json!({ "id": "org_01EHZNVPK3SFK441A1RGBFSHRT" }),
+            })
+        )
+    }
+
+    #[test]
+    fn it_fails_to_deserialize_a_known_event_with_invalid_data() {
+        let result = serde_json::from_value::<Event>(json!({
+            "id": "event_01H2GNQD5D7ZE06FDDS75NFPHY",
+            "event": "organization.deleted",
+            "data": { "id": "org_01EHZNVPK3SFK441A1RGBFSHRT" },
+            "created_at": "2023-06-09T18:12:01.837Z"
+        }));
+
+        assert!(result.is_err())
+    }
+}
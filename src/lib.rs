@@ -6,6 +6,7 @@ mod core;
 mod known_or_unknown;
 mod workos;
 
+pub mod actions;
 pub mod admin_portal;
 pub mod directory_sync;
 pub mod events;
@@ -13,8 +14,13 @@ pub mod mfa;
 pub mod organizations;
 pub mod passwordless;
 pub mod roles;
+#[cfg(feature = "sandbox")]
+pub mod sandbox;
+pub mod sessions;
 pub mod sso;
 pub mod user_management;
+pub mod vault;
+pub mod webhooks;
 
 pub use crate::core::*;
 pub use crate::workos::*;
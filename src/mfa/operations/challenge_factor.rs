@@ -3,7 +3,7 @@ use serde::Serialize;
 use thiserror::Error;
 
 use crate::mfa::{AuthenticationChallenge, AuthenticationFactorId, Mfa};
-use crate::{ResponseExt, WorkOsResult};
+use crate::{RequestBuilderExt, ResponseExt, WorkOsResult};
 
 /// The type of authentication factor to challenge.
 #[derive(Debug, Serialize)]
@@ -90,7 +90,7 @@ impl ChallengeFactor for Mfa<'_> {
             .post(url)
             .bearer_auth(self.workos.key())
             .json(&params)
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
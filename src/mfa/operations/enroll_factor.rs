@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::mfa::{AuthenticationFactor, Mfa};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{RequestBuilderExt, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`EnrollFactor`].
 #[derive(Debug, Serialize)]
@@ -134,7 +134,7 @@ impl EnrollFactor for Mfa<'_> {
             .post(url)
             .bearer_auth(self.workos.key())
             .json(&params)
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_error()?
             .handle_enroll_factor_error()
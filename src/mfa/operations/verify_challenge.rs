@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::mfa::{AuthenticationChallenge, AuthenticationChallengeId, Mfa, MfaCode};
-use crate::{ResponseExt, WorkOsResult};
+use crate::{RequestBuilderExt, ResponseExt, WorkOsResult};
 
 /// The response for [`VerifyChallenge`].
 #[derive(Debug, Serialize, Deserialize)]
@@ -82,7 +82,7 @@ impl VerifyChallenge for Mfa<'_> {
             .post(url)
             .bearer_auth(self.workos.key())
             .json(&params)
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
@@ -1,8 +1,10 @@
 //! A module for interacting with organizations within WorkOS.
 
+mod dns_verification_checker;
 mod operations;
 mod types;
 
+pub use dns_verification_checker::*;
 pub use operations::*;
 pub use types::*;
 
@@ -0,0 +1,183 @@
+use serde::Deserialize;
+use url::Url;
+
+use crate::organizations::DnsVerificationRecord;
+
+/// The type of TXT records in DNS over HTTPS responses.
+const TXT_RECORD_TYPE: u16 = 16;
+
+#[derive(Deserialize)]
+struct DnsResponse {
+    #[serde(rename = "Answer", default)]
+    answer: Vec<DnsAnswer>,
+}
+
+#[derive(Deserialize)]
+struct DnsAnswer {
+    #[serde(rename = "type")]
+    record_type: u16,
+    data: String,
+}
+
+/// Checks whether a [`DnsVerificationRecord`] is published, using a DNS over HTTPS resolver.
+///
+/// This lets products tell customers whether their DNS changes are visible yet, before WorkOS
+/// verifies the domain.
+///
+/// # Examples
+///
+/// ```
+/// # use workos_sdk::organizations::*;
+/// # async fn run(organization_domain: &OrganizationDomain) -> Result<(), reqwest::Error> {
+/// let checker = DnsVerificationChecker::new();
+///
+/// if let Some(record) = organization_domain.dns_verification_record() {
+///     let is_published = checker.is_published(&record).await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct DnsVerificationChecker {
+    client: reqwest::Client,
+    resolver_url: Url,
+}
+
+impl Default for DnsVerificationChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DnsVerificationChecker {
+    /// Returns a new [`DnsVerificationChecker`] using the Cloudflare DNS over HTTPS resolver.
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            resolver_url: Url::parse("https://cloudflare-dns.com/dns-query").unwrap(),
+        }
+    }
+
+    /// Sets the URL of the DNS over HTTPS resolver, which must support the JSON API.
+    pub fn resolver_url(mut self, resolver_url: Url) -> Self {
+        self.resolver_url = resolver_url;
+        self
+    }
+
+    /// Returns whether the record is published with the expected value.
+    pub async fn is_published(
+        &self,
+        record: &DnsVerificationRecord,
+    ) -> Result<bool, reqwest::Error> {
+        let response = self
+            .client
+            .get(self.resolver_url.clone())
+            .query(&[("name", record.name.as_str()), ("type", "TXT")])
+            .header("accept", "application/dns-json")
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<DnsResponse>()
+            .await?;
+
+        Ok(response
+            .answer
+            .iter()
+            .filter(|answer| answer.record_type == TXT_RECORD_TYPE)
+            .any(|answer| txt_value(&answer.data) == record.value))
+    }
+}
+
+/// Joins the quoted character strings of TXT record data, e.g. `"abc" "def"`.
+fn txt_value(data: &str) -> String {
+    data.split("\" \"")
+        .map(|part| part.trim_matches('"'))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use mockito::Matcher;
+    use serde_json::json;
+    use tokio;
+
+    use super::*;
+
+    fn record() -> DnsVerificationRecord {
+        DnsVerificationRecord {
+            name: "_workos-challenge.foo-corp.com".to_string(),
+            value: "m5Oztg3jdK4NJLgs8uIlIprMw".to_string(),
+        }
+    }
+
+    async fn checker(
+        server: &mut mockito::Server,
+        answer: serde_json::Value,
+    ) -> DnsVerificationChecker {
+        server
+            .mock("GET", "/dns-query")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded(
+                    "name".to_string(),
+                    "_workos-challenge.foo-corp.com".to_string(),
+                ),
+                Matcher::UrlEncoded("type".to_string(), "TXT".to_string()),
+            ]))
+            .match_header("accept", "application/dns-json")
+            .with_status(200)
+            .with_body(json!({ "Status": 0, "Answer": answer }).to_string())
+            .create_async()
+            .await;
+
+        DnsVerificationChecker::new()
+            .resolver_url(Url::parse(&format!("{}/dns-query", server.url())).unwrap())
+    }
+
+    #[tokio::test]
+    async fn it_detects_a_published_record() {
+        let mut server = mockito::Server::new_async().await;
+
+        let checker = checker(
+            &mut server,
+            json!([
+                {
+                    "name": "_workos-challenge.foo-corp.com",
+                    "type": 16,
+                    "TTL": 300,
+                    "data": "\"m5Oztg3jdK4NJLgs8uIlIprMw\""
+                }
+            ]),
+        )
+        .await;
+
+        assert!(checker.is_published(&record()).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn it_detects_a_missing_or_outdated_record() {
+        let mut server = mockito::Server::new_async().await;
+
+        let checker = checker(
+            &mut server,
+            json!([
+                {
+                    "name": "_workos-challenge.foo-corp.com",
+                    "type": 16,
+                    "TTL": 300,
+                    "data": "\"outdated\""
+                }
+            ]),
+        )
+        .await;
+
+        assert!(!checker.is_published(&record()).await.unwrap());
+    }
+
+    #[test]
+    fn it_joins_split_txt_values() {
+        assert_eq!(
+            txt_value("\"m5Oztg3jdK\" \"4NJLgs8uIlIprMw\""),
+            "m5Oztg3jdK4NJLgs8uIlIprMw"
+        );
+    }
+}
@@ -1,11 +1,15 @@
 mod create_organization;
 mod delete_organization;
 mod get_organization;
+mod get_organization_domain;
 mod list_organizations;
 mod update_organization;
+mod wait_for_domain_verification;
 
 pub use create_organization::*;
 pub use delete_organization::*;
 pub use get_organization::*;
+pub use get_organization_domain::*;
 pub use list_organizations::*;
 pub use update_organization::*;
+pub use wait_for_domain_verification::*;
@@ -3,7 +3,7 @@ use serde::Serialize;
 use thiserror::Error;
 
 use crate::organizations::{OrganizationId, Organizations};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{RequestBuilderExt, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`DeleteOrganization`].
 #[derive(Debug, Serialize)]
@@ -68,7 +68,7 @@ impl DeleteOrganization for Organizations<'_> {
             .client()
             .delete(url)
             .bearer_auth(self.workos.key())
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_or_generic_error()
             .await?;
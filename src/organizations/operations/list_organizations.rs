@@ -4,7 +4,8 @@ use thiserror::Error;
 
 use crate::organizations::{Organization, Organizations};
 use crate::{
-    PaginatedList, PaginationParams, ResponseExt, UrlEncodableVec, WorkOsError, WorkOsResult,
+    PaginatedList, PaginationParams, RequestBuilderExt, ResponseExt, UrlEncodableVec, WorkOsError,
+    WorkOsResult,
 };
 
 /// The domains to filter the organizations by.
@@ -84,8 +85,9 @@ impl ListOrganizations for Organizations<'_> {
             .client()
             .get(url)
             .query(&params)
+            .default_limit(self.workos, &params.pagination)
             .bearer_auth(self.workos.key())
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
@@ -0,0 +1,295 @@
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+
+use crate::organizations::{
+    GetOrganizationDomain, GetOrganizationDomainError, OrganizationDomain, OrganizationDomainId,
+    OrganizationDomainState, Organizations,
+};
+use crate::{KnownOrUnknown, WorkOsError, WorkOsFutureExt, WorkOsResult};
+
+/// The shortest interval between two polls of [`WaitForDomainVerification::wait_for_domain_verification`].
+const MIN_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The outcome of [`WaitForDomainVerification::wait_for_domain_verification`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DomainVerificationOutcome {
+    /// The organization domain was verified.
+    Verified(OrganizationDomain),
+
+    /// The verification of the organization domain failed.
+    Failed(OrganizationDomain),
+}
+
+/// Polls an [`OrganizationDomain`] until its verification completes.
+#[async_trait]
+pub trait WaitForDomainVerification {
+    /// Retrieves the [`OrganizationDomain`] every `interval` until it is verified or its
+    /// verification failed.
+    ///
+    /// The domain is retrieved one last time at the deadline, allowing that request up to one
+    /// `interval` to complete. Returns [`WorkOsError::DeadlineExceeded`] if the domain is still
+    /// pending after that.
+    ///
+    /// Intervals shorter than 100 milliseconds are raised to 100 milliseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::{Duration, Instant};
+    /// # use workos_sdk::WorkOsResult;
+    /// # use workos_sdk::organizations::*;
+    /// use workos_sdk::{ApiKey, WorkOs};
+    ///
+    /// # async fn run() -> WorkOsResult<(), GetOrganizationDomainError> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    ///
+    /// let outcome = workos
+    ///     .organizations()
+    ///     .wait_for_domain_verification(
+    ///         &OrganizationDomainId::from("org_domain_01EHZNVPK2QXHMVWCEDQEKY69A"),
+    ///         Duration::from_secs(10),
+    ///         Instant::now() + Duration::from_secs(5 * 60),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn wait_for_domain_verification(
+        &self,
+        id: &OrganizationDomainId,
+        interval: Duration,
+        deadline: Instant,
+    ) -> WorkOsResult<DomainVerificationOutcome, GetOrganizationDomainError>;
+}
+
+#[async_trait]
+impl WaitForDomainVerification for Organizations<'_> {
+    async fn wait_for_domain_verification(
+        &self,
+        id: &OrganizationDomainId,
+        interval: Duration,
+        deadline: Instant,
+    ) -> WorkOsResult<DomainVerificationOutcome, GetOrganizationDomainError> {
+        let interval = interval.max(MIN_INTERVAL);
+
+        let mut is_last_poll = false;
+
+        loop {
+            let request = self.get_organization_domain(id);
+            let organization_domain = if is_last_poll {
+                request.timeout(interval).await?
+            } else {
+                request.deadline(deadline).await?
+            };
+
+            match organization_domain.state {
+                KnownOrUnknown::Known(OrganizationDomainState::Verified) => {
+                    return Ok(DomainVerificationOutcome::Verified(organization_domain));
+                }
+                KnownOrUnknown::Known(OrganizationDomainState::Failed) => {
+                    return Ok(DomainVerificationOutcome::Failed(organization_domain));
+                }
+                _ => {}
+            }
+
+            if is_last_poll {
+                return Err(WorkOsError::DeadlineExceeded);
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            is_last_poll = remaining <= interval;
+
+            tokio::time::sleep(interval.min(remaining)).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use matches::assert_matches;
+    use serde_json::json;
+    use tokio;
+
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    fn organization_domain(state: &str) -> String {
+        json!({
+            "object": "organization_domain",
+            "id": "org_domain_01EHZNVPK2QXHMVWCEDQEKY69A",
+            "domain": "foo-corp.com",
+            "organization_id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+            "state": state,
+            "verification_strategy": "dns",
+            "verification_token": "m5Oztg3jdK4NJLgs8uIlIprMw",
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z"
+        })
+        .to_string()
+    }
+
+    /// Mocks the organization domain, which is pending for the first `pending` requests and verified afterwards.
+    async fn verified_after(server: &mut mockito::Server, pending: usize) -> mockito::Mock {
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        server
+            .mock(
+                "GET",
+                "/organization_domains/org_domain_01EHZNVPK2QXHMVWCEDQEKY69A",
+            )
+            .with_status(200)
+            .with_body_from_request(move |_| {
+                let state = if requests.fetch_add(1, Ordering::SeqCst) < pending {
+                    "pending"
+                } else {
+                    "verified"
+                };
+
+                organization_domain(state).into()
+            })
+            .expect(pending + 1)
+            .create_async()
+            .await
+    }
+
+    #[tokio::test]
+    async fn it_polls_until_the_domain_is_verified() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let mock = verified_after(&mut server, 2).await;
+
+        let outcome = workos
+            .organizations()
+            .wait_for_domain_verification(
+                &OrganizationDomainId::from("org_domain_01EHZNVPK2QXHMVWCEDQEKY69A"),
+                Duration::from_millis(100),
+                Instant::now() + Duration::from_secs(10),
+            )
+            .await;
+
+        assert_matches!(outcome, Ok(DomainVerificationOutcome::Verified(_)));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_returns_failed_verifications() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        server
+            .mock(
+                "GET",
+                "/organization_domains/org_domain_01EHZNVPK2QXHMVWCEDQEKY69A",
+            )
+            .with_status(200)
+            .with_body(organization_domain("failed"))
+            .create_async()
+            .await;
+
+        let outcome = workos
+            .organizations()
+            .wait_for_domain_verification(
+                &OrganizationDomainId::from("org_domain_01EHZNVPK2QXHMVWCEDQEKY69A"),
+                Duration::from_millis(10),
+                Instant::now() + Duration::from_secs(10),
+            )
+            .await;
+
+        assert_matches!(outcome, Ok(DomainVerificationOutcome::Failed(_)));
+    }
+
+    #[tokio::test]
+    async fn it_fails_when_the_deadline_passes() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        server
+            .mock(
+                "GET",
+                "/organization_domains/org_domain_01EHZNVPK2QXHMVWCEDQEKY69A",
+            )
+            .with_status(200)
+            .with_body(organization_domain("pending"))
+            .create_async()
+            .await;
+
+        let outcome = workos
+            .organizations()
+            .wait_for_domain_verification(
+                &OrganizationDomainId::from("org_domain_01EHZNVPK2QXHMVWCEDQEKY69A"),
+                Duration::from_millis(10),
+                Instant::now() + Duration::from_millis(50),
+            )
+            .await;
+
+        assert_matches!(outcome, Err(WorkOsError::DeadlineExceeded));
+    }
+
+    #[tokio::test]
+    async fn it_polls_one_last_time_at_the_deadline() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let mock = verified_after(&mut server, 1).await;
+
+        let outcome = workos
+            .organizations()
+            .wait_for_domain_verification(
+                &OrganizationDomainId::from("org_domain_01EHZNVPK2QXHMVWCEDQEKY69A"),
+                Duration::from_secs(10),
+                Instant::now() + Duration::from_millis(200),
+            )
+            .await;
+
+        assert_matches!(outcome, Ok(DomainVerificationOutcome::Verified(_)));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_raises_a_zero_interval_to_the_minimum() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let mock = verified_after(&mut server, 1).await;
+
+        let started_at = Instant::now();
+        let outcome = workos
+            .organizations()
+            .wait_for_domain_verification(
+                &OrganizationDomainId::from("org_domain_01EHZNVPK2QXHMVWCEDQEKY69A"),
+                Duration::ZERO,
+                Instant::now() + Duration::from_secs(10),
+            )
+            .await;
+
+        assert_matches!(outcome, Ok(DomainVerificationOutcome::Verified(_)));
+        assert!(started_at.elapsed() >= MIN_INTERVAL);
+        mock.assert_async().await;
+    }
+}
@@ -5,7 +5,7 @@ use crate::{Timestamps, organizations::OrganizationDomain};
 
 /// The ID of an [`Organization`].
 #[derive(
-    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
 )]
 #[from(forward)]
 pub struct OrganizationId(String);
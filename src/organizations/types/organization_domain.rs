@@ -1,7 +1,9 @@
+use std::fmt::{self, Display};
+
 use derive_more::{Deref, Display, From};
 use serde::{Deserialize, Serialize};
 
-use crate::{KnownOrUnknown, Timestamps, organizations::OrganizationId};
+use crate::{KnownOrUnknown, Timestamps, organizations::OrganizationId, wire_format};
 
 /// The ID of an [`OrganizationDomain`].
 #[derive(
@@ -24,6 +26,12 @@ pub enum OrganizationDomainState {
     Failed,
 }
 
+wire_format!(OrganizationDomainState {
+    Pending => "pending",
+    Verified => "verified",
+    Failed => "failed",
+});
+
 /// The verification strategy of an [`OrganizationDomain`].
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -67,3 +75,108 @@ pub struct OrganizationDomain {
     #[serde(flatten)]
     pub timestamps: Timestamps,
 }
+
+impl OrganizationDomain {
+    /// Returns the DNS record that has to be published to verify the domain.
+    ///
+    /// Returns `None` when the domain is not verified through DNS or has no verification token.
+    pub fn dns_verification_record(&self) -> Option<DnsVerificationRecord> {
+        if self.verification_strategy
+            != KnownOrUnknown::Known(OrganizationDomainVerificationStrategy::Dns)
+        {
+            return None;
+        }
+
+        self.verification_token
+            .as_ref()
+            .map(|token| DnsVerificationRecord {
+                name: format!("_workos-challenge.{}", self.domain),
+                value: token.to_string(),
+            })
+    }
+}
+
+/// A DNS TXT record that verifies an [`OrganizationDomain`] once published.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DnsVerificationRecord {
+    /// The fully qualified name of the record.
+    pub name: String,
+
+    /// The value of the record.
+    pub value: String,
+}
+
+impl DnsVerificationRecord {
+    /// The type of the record.
+    pub const RECORD_TYPE: &'static str = "TXT";
+}
+
+impl Display for DnsVerificationRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} \"{}\"", self.name, Self::RECORD_TYPE, self.value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    fn organization_domain(verification_strategy: &str) -> OrganizationDomain {
+        serde_json::from_value(json!({
+            "object": "organization_domain",
+            "id": "org_domain_01EHZNVPK2QXHMVWCEDQEKY69A",
+            "organization_id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+            "domain": "foo-corp.com",
+            "state": "pending",
+            "verification_strategy": verification_strategy,
+            "verification_token": "m5Oztg3jdK4NJLgs8uIlIprMw",
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn it_returns_the_dns_verification_record() {
+        let record = organization_domain("dns")
+            .dns_verification_record()
+            .unwrap();
+
+        assert_eq!(
+            record,
+            DnsVerificationRecord {
+                name: "_workos-challenge.foo-corp.com".to_string(),
+                value: "m5Oztg3jdK4NJLgs8uIlIprMw".to_string(),
+            }
+        );
+        assert_eq!(
+            record.to_string(),
+            "_workos-challenge.foo-corp.com TXT \"m5Oztg3jdK4NJLgs8uIlIprMw\""
+        );
+    }
+
+    #[test]
+    fn it_returns_no_dns_verification_record_for_manual_verification() {
+        assert_eq!(
+            organization_domain("manual").dns_verification_record(),
+            None
+        );
+    }
+
+    #[test]
+    fn it_round_trips_the_wire_format_of_all_states() {
+        for value in OrganizationDomainState::ALL {
+            assert_eq!(serde_json::to_value(value).unwrap(), json!(value.as_str()));
+            assert_eq!(
+                serde_json::from_value::<OrganizationDomainState>(json!(value.as_str())).unwrap(),
+                *value
+            );
+            assert_eq!(
+                value.as_str().parse::<OrganizationDomainState>(),
+                Ok(*value)
+            );
+        }
+    }
+}
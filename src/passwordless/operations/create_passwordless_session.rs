@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use serde::Serialize;
 
 use crate::passwordless::{Passwordless, PasswordlessSession};
-use crate::{ResponseExt, WorkOsResult};
+use crate::{RequestBuilderExt, ResponseExt, WorkOsResult};
 
 /// The type of passwordless session to create.
 #[derive(Debug, Serialize)]
@@ -87,7 +87,7 @@ impl CreatePasswordlessSession for Passwordless<'_> {
             .post(url)
             .bearer_auth(self.workos.key())
             .json(&params)
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use serde::Serialize;
 
 use crate::passwordless::{Passwordless, PasswordlessSessionId};
-use crate::{ResponseExt, WorkOsResult};
+use crate::{RequestBuilderExt, ResponseExt, WorkOsResult};
 
 /// The parameters for [`SendPasswordlessSession`].
 #[derive(Debug, Serialize)]
@@ -62,7 +62,7 @@ impl SendPasswordlessSession for Passwordless<'_> {
             .post(url)
             .bearer_auth(self.workos.key())
             .json(&params)
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_or_generic_error()
             .await?;
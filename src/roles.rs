@@ -3,9 +3,12 @@
 //! [WorkOS Docs: Roles](https://workos.com/docs/roles)
 
 mod operations;
+mod role_permissions;
+mod role_slugs;
 mod types;
 
-// pub use operations::*;
+pub use operations::*;
+pub use role_permissions::*;
 pub use types::*;
 
 use crate::WorkOs;
@@ -14,7 +17,6 @@ use crate::WorkOs;
 ///
 /// [WorkOS Docs: Roles](https://workos.com/docs/roles)
 pub struct Roles<'a> {
-    #[expect(dead_code)]
     workos: &'a WorkOs,
 }
 
@@ -1 +1,5 @@
+mod list_organization_roles;
+mod list_roles;
 
+pub use list_organization_roles::*;
+pub use list_roles::*;
@@ -0,0 +1,139 @@
+/// Defines an enum of the role slugs of an application.
+///
+/// The enum can be converted into a [`RoleSlug`](crate::roles::RoleSlug) and parsed from a
+/// slug, so role assignments can't contain a misspelled slug.
+///
+/// # Examples
+///
+/// ```
+/// use workos_sdk::role_slugs;
+/// use workos_sdk::roles::RoleSlug;
+///
+/// role_slugs! {
+///     /// The roles of the application.
+///     pub enum AppRole {
+///         /// Can manage the organization.
+///         Admin => "admin",
+///
+///         /// Can access the organization.
+///         Member => "member",
+///     }
+/// }
+///
+/// assert_eq!(AppRole::Admin.as_str(), "admin");
+/// assert_eq!(RoleSlug::from(AppRole::Member).slug, "member");
+/// assert_eq!("admin".parse(), Ok(AppRole::Admin));
+/// ```
+#[macro_export]
+macro_rules! role_slugs {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident => $slug:literal
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+        }
+
+        impl $name {
+            /// All role slugs, in the order they were defined.
+            pub const ALL: &'static [Self] = &[$(Self::$variant),*];
+
+            /// Returns the slug of the role.
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $slug,)*
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl ::std::str::FromStr for $name {
+            type Err = $crate::ParseEnumError;
+
+            fn from_str(slug: &str) -> ::std::result::Result<Self, Self::Err> {
+                match slug {
+                    $($slug => Ok(Self::$variant),)*
+                    _ => Err($crate::ParseEnumError {
+                        type_name: stringify!($name),
+                        value: slug.to_string(),
+                    }),
+                }
+            }
+        }
+
+        impl ::std::convert::TryFrom<&$crate::roles::RoleSlug> for $name {
+            type Error = $crate::ParseEnumError;
+
+            fn try_from(
+                role: &$crate::roles::RoleSlug,
+            ) -> ::std::result::Result<Self, Self::Error> {
+                role.slug.parse()
+            }
+        }
+
+        impl ::std::convert::From<$name> for $crate::roles::RoleSlug {
+            fn from(role: $name) -> Self {
+                Self {
+                    slug: role.as_str().to_string(),
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ParseEnumError;
+    use crate::roles::RoleSlug;
+
+    role_slugs! {
+        enum TestRole {
+            Admin => "admin",
+            BillingManager => "billing-manager",
+        }
+    }
+
+    #[test]
+    fn it_converts_role_slugs() {
+        assert_eq!(TestRole::ALL, &[TestRole::Admin, TestRole::BillingManager]);
+        assert_eq!(TestRole::BillingManager.to_string(), "billing-manager");
+        assert_eq!(
+            RoleSlug::from(TestRole::Admin),
+            RoleSlug {
+                slug: "admin".to_string()
+            }
+        );
+        assert_eq!(
+            TestRole::try_from(&RoleSlug {
+                slug: "billing-manager".to_string()
+            }),
+            Ok(TestRole::BillingManager)
+        );
+    }
+
+    #[test]
+    fn it_rejects_unknown_role_slugs() {
+        assert_eq!(
+            "owner".parse::<TestRole>(),
+            Err(ParseEnumError {
+                type_name: "TestRole",
+                value: "owner".to_string(),
+            })
+        );
+    }
+}
@@ -1,6 +1,50 @@
+use derive_more::{Deref, Display, From};
 use serde::{Deserialize, Serialize};
 
-use crate::Timestamps;
+use crate::{KnownOrUnknown, Timestamps};
+
+/// The ID of a [`Role`].
+#[derive(
+    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[from(forward)]
+pub struct RoleId(String);
+
+/// The type of a [`Role`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RoleType {
+    /// The role is defined for the whole environment.
+    EnvironmentRole,
+
+    /// The role is defined for a single organization.
+    OrganizationRole,
+}
+
+/// [WorkOS Docs: Role](https://workos.com/docs/reference/roles)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Role {
+    /// The ID of the role.
+    pub id: RoleId,
+
+    /// The name of the role.
+    pub name: String,
+
+    /// A unique key to reference the role.
+    pub slug: String,
+
+    /// The description of the role.
+    pub description: Option<String>,
+
+    /// A list of permission slugs assigned to the role.
+    pub permissions: Vec<String>,
+
+    /// The type of the role.
+    pub r#type: KnownOrUnknown<RoleType, String>,
+
+    /// The timestamps for the role.
+    #[serde(flatten)]
+    pub timestamps: Timestamps,
+}
 
 /// The slug of a [`Role`].
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -9,6 +53,12 @@ pub struct RoleSlug {
     pub slug: String,
 }
 
+/// The response body of the endpoints listing [`Role`]s.
+#[derive(Deserialize)]
+pub(crate) struct RoleList {
+    pub(crate) data: Vec<Role>,
+}
+
 /// [WorkOS Docs: Role events](https://workos.com/docs/events/role)
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RoleEvent {
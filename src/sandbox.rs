@@ -0,0 +1,587 @@
+//! A module for creating throwaway resources in integration tests.
+//!
+//! Every resource created through a [`Sandbox`] is tagged with the sandbox prefix, so
+//! [`Sandbox::sweep`] can delete everything left behind once the tests have run.
+
+use std::collections::HashSet;
+
+use rand::RngCore;
+use thiserror::Error;
+
+use crate::organizations::{
+    CreateOrganization, CreateOrganizationError, CreateOrganizationParams, DeleteOrganization,
+    DeleteOrganizationError, DeleteOrganizationParams, ListOrganizations, ListOrganizationsParams,
+    Organization, OrganizationId,
+};
+use crate::user_management::{
+    CreateUser, CreateUserError, CreateUserParams, DeleteUser, DeleteUserError, DeleteUserParams,
+    ListUsers, ListUsersError, ListUsersParams, User, UserId,
+};
+use crate::{PaginationParams, WorkOs, WorkOsError, WorkOsResult};
+
+/// The prefix used by [`Sandbox::random`], followed by a random suffix.
+pub const DEFAULT_PREFIX: &str = "sdk-test-";
+
+/// The minimum length of a sandbox prefix.
+///
+/// Short prefixes are likely to match real resources, which [`Sandbox::sweep`] would delete.
+pub const MIN_PREFIX_LEN: usize = 8;
+
+/// An error returned from [`Sandbox::new`] when the prefix is shorter than [`MIN_PREFIX_LEN`].
+#[derive(Debug, Error)]
+#[error("sandbox prefix {prefix:?} is shorter than {MIN_PREFIX_LEN} characters")]
+pub struct InvalidSandboxPrefix {
+    /// The rejected prefix.
+    pub prefix: String,
+}
+
+/// An error returned from [`Sandbox::sweep`].
+#[derive(Debug, Error)]
+pub enum SweepError {
+    /// The client does not use a test API key, and [`Sandbox::allow_any_key`] was not called.
+    #[error("refusing to sweep with an API key that is not a test key")]
+    NotATestKey,
+
+    /// Listing the organizations failed.
+    #[error("failed to list organizations: {0}")]
+    ListOrganizations(WorkOsError<()>),
+
+    /// Deleting an organization failed.
+    #[error("failed to delete organization {id}: {source}")]
+    DeleteOrganization {
+        /// The ID of the organization that could not be deleted.
+        id: OrganizationId,
+
+        /// The underlying error.
+        source: WorkOsError<DeleteOrganizationError>,
+    },
+
+    /// Listing the users failed.
+    #[error("failed to list users: {0}")]
+    ListUsers(WorkOsError<ListUsersError>),
+
+    /// Deleting a user failed.
+    #[error("failed to delete user {id}: {source}")]
+    DeleteUser {
+        /// The ID of the user that could not be deleted.
+        id: UserId,
+
+        /// The underlying error.
+        source: WorkOsError<DeleteUserError>,
+    },
+}
+
+/// The resources deleted by [`Sandbox::sweep`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SweepReport {
+    /// The IDs of the deleted organizations.
+    pub organizations: Vec<OrganizationId>,
+
+    /// The IDs of the deleted users.
+    pub users: Vec<UserId>,
+}
+
+/// Creates prefixed organizations and users, and deletes them again.
+///
+/// Organization names and the local part of user emails start with the sandbox prefix.
+/// Use a prefix that no real resource in the environment shares, as [`Sandbox::sweep`]
+/// deletes every organization and user that matches it.
+pub struct Sandbox<'a> {
+    workos: &'a WorkOs,
+    prefix: String,
+    allow_any_key: bool,
+}
+
+impl<'a> Sandbox<'a> {
+    /// The prefix of test API keys.
+    pub const TEST_KEY_PREFIX: &'static str = "sk_test_";
+
+    /// Returns a new [`Sandbox`] using the provided prefix.
+    ///
+    /// Returns an error if the prefix is shorter than [`MIN_PREFIX_LEN`].
+    pub fn new(
+        workos: &'a WorkOs,
+        prefix: impl Into<String>,
+    ) -> Result<Self, InvalidSandboxPrefix> {
+        let prefix = prefix.into();
+        if prefix.chars().count() < MIN_PREFIX_LEN {
+            return Err(InvalidSandboxPrefix { prefix });
+        }
+
+        Ok(Self {
+            workos,
+            prefix,
+            allow_any_key: false,
+        })
+    }
+
+    /// Returns a new [`Sandbox`] using [`DEFAULT_PREFIX`] followed by a random suffix,
+    /// so concurrent test runs do not sweep each other's resources.
+    pub fn random(workos: &'a WorkOs) -> Self {
+        let mut suffix = [0u8; 4];
+        rand::rng().fill_bytes(&mut suffix);
+
+        Self {
+            workos,
+            prefix: format!("{DEFAULT_PREFIX}{suffix}-", suffix = hex::encode(suffix)),
+            allow_any_key: false,
+        }
+    }
+
+    /// Allows [`Sandbox::sweep`] to run with an API key that does not start with
+    /// [`Sandbox::TEST_KEY_PREFIX`], such as a production key.
+    pub fn allow_any_key(mut self) -> Self {
+        self.allow_any_key = true;
+        self
+    }
+
+    /// Returns the prefix of this sandbox.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Returns the provided value with the sandbox prefix prepended.
+    pub fn prefixed(&self, value: &str) -> String {
+        format!("{prefix}{value}", prefix = self.prefix)
+    }
+
+    /// Creates an [`Organization`] whose name starts with the sandbox prefix.
+    ///
+    /// The organization has no domains and allows profiles outside the organization.
+    pub async fn create_organization(
+        &self,
+        name: &str,
+    ) -> WorkOsResult<Organization, CreateOrganizationError> {
+        self.workos
+            .organizations()
+            .create_organization(&CreateOrganizationParams {
+                name: &self.prefixed(name),
+                allow_profiles_outside_organization: Some(&true),
+                domains: HashSet::new(),
+            })
+            .await
+    }
+
+    /// Creates a [`User`] whose email starts with the sandbox prefix.
+    pub async fn create_user(&self, email: &str) -> WorkOsResult<User, CreateUserError> {
+        self.workos
+            .user_management()
+            .create_user(&CreateUserParams {
+                email: &self.prefixed(email),
+                password: None,
+                first_name: None,
+                last_name: None,
+                email_verified: None,
+                external_id: None,
+                metadata: None,
+            })
+            .await
+    }
+
+    /// Deletes every organization and user matching the sandbox prefix.
+    ///
+    /// All pages are listed before anything is deleted, so deletions do not shift the
+    /// pagination cursors.
+    ///
+    /// Returns [`SweepError::NotATestKey`] without deleting anything unless the client uses a
+    /// test API key or [`Sandbox::allow_any_key`] was called.
+    pub async fn sweep(&self) -> Result<SweepReport, SweepError> {
+        if !self.allow_any_key && !self.workos.key().starts_with(Self::TEST_KEY_PREFIX) {
+            return Err(SweepError::NotATestKey);
+        }
+
+        let organizations = self.matching_organizations().await?;
+        for id in &organizations {
+            self.workos
+                .organizations()
+                .delete_organization(&DeleteOrganizationParams {
+                    organization_id: id,
+                })
+                .await
+                .map_err(|source| SweepError::DeleteOrganization {
+                    id: id.clone(),
+                    source,
+                })?;
+        }
+
+        let users = self.matching_users().await?;
+        for id in &users {
+            self.workos
+                .user_management()
+                .delete_user(&DeleteUserParams { user_id: id })
+                .await
+                .map_err(|source| SweepError::DeleteUser {
+                    id: id.clone(),
+                    source,
+                })?;
+        }
+
+        Ok(SweepReport {
+            organizations,
+            users,
+        })
+    }
+
+    async fn matching_organizations(&self) -> Result<Vec<OrganizationId>, SweepError> {
+        let mut ids = Vec::new();
+        let mut after = None;
+
+        loop {
+            let page = self
+                .workos
+                .organizations()
+                .list_organizations(&ListOrganizationsParams {
+                    pagination: PaginationParams {
+                        after: after.as_deref(),
+                        limit: Some(100),
+                        ..Default::default()
+                    },
+                    domains: None,
+                })
+                .await
+                .map_err(SweepError::ListOrganizations)?;
+
+            ids.extend(
+                page.data
+                    .into_iter()
+                    .filter(|organization| organization.name.starts_with(&self.prefix))
+                    .map(|organization| organization.id),
+            );
+
+            match page.metadata.after {
+                Some(cursor) => after = Some(cursor),
+                None => return Ok(ids),
+            }
+        }
+    }
+
+    async fn matching_users(&self) -> Result<Vec<UserId>, SweepError> {
+        let mut ids = Vec::new();
+        let mut after = None;
+
+        loop {
+            let page = self
+                .workos
+                .user_management()
+                .list_users(&ListUsersParams {
+                    pagination: PaginationParams {
+                        after: after.as_deref(),
+                        limit: Some(100),
+                        ..Default::default()
+                    },
+                    email: None,
+                    organization_id: None,
+                })
+                .await
+                .map_err(SweepError::ListUsers)?;
+
+            ids.extend(
+                page.data
+                    .into_iter()
+                    .filter(|user| user.email.starts_with(&self.prefix))
+                    .map(|user| user.id),
+            );
+
+            match page.metadata.after {
+                Some(cursor) => after = Some(cursor),
+                None => return Ok(ids),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+    use mockito::Matcher;
+    use serde_json::json;
+    use tokio;
+
+    use super::*;
+    use crate::ApiKey;
+
+    fn organization(id: &str, name: &str) -> serde_json::Value {
+        json!({
+            "id": id,
+            "object": "organization",
+            "name": name,
+            "allow_profiles_outside_organization": true,
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "domains": [],
+            "updated_at": "2021-06-25T19:07:33.155Z"
+        })
+    }
+
+    fn user(id: &str, email: &str) -> serde_json::Value {
+        json!({
+            "object": "user",
+            "id": id,
+            "email": email,
+            "first_name": null,
+            "last_name": null,
+            "email_verified": false,
+            "profile_picture_url": null,
+            "last_sign_in_at": null,
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z"
+        })
+    }
+
+    #[test]
+    fn it_generates_a_random_prefix() {
+        let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+
+        let first = Sandbox::random(&workos);
+        let second = Sandbox::random(&workos);
+
+        assert!(first.prefix().starts_with(DEFAULT_PREFIX));
+        assert!(first.prefix().ends_with('-'));
+        assert_ne!(first.prefix(), second.prefix());
+    }
+
+    #[test]
+    fn it_rejects_a_short_prefix() {
+        let workos = WorkOs::new(&ApiKey::from("sk_test_123456789"));
+
+        assert_matches!(
+            Sandbox::new(&workos, "").err(),
+            Some(InvalidSandboxPrefix { prefix }) if prefix.is_empty()
+        );
+        assert!(Sandbox::new(&workos, "test-").is_err());
+    }
+
+    #[tokio::test]
+    async fn it_refuses_to_sweep_without_a_test_key() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_live_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let list = server
+            .mock("GET", "/organizations")
+            .match_query(Matcher::Any)
+            .expect(0)
+            .create_async()
+            .await;
+
+        let result = Sandbox::new(&workos, "sdk-test-abc-")
+            .unwrap()
+            .sweep()
+            .await;
+
+        assert_matches!(result, Err(SweepError::NotATestKey));
+        list.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_creates_prefixed_resources() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        server
+            .mock("POST", "/organizations")
+            .match_body(Matcher::PartialJson(json!({
+                "name": "sdk-test-abc-Foo Corp",
+                "allow_profiles_outside_organization": true,
+                "domains": []
+            })))
+            .with_status(201)
+            .with_body(
+                organization("org_01EHZNVPK3SFK441A1RGBFSHRT", "sdk-test-abc-Foo Corp").to_string(),
+            )
+            .create_async()
+            .await;
+
+        server
+            .mock("POST", "/user_management/users")
+            .match_body(Matcher::PartialJson(json!({
+                "email": "sdk-test-abc-marcelina@example.com"
+            })))
+            .with_status(201)
+            .with_body(
+                user(
+                    "user_01E4ZCR3C56J083X43JQXF3JK5",
+                    "sdk-test-abc-marcelina@example.com",
+                )
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let sandbox = Sandbox::new(&workos, "sdk-test-abc-").unwrap();
+
+        let organization = sandbox.create_organization("Foo Corp").await.unwrap();
+        assert_eq!(organization.name, "sdk-test-abc-Foo Corp");
+
+        let user = sandbox.create_user("marcelina@example.com").await.unwrap();
+        assert_eq!(user.email, "sdk-test-abc-marcelina@example.com");
+    }
+
+    #[tokio::test]
+    async fn it_sweeps_resources_matching_the_prefix() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_test_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        server
+            .mock("GET", "/organizations")
+            .match_query(Matcher::UrlEncoded("limit".to_string(), "100".to_string()))
+            .expect(1)
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": [
+                        organization("org_01EHZNVPK3SFK441A1RGBFSHRT", "sdk-test-abc-Foo Corp"),
+                        organization("org_01EHZNVPK3SFK441A1RGBFSHRU", "Real Corp")
+                    ],
+                    "list_metadata": {
+                        "before": null,
+                        "after": "org_01EHZNVPK3SFK441A1RGBFSHRU"
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        server
+            .mock("GET", "/organizations")
+            .match_query(Matcher::UrlEncoded(
+                "after".to_string(),
+                "org_01EHZNVPK3SFK441A1RGBFSHRU".to_string(),
+            ))
+            .expect(1)
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": [
+                        organization("org_01EHZNVPK3SFK441A1RGBFSHRV", "sdk-test-abc-Bar Corp")
+                    ],
+                    "list_metadata": {
+                        "before": "org_01EHZNVPK3SFK441A1RGBFSHRV",
+                        "after": null
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        server
+            .mock("GET", "/user_management/users")
+            .match_query(Matcher::UrlEncoded("limit".to_string(), "100".to_string()))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": [
+                        user("user_01E4ZCR3C56J083X43JQXF3JK5", "sdk-test-abc-marcelina@example.com"),
+                        user("user_01E4ZCR3C56J083X43JQXF3JK6", "marcelina@example.com")
+                    ],
+                    "list_metadata": {
+                        "before": null,
+                        "after": null
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let organization_deleted = server
+            .mock("DELETE", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+            .with_status(202)
+            .expect(1)
+            .create_async()
+            .await;
+        let other_organization_deleted = server
+            .mock("DELETE", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRV")
+            .with_status(202)
+            .expect(1)
+            .create_async()
+            .await;
+        let user_deleted = server
+            .mock(
+                "DELETE",
+                "/user_management/users/user_01E4ZCR3C56J083X43JQXF3JK5",
+            )
+            .with_status(202)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let report = Sandbox::new(&workos, "sdk-test-abc-")
+            .unwrap()
+            .sweep()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            report,
+            SweepReport {
+                organizations: vec![
+                    OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"),
+                    OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRV"),
+                ],
+                users: vec![UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5")],
+            }
+        );
+        organization_deleted.assert_async().await;
+        other_organization_deleted.assert_async().await;
+        user_deleted.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_reports_which_resource_failed_to_delete() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_test_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        server
+            .mock("GET", "/organizations")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": [
+                        organization("org_01EHZNVPK3SFK441A1RGBFSHRT", "sdk-test-abc-Foo Corp")
+                    ],
+                    "list_metadata": {
+                        "before": null,
+                        "after": null
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        server
+            .mock("DELETE", "/organizations/org_01EHZNVPK3SFK441A1RGBFSHRT")
+            .with_status(500)
+            .create_async()
+            .await;
+
+        let result = Sandbox::new(&workos, "sdk-test-abc-")
+            .unwrap()
+            .sweep()
+            .await;
+
+        assert_matches!(
+            result,
+            Err(SweepError::DeleteOrganization { id, .. })
+                if id == OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT")
+        );
+    }
+}
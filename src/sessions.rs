@@ -0,0 +1,34 @@
+//! A module for verifying sessions issued by AuthKit.
+//!
+//! [WorkOS Docs: Sessions](https://workos.com/docs/user-management/sessions)
+
+mod access_token_verifier;
+#[cfg(feature = "axum")]
+mod authkit_router;
+mod authorization_state;
+mod on_tokens_refreshed;
+mod requirement;
+mod seal;
+mod sealed_session;
+mod session_authenticator;
+mod session_manager;
+mod token_store;
+mod types;
+mod workos_session;
+
+#[cfg(test)]
+pub(crate) mod testing;
+
+pub use access_token_verifier::*;
+#[cfg(feature = "axum")]
+pub use authkit_router::*;
+pub use authorization_state::*;
+pub use on_tokens_refreshed::*;
+pub use requirement::*;
+pub use seal::*;
+pub use sealed_session::*;
+pub use session_authenticator::*;
+pub use session_manager::*;
+pub use token_store::*;
+pub use types::*;
+pub use workos_session::*;
@@ -0,0 +1,464 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use jsonwebtoken::errors::ErrorKind;
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+use tokio::sync::RwLock;
+
+use crate::organizations::OrganizationId;
+use crate::sso::{AccessToken, ClientId};
+use crate::user_management::{
+    AuthenticateError, AuthenticateWithRefreshToken, AuthenticateWithRefreshTokenParams,
+    AuthenticationResponse, GetJwks, GetJwksError, RefreshToken,
+};
+use crate::{WorkOs, WorkOsError, WorkOsResult};
+
+/// An error returned from [`AccessTokenVerifier::verify`].
+#[derive(Debug, Error)]
+pub enum VerifyAccessTokenError {
+    /// The access token is malformed, expired, or its signature or claims are invalid.
+    #[error("invalid access token")]
+    InvalidToken(#[from] jsonwebtoken::errors::Error),
+
+    /// The header of the access token does not contain a key ID.
+    #[error("missing key ID")]
+    MissingKeyId,
+
+    /// The access token was signed with a key that is not part of the JWKS.
+    #[error("unknown key ID: {0}")]
+    UnknownKeyId(String),
+
+    /// The JWKS could not be fetched.
+    #[error("failed to fetch JWKS")]
+    Jwks(#[source] WorkOsError<GetJwksError>),
+}
+
+impl VerifyAccessTokenError {
+    /// Whether the access token was rejected only because it has expired.
+    ///
+    /// An expired access token can be exchanged for a new one using the refresh token of the session.
+    pub fn is_expired(&self) -> bool {
+        matches!(self, Self::InvalidToken(err) if matches!(err.kind(), ErrorKind::ExpiredSignature))
+    }
+}
+
+struct JwksCache {
+    jwks: JwkSet,
+    fetched_at: Option<Instant>,
+}
+
+impl Default for JwksCache {
+    fn default() -> Self {
+        Self {
+            jwks: JwkSet { keys: vec![] },
+            fetched_at: None,
+        }
+    }
+}
+
+impl JwksCache {
+    /// Whether the JWKS was fetched within the last [`AccessTokenVerifier::JWKS_REFRESH_INTERVAL`].
+    fn is_fresh(&self) -> bool {
+        self.fetched_at.is_some_and(|fetched_at| {
+            fetched_at.elapsed() < AccessTokenVerifier::JWKS_REFRESH_INTERVAL
+        })
+    }
+}
+
+/// Verifies AuthKit access tokens locally using the JWKS of a client.
+///
+/// The JWKS is fetched on first use and cached. It is fetched again when a token is signed with an unknown key,
+/// so signing key rotations are picked up without a restart.
+///
+/// The verifier is cheap to clone and clones share the cached JWKS.
+///
+/// [WorkOS Docs: Session tokens](https://workos.com/docs/reference/user-management/session-tokens)
+///
+/// # Examples
+///
+/// ```
+/// # use workos_sdk::sessions::*;
+/// # use workos_sdk::sso::{AccessToken, ClientId};
+/// use workos_sdk::{ApiKey, WorkOs};
+///
+/// # async fn run(access_token: &AccessToken) -> Result<(), VerifyAccessTokenError> {
+/// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+///
+/// let verifier = AccessTokenVerifier::new(&workos, &ClientId::from("client_123456789"));
+///
+/// let claims = verifier.verify::<AccessTokenClaims>(access_token).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct AccessTokenVerifier {
+    workos: WorkOs,
+    client_id: ClientId,
+    issuer: Option<String>,
+    audience: Option<String>,
+    leeway: Duration,
+    cache: Arc<RwLock<JwksCache>>,
+}
+
+impl AccessTokenVerifier {
+    /// The minimum time between two fetches of the JWKS triggered by unknown key IDs.
+    ///
+    /// This prevents tokens with made-up key IDs from causing a request to WorkOS each.
+    pub const JWKS_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+    /// Returns a new [`AccessTokenVerifier`] for access tokens issued to the provided client.
+    pub fn new(workos: &WorkOs, client_id: &ClientId) -> Self {
+        Self {
+            workos: workos.clone(),
+            client_id: client_id.clone(),
+            issuer: None,
+            audience: None,
+            leeway: Duration::from_secs(60),
+            cache: Arc::default(),
+        }
+    }
+
+    /// Requires the `iss` claim of access tokens to match the provided issuer, e.g. a custom authentication domain.
+    ///
+    /// By default, the `iss` claim must match the base URL of the client, e.g. `https://api.workos.com`, or the
+    /// issuer of the client ID, e.g. `https://api.workos.com/user_management/client_123456789`.
+    pub fn issuer(mut self, issuer: impl Into<String>) -> Self {
+        self.issuer = Some(issuer.into());
+        self
+    }
+
+    /// Requires the `aud` claim of access tokens to contain the provided audience.
+    pub fn audience(mut self, audience: impl Into<String>) -> Self {
+        self.audience = Some(audience.into());
+        self
+    }
+
+    /// Sets the leeway that is allowed when checking the `exp` and `nbf` claims, which defaults to 60 seconds.
+    pub fn leeway(mut self, leeway: Duration) -> Self {
+        self.leeway = leeway;
+        self
+    }
+
+    /// The client the access tokens are issued to.
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    /// The client used to call WorkOS.
+    pub fn workos(&self) -> &WorkOs {
+        &self.workos
+    }
+
+    /// Exchanges the refresh token for a new access token, issued to the client of the verifier.
+    pub(crate) async fn refresh(
+        &self,
+        refresh_token: &RefreshToken,
+        organization_id: Option<&OrganizationId>,
+    ) -> WorkOsResult<AuthenticationResponse, AuthenticateError> {
+        self.workos
+            .user_management()
+            .authenticate_with_refresh_token(&AuthenticateWithRefreshTokenParams {
+                client_id: &self.client_id,
+                refresh_token,
+                organization_id,
+                ip_address: None,
+                user_agent: None,
+            })
+            .await
+    }
+
+    /// Verifies the signature and claims of the access token and returns its claims.
+    ///
+    /// The claims are usually deserialized into [`AccessTokenClaims`](crate::sessions::AccessTokenClaims).
+    pub async fn verify<C: DeserializeOwned>(
+        &self,
+        access_token: &AccessToken,
+    ) -> Result<C, VerifyAccessTokenError> {
+        let header = jsonwebtoken::decode_header(access_token)?;
+        let key_id = header.kid.ok_or(VerifyAccessTokenError::MissingKeyId)?;
+
+        let key = self.decoding_key(&key_id).await?;
+
+        let mut validation = Validation::new(Algorithm::RS256);
+        validation.leeway = self.leeway.as_secs();
+        validation.set_required_spec_claims(&["exp", "iss"]);
+        validation.set_issuer(&self.issuers());
+
+        match &self.audience {
+            Some(audience) => validation.set_audience(&[audience]),
+            None => validation.validate_aud = false,
+        }
+
+        let token = jsonwebtoken::decode::<C>(access_token, &key, &validation)?;
+
+        Ok(token.claims)
+    }
+
+    /// The accepted values of the `iss` claim.
+    fn issuers(&self) -> Vec<String> {
+        match &self.issuer {
+            Some(issuer) => vec![issuer.clone()],
+            None => {
+                let base_url = self.workos.base_url().as_str().trim_end_matches('/');
+
+                vec![
+                    base_url.to_string(),
+                    format!("{base_url}/user_management/{}", self.client_id),
+                ]
+            }
+        }
+    }
+
+    async fn decoding_key(&self, key_id: &str) -> Result<DecodingKey, VerifyAccessTokenError> {
+        {
+            let cache = self.cache.read().await;
+
+            if let Some(jwk) = cache.jwks.find(key_id) {
+                return Ok(DecodingKey::from_jwk(jwk)?);
+            }
+
+            if cache.is_fresh() {
+                return Err(VerifyAccessTokenError::UnknownKeyId(key_id.to_string()));
+            }
+        }
+
+        let mut cache = self.cache.write().await;
+
+        // Another task may have refreshed the JWKS while we were waiting for the lock.
+        if !cache.is_fresh() {
+            cache.jwks = self
+                .workos
+                .user_management()
+                .get_jwks(&self.client_id)
+                .await
+                .map_err(VerifyAccessTokenError::Jwks)?;
+            cache.fetched_at = Some(Instant::now());
+        }
+
+        let jwk = cache
+            .jwks
+            .find(key_id)
+            .ok_or_else(|| VerifyAccessTokenError::UnknownKeyId(key_id.to_string()))?;
+
+        Ok(DecodingKey::from_jwk(jwk)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+    use serde_json::{Value, json};
+    use tokio;
+
+    use crate::ApiKey;
+    use crate::sessions::{AccessTokenClaims, testing};
+    use crate::user_management::SessionId;
+
+    use super::*;
+
+    fn verifier(server: &mockito::Server) -> AccessTokenVerifier {
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        AccessTokenVerifier::new(&workos, &ClientId::from("client_123456789"))
+            .issuer("https://api.workos.com")
+    }
+
+    fn claims(exp_offset: i64) -> Value {
+        json!({
+            "iss": "https://api.workos.com",
+            "sub": "user_01E4ZCR3C56J083X43JQXF3JK5",
+            "sid": "session_01H93ZY4F80QPBEZ1R5B2SHQG8",
+            "exp": testing::timestamp(exp_offset),
+            "iat": testing::timestamp(-60)
+        })
+    }
+
+    #[tokio::test]
+    async fn it_verifies_an_access_token_and_caches_the_jwks() {
+        let mut server = mockito::Server::new_async().await;
+
+        let jwks = server
+            .mock("GET", "/sso/jwks/client_123456789")
+            .with_status(200)
+            .with_body(testing::jwks().to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let verifier = verifier(&server);
+        let access_token = testing::sign(&claims(300));
+
+        for _ in 0..2 {
+            let claims = verifier
+                .verify::<AccessTokenClaims>(&access_token)
+                .await
+                .unwrap();
+
+            assert_eq!(
+                claims.sid,
+                SessionId::from("session_01H93ZY4F80QPBEZ1R5B2SHQG8")
+            );
+        }
+
+        jwks.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_refreshes_the_jwks_when_the_key_id_is_unknown() {
+        let mut server = mockito::Server::new_async().await;
+
+        let jwks = server
+            .mock("GET", "/sso/jwks/client_123456789")
+            .with_status(200)
+            .with_body(testing::jwks().to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let verifier = verifier(&server);
+        *verifier.cache.write().await = JwksCache {
+            jwks: JwkSet { keys: vec![] },
+            fetched_at: Instant::now().checked_sub(AccessTokenVerifier::JWKS_REFRESH_INTERVAL),
+        };
+
+        let result = verifier.verify::<Value>(&testing::sign(&claims(300))).await;
+
+        assert_matches!(result, Ok(_));
+        jwks.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_does_not_refresh_the_jwks_again_within_the_refresh_interval() {
+        let mut server = mockito::Server::new_async().await;
+
+        let jwks = server
+            .mock("GET", "/sso/jwks/client_123456789")
+            .with_status(200)
+            .with_body(testing::jwks().to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let verifier = verifier(&server);
+        let access_token = testing::sign_with_key_id(&claims(300), "unknown_key");
+
+        for _ in 0..2 {
+            let result = verifier.verify::<Value>(&access_token).await;
+
+            assert_matches!(result, Err(VerifyAccessTokenError::UnknownKeyId(key_id)) if key_id == "unknown_key");
+        }
+
+        jwks.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_rejects_an_expired_access_token() {
+        let mut server = mockito::Server::new_async().await;
+
+        server
+            .mock("GET", "/sso/jwks/client_123456789")
+            .with_status(200)
+            .with_body(testing::jwks().to_string())
+            .create_async()
+            .await;
+
+        let result = verifier(&server)
+            .verify::<Value>(&testing::sign(&claims(-300)))
+            .await;
+
+        assert_matches!(result, Err(ref err) if err.is_expired())
+    }
+
+    #[tokio::test]
+    async fn it_rejects_an_access_token_from_another_issuer() {
+        let mut server = mockito::Server::new_async().await;
+
+        server
+            .mock("GET", "/sso/jwks/client_123456789")
+            .with_status(200)
+            .with_body(testing::jwks().to_string())
+            .create_async()
+            .await;
+
+        let mut claims = claims(300);
+        claims["iss"] = json!("https://example.com");
+
+        let result = verifier(&server)
+            .verify::<Value>(&testing::sign(&claims))
+            .await;
+
+        assert_matches!(result, Err(VerifyAccessTokenError::InvalidToken(ref err)) if matches!(err.kind(), ErrorKind::InvalidIssuer))
+    }
+
+    #[tokio::test]
+    async fn it_validates_the_issuer_against_the_base_url_by_default() {
+        let mut server = mockito::Server::new_async().await;
+
+        server
+            .mock("GET", "/sso/jwks/client_123456789")
+            .with_status(200)
+            .with_body(testing::jwks().to_string())
+            .create_async()
+            .await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+        let verifier = AccessTokenVerifier::new(&workos, &ClientId::from("client_123456789"));
+
+        for issuer in [
+            server.url(),
+            format!("{}/user_management/client_123456789", server.url()),
+        ] {
+            let mut claims = claims(300);
+            claims["iss"] = json!(issuer);
+
+            let result = verifier.verify::<Value>(&testing::sign(&claims)).await;
+
+            assert_matches!(result, Ok(_));
+        }
+
+        let result = verifier.verify::<Value>(&testing::sign(&claims(300))).await;
+
+        assert_matches!(result, Err(VerifyAccessTokenError::InvalidToken(ref err)) if matches!(err.kind(), ErrorKind::InvalidIssuer));
+
+        let mut claims = claims(300);
+        claims.as_object_mut().unwrap().remove("iss");
+
+        let result = verifier.verify::<Value>(&testing::sign(&claims)).await;
+
+        assert_matches!(result, Err(VerifyAccessTokenError::InvalidToken(ref err)) if matches!(err.kind(), ErrorKind::MissingRequiredClaim(_)));
+    }
+
+    #[tokio::test]
+    async fn it_rejects_an_access_token_with_a_tampered_payload() {
+        let mut server = mockito::Server::new_async().await;
+
+        server
+            .mock("GET", "/sso/jwks/client_123456789")
+            .with_status(200)
+            .with_body(testing::jwks().to_string())
+            .create_async()
+            .await;
+
+        let access_token = testing::sign(&claims(300));
+        let forged =
+            testing::sign(&json!({ "sub": "user_forged", "exp": testing::timestamp(300) }));
+
+        let mut parts = access_token.split('.').collect::<Vec<_>>();
+        parts[1] = forged.split('.').nth(1).unwrap();
+
+        let result = verifier(&server)
+            .verify::<Value>(&AccessToken::from(parts.join(".")))
+            .await;
+
+        assert_matches!(result, Err(VerifyAccessTokenError::InvalidToken(ref err)) if matches!(err.kind(), ErrorKind::InvalidSignature))
+    }
+}
@@ -0,0 +1,586 @@
+use axum::Router;
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode, Uri, header};
+use axum::response::{IntoResponse, Redirect, Response};
+use axum::routing::get;
+use thiserror::Error;
+use url::Url;
+
+use crate::WorkOsError;
+use crate::sessions::workos_session::http::{cookie, set_cookie};
+use crate::sessions::{
+    AuthorizationState, SealSessionError, SessionAuthenticator, SessionData,
+    VerifyAuthorizationStateError,
+};
+use crate::sso::AuthorizationCode;
+use crate::user_management::{
+    AuthenticateError, AuthenticateWithCode, AuthenticateWithCodeParams, CodeChallenge,
+    ConnectionSelector, GetAuthorizationUrl, GetAuthorizationUrlParams, Provider,
+};
+
+/// The configuration of the routes mounted by [`authkit_router`].
+///
+/// # Examples
+///
+/// ```
+/// # use workos_sdk::sessions::*;
+/// # fn run(authenticator: SessionAuthenticator) {
+/// let config = AuthKitConfig::new(authenticator, "https://your-app.com/callback")
+///     .return_to("/dashboard");
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct AuthKitConfig {
+    authenticator: SessionAuthenticator,
+    redirect_uri: String,
+    return_to: String,
+    logout_return_to: Option<Url>,
+    state_cookie_name: String,
+}
+
+impl AuthKitConfig {
+    /// Returns a new [`AuthKitConfig`].
+    ///
+    /// The redirect URI must point to the `/callback` route and be configured in the WorkOS dashboard.
+    pub fn new(authenticator: SessionAuthenticator, redirect_uri: impl Into<String>) -> Self {
+        Self {
+            authenticator,
+            redirect_uri: redirect_uri.into(),
+            return_to: "/".to_string(),
+            logout_return_to: None,
+            state_cookie_name: AuthorizationState::DEFAULT_COOKIE_NAME.to_string(),
+        }
+    }
+
+    /// Sets where users are redirected after signing in, which defaults to `/`.
+    ///
+    /// The `/login` route accepts a `return_to` query parameter with a relative path that takes precedence.
+    pub fn return_to(mut self, return_to: impl Into<String>) -> Self {
+        self.return_to = return_to.into();
+        self
+    }
+
+    /// Sets where WorkOS redirects users after signing out, which defaults to the URL configured in the
+    /// WorkOS dashboard.
+    pub fn logout_return_to(mut self, logout_return_to: Url) -> Self {
+        self.logout_return_to = Some(logout_return_to);
+        self
+    }
+
+    /// Sets the name of the cookie that stores the state of a pending sign in.
+    pub fn state_cookie_name(mut self, state_cookie_name: impl Into<String>) -> Self {
+        self.state_cookie_name = state_cookie_name.into();
+        self
+    }
+}
+
+/// An error returned from the `/callback` route of [`authkit_router`].
+#[derive(Debug, Error)]
+pub enum AuthKitCallbackError {
+    /// WorkOS redirected back with an error instead of an authorization code.
+    #[error("authorization failed: {0}")]
+    Authorization(String),
+
+    /// The callback is missing the authorization code.
+    #[error("missing authorization code")]
+    MissingCode,
+
+    /// The state does not match the state cookie, or the state cookie has expired.
+    #[error("invalid state")]
+    InvalidState(#[from] VerifyAuthorizationStateError),
+
+    /// The authorization code could not be exchanged for a session.
+    #[error("failed to authenticate")]
+    Authenticate(#[source] WorkOsError<AuthenticateError>),
+
+    /// The session could not be sealed.
+    #[error(transparent)]
+    Seal(#[from] SealSessionError),
+}
+
+impl AuthKitCallbackError {
+    /// The HTTP status code of the response for this error.
+    pub fn status_code(&self) -> u16 {
+        match self {
+            Self::Authorization(_)
+            | Self::MissingCode
+            | Self::InvalidState(_)
+            | Self::Authenticate(WorkOsError::Operation(_)) => 400,
+            Self::Authenticate(_) | Self::Seal(_) => 500,
+        }
+    }
+}
+
+impl IntoResponse for AuthKitCallbackError {
+    fn into_response(self) -> Response {
+        let status =
+            StatusCode::from_u16(self.status_code()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+        (status, self.to_string()).into_response()
+    }
+}
+
+/// Returns a router implementing the AuthKit sign in flow.
+///
+/// - `/login` redirects to AuthKit, storing the [`AuthorizationState`] with the PKCE code verifier in a cookie.
+/// - `/callback` validates the state, exchanges the authorization code for a session and stores it in the
+///   session cookie, then redirects to the `return_to` parameter of `/login` or [`AuthKitConfig::return_to`].
+/// - `/logout` clears the session cookie and redirects to the WorkOS logout URL of the session, see
+///   [`SessionAuthenticator::logout`].
+///
+/// [WorkOS Docs: AuthKit](https://workos.com/docs/user-management/authkit)
+///
+/// # Examples
+///
+/// ```
+/// # use axum::routing::get;
+/// # use axum::{Router, middleware};
+/// # use workos_sdk::sessions::*;
+/// # fn run(authenticator: SessionAuthenticator) {
+/// let authenticator = authenticator.sign_in_url("/auth/login");
+///
+/// let app: Router = Router::new()
+///     .route("/dashboard", get(|session: WorkOsSession| async move {
+///         format!("Hello, {}!", session.claims.sub)
+///     }))
+///     .route_layer(middleware::from_fn_with_state(authenticator.clone(), require_session))
+///     .nest(
+///         "/auth",
+///         authkit_router(AuthKitConfig::new(
+///             authenticator.clone(),
+///             "https://your-app.com/auth/callback",
+///         )),
+///     )
+///     .with_state(authenticator);
+/// # }
+/// ```
+pub fn authkit_router<S>(config: AuthKitConfig) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .route("/login", get(login))
+        .route("/callback", get(callback))
+        .route("/logout", get(logout))
+        .with_state(config)
+}
+
+async fn login(State(config): State<AuthKitConfig>, uri: Uri) -> Response {
+    let mut authorization_state = AuthorizationState::new();
+
+    // Only local paths are accepted, so the login route cannot be used as an open redirect.
+    if let Some(return_to) = query(&uri)
+        .find(|(key, _)| key == "return_to")
+        .map(|(_, value)| value)
+        .filter(|return_to| is_local_path(return_to))
+    {
+        authorization_state = authorization_state.return_to(return_to);
+    }
+
+    let code_challenge = authorization_state.code_challenge();
+
+    let authorization_url = config
+        .authenticator
+        .verifier()
+        .workos()
+        .user_management()
+        .get_authorization_url(&GetAuthorizationUrlParams {
+            client_id: config.authenticator.verifier().client_id(),
+            redirect_uri: &config.redirect_uri,
+            connection_selector: ConnectionSelector::Provider(&Provider::AuthKit {
+                screen_hint: None,
+            }),
+            state: Some(&authorization_state.state),
+            code_challenge: Some(CodeChallenge::S256(&code_challenge)),
+            login_hint: None,
+            domain_hint: None,
+        });
+
+    let (Ok(authorization_url), Ok(state_cookie)) = (
+        authorization_url,
+        authorization_state.cookie(
+            &config.state_cookie_name,
+            config.authenticator.cookie_password(),
+        ),
+    ) else {
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    };
+
+    let mut response = Redirect::to(authorization_url.as_str()).into_response();
+    set_cookie(response.headers_mut(), &state_cookie);
+    response
+}
+
+async fn callback(
+    State(config): State<AuthKitConfig>,
+    uri: Uri,
+    headers: HeaderMap,
+) -> Result<Response, AuthKitCallbackError> {
+    let (mut code, mut state, mut error) = (None, None, None);
+    for (key, value) in query(&uri) {
+        match key.as_str() {
+            "code" => code = Some(value),
+            "state" => state = Some(value),
+            "error_description" => error = Some(value),
+            "error" if error.is_none() => error = Some(value),
+            _ => {}
+        }
+    }
+
+    if let Some(error) = error {
+        return Err(AuthKitCallbackError::Authorization(error));
+    }
+
+    let code = code.ok_or(AuthKitCallbackError::MissingCode)?;
+
+    let authorization_state = AuthorizationState::verify(
+        cookie(&headers, &config.state_cookie_name),
+        state.as_deref(),
+        config.authenticator.cookie_password(),
+    )?;
+
+    let authentication_response = config
+        .authenticator
+        .verifier()
+        .workos()
+        .user_management()
+        .authenticate_with_code(&AuthenticateWithCodeParams {
+            client_id: config.authenticator.verifier().client_id(),
+            code_verifier: Some(&authorization_state.code_verifier),
+            code: &AuthorizationCode::from(code),
+            invitation_token: None,
+            ip_address: None,
+            user_agent: headers
+                .get(header::USER_AGENT)
+                .and_then(|value| value.to_str().ok()),
+        })
+        .await
+        .map_err(AuthKitCallbackError::Authenticate)?;
+
+    let sealed_session =
+        SessionData::from(authentication_response).seal(config.authenticator.cookie_password())?;
+
+    let return_to = authorization_state
+        .return_to
+        .as_deref()
+        .unwrap_or(&config.return_to);
+
+    let mut response = Redirect::to(return_to).into_response();
+    set_cookie(
+        response.headers_mut(),
+        &config.authenticator.session_cookie(&sealed_session),
+    );
+    set_cookie(
+        response.headers_mut(),
+        &AuthorizationState::clear_cookie(&config.state_cookie_name),
+    );
+    Ok(response)
+}
+
+/// Whether the value is an absolute path without a scheme or authority, so redirecting to it stays on the same origin.
+///
+/// Backslashes are rejected anywhere, including percent-encoded, since browsers treat `/\` like `//`.
+fn is_local_path(value: &str) -> bool {
+    let base = Url::parse("http://localhost").expect("base URL is valid");
+
+    value.starts_with('/')
+        && !value.starts_with("//")
+        && !value.chars().any(|char| char == '\\' || char.is_control())
+        && !value.to_ascii_lowercase().contains("%5c")
+        && base
+            .join(value)
+            .is_ok_and(|url| url.origin() == base.origin())
+}
+
+fn query(uri: &Uri) -> impl Iterator<Item = (String, String)> {
+    url::form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes()).into_owned()
+}
+
+async fn logout(State(config): State<AuthKitConfig>, headers: HeaderMap) -> Response {
+    let logout = cookie(&headers, &config.authenticator.cookie_name).and_then(|sealed_session| {
+        config
+            .authenticator
+            .logout(sealed_session, config.logout_return_to.as_ref())
+            .ok()
+    });
+
+    let location = match (&logout, &config.logout_return_to) {
+        (Some(logout), _) => logout.logout_url.as_str(),
+        (None, Some(logout_return_to)) => logout_return_to.as_str(),
+        (None, None) => "/",
+    };
+
+    let mut response = Redirect::to(location).into_response();
+    set_cookie(
+        response.headers_mut(),
+        &config.authenticator.clear_session_cookie(),
+    );
+    response
+}
+
+#[cfg(test)]
+mod test {
+    use axum::body::Body;
+    use axum::http::Request;
+    use mockito::Matcher;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::sessions::{AccessTokenVerifier, CookiePassword, testing};
+    use crate::sso::ClientId;
+    use crate::user_management::AuthenticationResponse;
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    fn router(server: &mockito::Server) -> Router {
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        let authenticator = SessionAuthenticator::new(
+            AccessTokenVerifier::new(&workos, &ClientId::from("client_123456789"))
+                .issuer("https://api.workos.com"),
+            &CookiePassword::from("DQhXs6bZPy8pYbd8bQJAVvoaXUwXSvLJ"),
+        );
+
+        authkit_router(
+            AuthKitConfig::new(authenticator, "https://your-app.com/callback")
+                .return_to("/dashboard"),
+        )
+    }
+
+    fn set_cookies(response: &Response) -> Vec<&str> {
+        response
+            .headers()
+            .get_all(header::SET_COOKIE)
+            .iter()
+            .map(|value| value.to_str().unwrap())
+            .collect()
+    }
+
+    fn password() -> CookiePassword {
+        CookiePassword::from("DQhXs6bZPy8pYbd8bQJAVvoaXUwXSvLJ")
+    }
+
+    #[tokio::test]
+    async fn it_redirects_to_authkit_with_a_state_cookie() {
+        let server = mockito::Server::new_async().await;
+
+        let response = router(&server)
+            .oneshot(
+                Request::get("/login?return_to=%2Freports")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+
+        let location = Url::parse(
+            response
+                .headers()
+                .get(header::LOCATION)
+                .unwrap()
+                .to_str()
+                .unwrap(),
+        )
+        .unwrap();
+        let query = |name: &str| {
+            location
+                .query_pairs()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.into_owned())
+        };
+
+        assert_eq!(location.path(), "/user_management/authorize");
+        assert_eq!(query("provider").as_deref(), Some("authkit"));
+
+        let cookies = set_cookies(&response);
+        let sealed = cookies[0]
+            .strip_prefix("wos-auth-state=")
+            .unwrap()
+            .split(';')
+            .next()
+            .unwrap();
+        let authorization_state =
+            AuthorizationState::verify(Some(sealed), query("state").as_deref(), &password())
+                .unwrap();
+
+        assert_eq!(authorization_state.return_to.as_deref(), Some("/reports"));
+        assert_eq!(query("code_challenge_method").as_deref(), Some("S256"));
+        assert_eq!(
+            query("code_challenge"),
+            Some(authorization_state.code_challenge())
+        );
+        assert!(cookies[0].ends_with("; Max-Age=600"))
+    }
+
+    #[tokio::test]
+    async fn it_ignores_an_absolute_return_to() {
+        let server = mockito::Server::new_async().await;
+
+        for return_to in [
+            "%2F%2Fevil.example.com",
+            "%2F%5Cevil.example.com",
+            "%2F%255Cevil.example.com",
+            "%2F%09%2Fevil.example.com",
+            "https%3A%2F%2Fevil.example.com",
+        ] {
+            let response = router(&server)
+                .oneshot(
+                    Request::get(format!("/login?return_to={return_to}"))
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+
+            let cookies = set_cookies(&response);
+            let sealed = cookies[0]
+                .strip_prefix("wos-auth-state=")
+                .unwrap()
+                .split(';')
+                .next()
+                .unwrap();
+
+            assert_eq!(
+                AuthorizationState::unseal(sealed, &password())
+                    .unwrap()
+                    .return_to,
+                None,
+                "{return_to} should be ignored"
+            )
+        }
+    }
+
+    #[tokio::test]
+    async fn it_exchanges_the_code_and_sets_the_session_cookie() {
+        let mut server = mockito::Server::new_async().await;
+        let authorization_state = AuthorizationState::new();
+
+        server
+            .mock("POST", "/user_management/authenticate")
+            .match_body(Matcher::PartialJson(json!({
+                "grant_type": "authorization_code",
+                "code": "01E2RJ4C05B52KKZ8FSRDAP23J",
+                "code_verifier": authorization_state.code_verifier,
+            })))
+            .with_status(200)
+            .with_body(
+                testing::authentication_response(
+                    &testing::access_token(300),
+                    "yAjhKk123NLIjdrBdGZPf8pLIDvK",
+                )
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let response = router(&server)
+            .oneshot(
+                Request::get(format!(
+                    "/callback?code=01E2RJ4C05B52KKZ8FSRDAP23J&state={}",
+                    authorization_state.state
+                ))
+                .header(
+                    header::COOKIE,
+                    format!(
+                        "wos-auth-state={}",
+                        authorization_state.seal(&password()).unwrap()
+                    ),
+                )
+                .body(Body::empty())
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+        assert_eq!(
+            response.headers().get(header::LOCATION).unwrap(),
+            "/dashboard"
+        );
+
+        let cookies = set_cookies(&response);
+        let sealed_session = cookies[0]
+            .strip_prefix("wos-session=")
+            .unwrap()
+            .split(';')
+            .next()
+            .unwrap();
+
+        let data = SessionData::unseal(sealed_session, &password()).unwrap();
+
+        assert_eq!(data.user.id.to_string(), "user_01E4ZCR3C56J083X43JQXF3JK5");
+        assert!(cookies[1].starts_with("wos-auth-state=; "))
+    }
+
+    #[tokio::test]
+    async fn it_rejects_a_callback_with_a_mismatched_state() {
+        let server = mockito::Server::new_async().await;
+
+        let response = router(&server)
+            .oneshot(
+                Request::get("/callback?code=01E2RJ4C05B52KKZ8FSRDAP23J&state=7d5c1f6a")
+                    .header(
+                        header::COOKIE,
+                        format!(
+                            "wos-auth-state={}",
+                            AuthorizationState::new().seal(&password()).unwrap()
+                        ),
+                    )
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST)
+    }
+
+    #[tokio::test]
+    async fn it_redirects_to_the_logout_url_and_clears_the_session_cookie() {
+        let server = mockito::Server::new_async().await;
+
+        let sealed_session = SessionData::from(
+            serde_json::from_value::<AuthenticationResponse>(testing::authentication_response(
+                &testing::access_token(-300),
+                "yAjhKk123NLIjdrBdGZPf8pLIDvK",
+            ))
+            .unwrap(),
+        )
+        .seal(&password())
+        .unwrap();
+
+        let response = router(&server)
+            .oneshot(
+                Request::get("/logout")
+                    .header(header::COOKIE, format!("wos-session={sealed_session}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+        assert_eq!(
+            response
+                .headers()
+                .get(header::LOCATION)
+                .unwrap()
+                .to_str()
+                .unwrap(),
+            format!(
+                "{}/user_management/sessions/logout?session_id=session_01H93ZY4F80QPBEZ1R5B2SHQG8",
+                server.url()
+            )
+        );
+        assert_eq!(
+            set_cookies(&response),
+            vec!["wos-session=; Path=/; HttpOnly; Secure; SameSite=Lax; Max-Age=0"]
+        )
+    }
+}
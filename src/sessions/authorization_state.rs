@@ -0,0 +1,243 @@
+use std::time::Duration;
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::sessions::seal::{seal_json, unseal_json};
+use crate::sessions::{CookiePassword, SealSessionError, UnsealSessionError};
+
+/// An error returned from [`AuthorizationState::verify`].
+#[derive(Debug, Error)]
+pub enum VerifyAuthorizationStateError {
+    /// The request does not contain the state cookie, which may have expired.
+    #[error("missing state cookie")]
+    MissingCookie,
+
+    /// The state cookie could not be unsealed.
+    #[error("invalid state cookie")]
+    InvalidCookie(#[from] UnsealSessionError),
+
+    /// The `state` parameter of the callback does not match the state cookie.
+    #[error("state mismatch")]
+    StateMismatch,
+}
+
+/// The state of a pending sign in, stored in a short-lived cookie between the redirect to the authorization URL and
+/// the callback.
+///
+/// The state is sealed with the cookie password, so the PKCE code verifier stays secret and the cookie cannot be
+/// forged. Validating the `state` parameter of the callback against the cookie protects against CSRF attacks.
+///
+/// [WorkOS Docs: PKCE](https://workos.com/docs/reference/user-management/authentication/get-authorization-url/pkce)
+///
+/// # Examples
+///
+/// ```
+/// # use workos_sdk::sessions::*;
+/// # use workos_sdk::sso::ClientId;
+/// # use workos_sdk::user_management::*;
+/// # use workos_sdk::{ApiKey, WorkOs};
+/// # fn login(workos: &WorkOs, cookie_password: &CookiePassword) -> Result<(), Box<dyn std::error::Error>> {
+/// let authorization_state = AuthorizationState::new().return_to("/dashboard");
+/// let code_challenge = authorization_state.code_challenge();
+///
+/// let authorization_url = workos
+///     .user_management()
+///     .get_authorization_url(&GetAuthorizationUrlParams {
+///         client_id: &ClientId::from("client_123456789"),
+///         redirect_uri: "https://your-app.com/callback",
+///         connection_selector: ConnectionSelector::Provider(&Provider::AuthKit {
+///             screen_hint: None,
+///         }),
+///         state: Some(&authorization_state.state),
+///         code_challenge: Some(CodeChallenge::S256(&code_challenge)),
+///         login_hint: None,
+///         domain_hint: None,
+///     })?;
+///
+/// let set_cookie = authorization_state.cookie(AuthorizationState::DEFAULT_COOKIE_NAME, cookie_password)?;
+/// // Redirect to the authorization URL and set the cookie.
+/// # Ok(())
+/// # }
+///
+/// # fn callback(cookie: Option<&str>, state: Option<&str>, cookie_password: &CookiePassword) -> Result<(), VerifyAuthorizationStateError> {
+/// let authorization_state = AuthorizationState::verify(cookie, state, cookie_password)?;
+/// // Exchange the code with `authorization_state.code_verifier` and clear the cookie.
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthorizationState {
+    /// The random value passed as the `state` parameter of the authorization URL.
+    pub state: String,
+
+    /// The PKCE code verifier, which must be passed when exchanging the authorization code.
+    pub code_verifier: String,
+
+    /// Where the user should be redirected after signing in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_to: Option<String>,
+}
+
+impl Default for AuthorizationState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AuthorizationState {
+    /// The default name of the state cookie.
+    pub const DEFAULT_COOKIE_NAME: &str = "wos-auth-state";
+
+    /// How long a sign in may take before the state cookie expires.
+    pub const TTL: Duration = Duration::from_secs(10 * 60);
+
+    /// Returns a new [`AuthorizationState`] with a random state and code verifier.
+    pub fn new() -> Self {
+        let mut state = [0; 16];
+        rand::rng().fill_bytes(&mut state);
+
+        let mut code_verifier = [0; 32];
+        rand::rng().fill_bytes(&mut code_verifier);
+
+        Self {
+            state: hex::encode(state),
+            code_verifier: URL_SAFE_NO_PAD.encode(code_verifier),
+            return_to: None,
+        }
+    }
+
+    /// Sets where the user should be redirected after signing in.
+    pub fn return_to(mut self, return_to: impl Into<String>) -> Self {
+        self.return_to = Some(return_to.into());
+        self
+    }
+
+    /// Returns the S256 code challenge derived from the code verifier, to be passed as
+    /// [`CodeChallenge::S256`](crate::user_management::CodeChallenge::S256) to the authorization URL.
+    pub fn code_challenge(&self) -> String {
+        URL_SAFE_NO_PAD.encode(Sha256::digest(self.code_verifier.as_bytes()))
+    }
+
+    /// Seals the state with the provided password, so it expires after [`AuthorizationState::TTL`].
+    pub fn seal(&self, password: &CookiePassword) -> Result<String, SealSessionError> {
+        seal_json(self, password, Some(Self::TTL))
+    }
+
+    /// Unseals a state that was sealed with the provided password.
+    pub fn unseal(sealed: &str, password: &CookiePassword) -> Result<Self, UnsealSessionError> {
+        unseal_json(sealed, password)
+    }
+
+    /// Returns the value of a `Set-Cookie` header that stores the sealed state.
+    pub fn cookie(
+        &self,
+        cookie_name: &str,
+        password: &CookiePassword,
+    ) -> Result<String, SealSessionError> {
+        Ok(format!(
+            "{cookie_name}={}; Path=/; HttpOnly; Secure; SameSite=Lax; Max-Age={}",
+            self.seal(password)?,
+            Self::TTL.as_secs()
+        ))
+    }
+
+    /// Returns the value of a `Set-Cookie` header that clears the state cookie.
+    pub fn clear_cookie(cookie_name: &str) -> String {
+        format!("{cookie_name}=; Path=/; HttpOnly; Secure; SameSite=Lax; Max-Age=0")
+    }
+
+    /// Unseals the state cookie and checks that it matches the `state` parameter of the callback.
+    pub fn verify(
+        cookie: Option<&str>,
+        state: Option<&str>,
+        password: &CookiePassword,
+    ) -> Result<Self, VerifyAuthorizationStateError> {
+        let cookie = cookie.ok_or(VerifyAuthorizationStateError::MissingCookie)?;
+        let authorization_state = Self::unseal(cookie, password)?;
+
+        if state != Some(authorization_state.state.as_str()) {
+            return Err(VerifyAuthorizationStateError::StateMismatch);
+        }
+
+        Ok(authorization_state)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+
+    use super::*;
+
+    fn password() -> CookiePassword {
+        CookiePassword::from("DQhXs6bZPy8pYbd8bQJAVvoaXUwXSvLJ")
+    }
+
+    #[test]
+    fn it_derives_the_code_challenge_from_the_code_verifier() {
+        // From RFC 7636, Appendix B.
+        let authorization_state = AuthorizationState {
+            state: "7d5c1f6a".to_string(),
+            code_verifier: "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk".to_string(),
+            return_to: None,
+        };
+
+        assert_eq!(
+            authorization_state.code_challenge(),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        )
+    }
+
+    #[test]
+    fn it_verifies_the_state_of_the_callback() {
+        let authorization_state = AuthorizationState::new().return_to("/dashboard");
+        let sealed = authorization_state.seal(&password()).unwrap();
+
+        assert_eq!(
+            AuthorizationState::verify(
+                Some(&sealed),
+                Some(&authorization_state.state),
+                &password()
+            )
+            .unwrap(),
+            authorization_state
+        )
+    }
+
+    #[test]
+    fn it_rejects_a_mismatched_state() {
+        let sealed = AuthorizationState::new().seal(&password()).unwrap();
+
+        assert_matches!(
+            AuthorizationState::verify(Some(&sealed), Some("7d5c1f6a"), &password()),
+            Err(VerifyAuthorizationStateError::StateMismatch)
+        );
+        assert_matches!(
+            AuthorizationState::verify(Some(&sealed), None, &password()),
+            Err(VerifyAuthorizationStateError::StateMismatch)
+        );
+        assert_matches!(
+            AuthorizationState::verify(None, Some("7d5c1f6a"), &password()),
+            Err(VerifyAuthorizationStateError::MissingCookie)
+        )
+    }
+
+    #[test]
+    fn it_rejects_a_forged_state_cookie() {
+        let sealed = AuthorizationState::new()
+            .seal(&CookiePassword::from("ynWiSaHXvqWB1HknDNa6ZYkfmU6cxQvS"))
+            .unwrap();
+
+        assert_matches!(
+            AuthorizationState::verify(Some(&sealed), Some("7d5c1f6a"), &password()),
+            Err(VerifyAuthorizationStateError::InvalidCookie(
+                UnsealSessionError::IntegrityMismatch
+            ))
+        )
+    }
+}
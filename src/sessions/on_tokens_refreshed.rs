@@ -0,0 +1,257 @@
+use std::error::Error;
+use std::future::Future;
+use std::sync::Arc;
+
+use futures_util::future::BoxFuture;
+use thiserror::Error;
+
+use crate::WorkOsError;
+use crate::organizations::OrganizationId;
+use crate::sessions::{AccessTokenVerifier, SessionTokens};
+use crate::user_management::{AuthenticateError, AuthenticationResponse, RefreshToken};
+
+/// An error returned from an [`OnTokensRefreshed`] hook.
+pub type TokensRefreshedError = Box<dyn Error + Send + Sync>;
+
+type Hook =
+    dyn Fn(SessionTokens) -> BoxFuture<'static, Result<(), TokensRefreshedError>> + Send + Sync;
+
+/// A hook that is called with the new tokens after a refresh token has been exchanged.
+///
+/// When refresh token rotation is enforced, the previous refresh token can no longer be used once it has been
+/// exchanged. Use this hook to persist the new tokens before they are handed out, so the session is not lost when the
+/// process exits.
+///
+/// # Examples
+///
+/// ```
+/// # use workos_sdk::sessions::*;
+/// let on_tokens_refreshed = OnTokensRefreshed::new(|tokens: SessionTokens| async move {
+///     // Persist the refreshed tokens.
+///     Ok(())
+/// });
+/// ```
+#[derive(Clone)]
+pub struct OnTokensRefreshed(Arc<Hook>);
+
+impl OnTokensRefreshed {
+    /// Returns a new [`OnTokensRefreshed`] hook calling the provided function.
+    pub fn new<F, Fut>(hook: F) -> Self
+    where
+        F: Fn(SessionTokens) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), TokensRefreshedError>> + Send + 'static,
+    {
+        Self(Arc::new(move |tokens| Box::pin(hook(tokens))))
+    }
+
+    /// Calls the hook with the provided tokens.
+    pub async fn call(&self, tokens: &SessionTokens) -> Result<(), TokensRefreshedError> {
+        (self.0)(tokens.clone()).await
+    }
+}
+
+/// An error returned from [`AccessTokenVerifier::authenticate_with_refresh_token`].
+#[derive(Debug, Error)]
+pub enum RefreshTokensError {
+    /// The refresh token was rejected by WorkOS.
+    #[error("failed to refresh session")]
+    Refresh(#[source] WorkOsError<AuthenticateError>),
+
+    /// The hook failed to persist the new tokens.
+    ///
+    /// The refresh token may already have been rotated, so the response is returned to let the caller retry
+    /// persisting its tokens.
+    #[error("failed to persist refreshed tokens")]
+    TokensRefreshed {
+        /// The error returned from the hook.
+        #[source]
+        source: TokensRefreshedError,
+
+        /// The response containing the new tokens.
+        response: Box<AuthenticationResponse>,
+    },
+}
+
+impl AccessTokenVerifier {
+    /// Exchanges the refresh token for new tokens and calls the hook with them before returning the response.
+    ///
+    /// If an organization ID is provided, the new access token is scoped to that organization.
+    ///
+    /// [WorkOS Docs: Authenticate with refresh token](https://workos.com/docs/reference/user-management/authentication/refresh-token)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos_sdk::sessions::*;
+    /// # use workos_sdk::user_management::RefreshToken;
+    /// # async fn run(verifier: &AccessTokenVerifier, on_tokens_refreshed: &OnTokensRefreshed) -> Result<(), RefreshTokensError> {
+    /// let response = verifier
+    ///     .authenticate_with_refresh_token(
+    ///         &RefreshToken::from("Xw0NsCVXMBf7svAoIoKBmkpEK"),
+    ///         None,
+    ///         on_tokens_refreshed,
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn authenticate_with_refresh_token(
+        &self,
+        refresh_token: &RefreshToken,
+        organization_id: Option<&OrganizationId>,
+        on_tokens_refreshed: &OnTokensRefreshed,
+    ) -> Result<AuthenticationResponse, RefreshTokensError> {
+        let response = self
+            .refresh(refresh_token, organization_id)
+            .await
+            .map_err(RefreshTokensError::Refresh)?;
+
+        let tokens = SessionTokens {
+            access_token: response.access_token.clone(),
+            refresh_token: response.refresh_token.clone(),
+        };
+
+        match on_tokens_refreshed.call(&tokens).await {
+            Ok(()) => Ok(response),
+            Err(source) => Err(RefreshTokensError::TokensRefreshed {
+                source,
+                response: Box::new(response),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Mutex;
+
+    use matches::assert_matches;
+    use mockito::Matcher;
+    use serde_json::json;
+    use tokio;
+
+    use crate::sessions::testing;
+    use crate::sso::ClientId;
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    async fn server() -> mockito::ServerGuard {
+        let mut server = mockito::Server::new_async().await;
+
+        server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(200)
+            .with_body(
+                testing::authentication_response(
+                    &testing::access_token(600),
+                    "yAjhKk123NLIjdrBdGZPf8pLIDvK",
+                )
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        server
+    }
+
+    fn verifier(server: &mockito::Server) -> AccessTokenVerifier {
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        AccessTokenVerifier::new(&workos, &ClientId::from("client_123456789"))
+            .issuer("https://api.workos.com")
+    }
+
+    #[tokio::test]
+    async fn it_calls_the_hook_with_the_rotated_tokens() {
+        let server = server().await;
+        let persisted = Arc::new(Mutex::new(None));
+
+        let on_tokens_refreshed = OnTokensRefreshed::new({
+            let persisted = persisted.clone();
+            move |tokens: SessionTokens| {
+                let persisted = persisted.clone();
+                async move {
+                    *persisted.lock().unwrap() = Some(tokens.refresh_token);
+                    Ok(())
+                }
+            }
+        });
+
+        let response = verifier(&server)
+            .authenticate_with_refresh_token(
+                &RefreshToken::from("Xw0NsCVXMBf7svAoIoKBmkpEK"),
+                None,
+                &on_tokens_refreshed,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *persisted.lock().unwrap(),
+            Some(response.refresh_token.clone())
+        );
+        assert_eq!(
+            response.refresh_token,
+            RefreshToken::from("yAjhKk123NLIjdrBdGZPf8pLIDvK")
+        )
+    }
+
+    #[tokio::test]
+    async fn it_refreshes_the_tokens_for_the_provided_organization() {
+        let mut server = mockito::Server::new_async().await;
+
+        let refresh = server
+            .mock("POST", "/user_management/authenticate")
+            .match_body(Matcher::PartialJson(json!({
+                "grant_type": "refresh_token",
+                "organization_id": "org_01EHZNVPK3SFK441A1RGBFSHRT"
+            })))
+            .with_status(200)
+            .with_body(
+                testing::authentication_response(
+                    &testing::access_token(600),
+                    "yAjhKk123NLIjdrBdGZPf8pLIDvK",
+                )
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        verifier(&server)
+            .authenticate_with_refresh_token(
+                &RefreshToken::from("Xw0NsCVXMBf7svAoIoKBmkpEK"),
+                Some(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT")),
+                &OnTokensRefreshed::new(|_| async { Ok(()) }),
+            )
+            .await
+            .unwrap();
+
+        refresh.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_returns_the_response_when_the_hook_fails() {
+        let server = server().await;
+
+        let on_tokens_refreshed =
+            OnTokensRefreshed::new(|_| async { Err("database unavailable".into()) });
+
+        let result = verifier(&server)
+            .authenticate_with_refresh_token(
+                &RefreshToken::from("Xw0NsCVXMBf7svAoIoKBmkpEK"),
+                None,
+                &on_tokens_refreshed,
+            )
+            .await;
+
+        assert_matches!(
+            result,
+            Err(RefreshTokensError::TokensRefreshed { ref response, .. })
+                if response.refresh_token == RefreshToken::from("yAjhKk123NLIjdrBdGZPf8pLIDvK")
+        )
+    }
+}
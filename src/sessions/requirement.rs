@@ -0,0 +1,175 @@
+use crate::organizations::OrganizationId;
+use crate::sessions::AccessTokenClaims;
+
+impl AccessTokenClaims {
+    /// Whether the role of the user grants the provided permission.
+    pub fn has_permission(&self, permission: &str) -> bool {
+        self.permissions.iter().any(|granted| granted == permission)
+    }
+
+    /// Whether the user has the provided role in the organization of the session.
+    pub fn has_role(&self, role: &str) -> bool {
+        self.role.as_deref() == Some(role)
+    }
+
+    /// Whether the session is authorized for the provided organization.
+    pub fn in_organization(&self, organization_id: &OrganizationId) -> bool {
+        self.org_id.as_ref() == Some(organization_id)
+    }
+
+    /// Whether the claims satisfy the provided [`Requirement`].
+    pub fn satisfies(&self, requirement: &impl Requirement) -> bool {
+        requirement.is_satisfied_by(self)
+    }
+}
+
+/// A requirement that the claims of an access token must satisfy to be authorized.
+///
+/// # Examples
+///
+/// ```
+/// # use workos_sdk::sessions::*;
+/// # fn run(claims: &AccessTokenClaims) {
+/// let requirement = RequireAny(vec![
+///     RequireRole("admin").boxed(),
+///     RequireAll(vec![
+///         RequirePermission("reports:read").boxed(),
+///         RequirePermission("reports:export").boxed(),
+///     ])
+///     .boxed(),
+/// ]);
+///
+/// if !claims.satisfies(&requirement) {
+///     // Respond with 403 Forbidden.
+/// }
+/// # }
+/// ```
+pub trait Requirement: Send + Sync {
+    /// Whether the claims satisfy the requirement.
+    fn is_satisfied_by(&self, claims: &AccessTokenClaims) -> bool;
+
+    /// Boxes the requirement, so it can be combined with requirements of other types.
+    fn boxed(self) -> Box<dyn Requirement>
+    where
+        Self: Sized + 'static,
+    {
+        Box::new(self)
+    }
+}
+
+impl Requirement for Box<dyn Requirement> {
+    fn is_satisfied_by(&self, claims: &AccessTokenClaims) -> bool {
+        self.as_ref().is_satisfied_by(claims)
+    }
+}
+
+/// Requires the role of the user to grant the permission.
+#[derive(Clone, Debug)]
+pub struct RequirePermission<S>(pub S);
+
+impl<S: AsRef<str> + Send + Sync> Requirement for RequirePermission<S> {
+    fn is_satisfied_by(&self, claims: &AccessTokenClaims) -> bool {
+        claims.has_permission(self.0.as_ref())
+    }
+}
+
+/// Requires the user to have the role.
+#[derive(Clone, Debug)]
+pub struct RequireRole<S>(pub S);
+
+impl<S: AsRef<str> + Send + Sync> Requirement for RequireRole<S> {
+    fn is_satisfied_by(&self, claims: &AccessTokenClaims) -> bool {
+        claims.has_role(self.0.as_ref())
+    }
+}
+
+/// Requires the session to be authorized for the organization.
+#[derive(Clone, Debug)]
+pub struct RequireOrganization(pub OrganizationId);
+
+impl Requirement for RequireOrganization {
+    fn is_satisfied_by(&self, claims: &AccessTokenClaims) -> bool {
+        claims.in_organization(&self.0)
+    }
+}
+
+/// Requires all of the requirements to be satisfied.
+pub struct RequireAll<R>(pub Vec<R>);
+
+impl<R: Requirement> Requirement for RequireAll<R> {
+    fn is_satisfied_by(&self, claims: &AccessTokenClaims) -> bool {
+        self.0
+            .iter()
+            .all(|requirement| requirement.is_satisfied_by(claims))
+    }
+}
+
+/// Requires at least one of the requirements to be satisfied.
+pub struct RequireAny<R>(pub Vec<R>);
+
+impl<R: Requirement> Requirement for RequireAny<R> {
+    fn is_satisfied_by(&self, claims: &AccessTokenClaims) -> bool {
+        self.0
+            .iter()
+            .any(|requirement| requirement.is_satisfied_by(claims))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    fn claims() -> AccessTokenClaims {
+        serde_json::from_value(json!({
+            "sub": "user_01E4ZCR3C56J083X43JQXF3JK5",
+            "sid": "session_01H93ZY4F80QPBEZ1R5B2SHQG8",
+            "org_id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+            "role": "member",
+            "permissions": ["reports:read"],
+            "exp": 1710000000
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn it_checks_the_permissions_role_and_organization() {
+        let claims = claims();
+
+        assert!(claims.has_permission("reports:read"));
+        assert!(!claims.has_permission("reports:write"));
+        assert!(claims.has_role("member"));
+        assert!(!claims.has_role("admin"));
+        assert!(claims.in_organization(&OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT")));
+        assert!(!claims.in_organization(&OrganizationId::from("org_another")))
+    }
+
+    #[test]
+    fn it_requires_all_requirements() {
+        let claims = claims();
+
+        assert!(claims.satisfies(&RequireAll(vec![
+            RequireRole("member").boxed(),
+            RequirePermission("reports:read").boxed(),
+        ])));
+        assert!(!claims.satisfies(&RequireAll(vec![
+            RequirePermission("reports:read"),
+            RequirePermission("reports:write"),
+        ])))
+    }
+
+    #[test]
+    fn it_requires_any_requirement() {
+        let claims = claims();
+
+        assert!(claims.satisfies(&RequireAny(vec![
+            RequireRole("admin").boxed(),
+            RequirePermission("reports:read").boxed(),
+        ])));
+        assert!(!claims.satisfies(&RequireAny(vec![
+            RequireRole("admin").boxed(),
+            RequireOrganization(OrganizationId::from("org_another")).boxed(),
+        ])))
+    }
+}
@@ -0,0 +1,422 @@
+use jsonwebtoken::{DecodingKey, Validation};
+use serde::Deserialize;
+use thiserror::Error;
+use url::Url;
+
+use crate::WorkOsError;
+use crate::sessions::{
+    AccessTokenClaims, AccessTokenVerifier, CookiePassword, SealSessionError, SessionData,
+    UnsealSessionError, VerifyAccessTokenError,
+};
+use crate::user_management::{
+    AuthenticateError, GetJwksError, GetLogoutUrl, GetLogoutUrlParams, SessionId,
+};
+
+/// An error returned from [`SealedSession::authenticate`].
+#[derive(Debug, Error)]
+pub enum AuthenticateSessionError {
+    /// The JWKS could not be fetched to verify the access token.
+    #[error("failed to fetch JWKS")]
+    Jwks(#[source] WorkOsError<GetJwksError>),
+}
+
+/// An error returned from [`SealedSession::refresh`].
+#[derive(Debug, Error)]
+pub enum RefreshSessionError {
+    /// The session cookie could not be unsealed.
+    #[error("invalid session cookie")]
+    InvalidSessionCookie(#[from] UnsealSessionError),
+
+    /// The refresh token was rejected by WorkOS.
+    #[error("failed to refresh session")]
+    Refresh(#[source] WorkOsError<AuthenticateError>),
+
+    /// The refreshed access token could not be verified.
+    #[error("failed to verify refreshed access token")]
+    Verification(#[from] VerifyAccessTokenError),
+
+    /// The refreshed session could not be sealed.
+    #[error(transparent)]
+    Seal(#[from] SealSessionError),
+}
+
+/// An error returned from [`SealedSession::logout_url`].
+#[derive(Debug, Error)]
+pub enum LogoutSessionError {
+    /// The session cookie could not be unsealed.
+    #[error("invalid session cookie")]
+    InvalidSessionCookie(#[from] UnsealSessionError),
+
+    /// The access token in the session cookie is malformed or has no `sid` claim.
+    #[error("invalid access token")]
+    InvalidAccessToken(#[from] jsonwebtoken::errors::Error),
+
+    /// The logout URL could not be built.
+    #[error("URL parse error")]
+    UrlParseError(#[from] url::ParseError),
+}
+
+/// The reason a user needs to sign in again.
+#[derive(Debug)]
+pub enum SignInRequiredReason {
+    /// The session cookie is malformed, expired, or was sealed with another password.
+    InvalidSessionCookie(UnsealSessionError),
+
+    /// The access token in the session cookie is invalid.
+    InvalidAccessToken(VerifyAccessTokenError),
+}
+
+/// A session whose access token has been verified.
+#[derive(Clone, Debug)]
+pub struct AuthenticatedSession {
+    /// The data of the session, including the user.
+    pub data: SessionData,
+
+    /// The claims of the access token.
+    pub claims: AccessTokenClaims,
+}
+
+/// The outcome of [`SealedSession::authenticate`].
+#[derive(Debug)]
+pub enum SessionAuthentication {
+    /// The session is valid.
+    Authenticated(Box<AuthenticatedSession>),
+
+    /// The access token has expired and the session should be refreshed with [`SealedSession::refresh`].
+    RefreshRequired,
+
+    /// The session is invalid and the user should be redirected to sign in.
+    SignInRequired(SignInRequiredReason),
+}
+
+/// A session that was refreshed with [`SealedSession::refresh`].
+#[derive(Clone, Debug)]
+pub struct RefreshedSession {
+    /// The new sealed session, which should replace the session cookie.
+    pub sealed_session: String,
+
+    /// The refreshed session.
+    pub session: AuthenticatedSession,
+}
+
+/// A sealed session loaded from a cookie with [`AccessTokenVerifier::load_sealed_session`].
+pub struct SealedSession<'a> {
+    verifier: &'a AccessTokenVerifier,
+    sealed_session: &'a str,
+    cookie_password: &'a CookiePassword,
+}
+
+impl AccessTokenVerifier {
+    /// Loads a sealed session from the value of a session cookie.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos_sdk::sessions::*;
+    /// # async fn run(verifier: &AccessTokenVerifier, cookie: &str) -> Result<(), RefreshSessionError> {
+    /// let cookie_password = CookiePassword::from("DQhXs6bZPy8pYbd8bQJAVvoaXUwXSvLJ");
+    /// let session = verifier.load_sealed_session(cookie, &cookie_password);
+    ///
+    /// match session.authenticate().await {
+    ///     Ok(SessionAuthentication::Authenticated(session)) => {
+    ///         let user = &session.data.user;
+    ///     }
+    ///     Ok(SessionAuthentication::RefreshRequired) => {
+    ///         let RefreshedSession { sealed_session, .. } = session.refresh().await?;
+    ///         // Replace the session cookie with the refreshed session.
+    ///     }
+    ///     Ok(SessionAuthentication::SignInRequired(_)) => {
+    ///         // Redirect to the authorization URL.
+    ///     }
+    ///     Err(_) => {
+    ///         // Respond with 500 Internal Server Error.
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn load_sealed_session<'a>(
+        &'a self,
+        sealed_session: &'a str,
+        cookie_password: &'a CookiePassword,
+    ) -> SealedSession<'a> {
+        SealedSession {
+            verifier: self,
+            sealed_session,
+            cookie_password,
+        }
+    }
+}
+
+impl SealedSession<'_> {
+    /// Unseals the session and verifies its access token.
+    ///
+    /// Only infrastructure failures are returned as errors; invalid sessions are reported as
+    /// [`SessionAuthentication::SignInRequired`].
+    pub async fn authenticate(&self) -> Result<SessionAuthentication, AuthenticateSessionError> {
+        let data = match SessionData::unseal(self.sealed_session, self.cookie_password) {
+            Ok(data) => data,
+            Err(err) => {
+                return Ok(SessionAuthentication::SignInRequired(
+                    SignInRequiredReason::InvalidSessionCookie(err),
+                ));
+            }
+        };
+
+        match self.verifier.verify(&data.access_token).await {
+            Ok(claims) => Ok(SessionAuthentication::Authenticated(Box::new(
+                AuthenticatedSession { data, claims },
+            ))),
+            Err(VerifyAccessTokenError::Jwks(err)) => Err(AuthenticateSessionError::Jwks(err)),
+            Err(err) if err.is_expired() => Ok(SessionAuthentication::RefreshRequired),
+            Err(err) => Ok(SessionAuthentication::SignInRequired(
+                SignInRequiredReason::InvalidAccessToken(err),
+            )),
+        }
+    }
+
+    /// Exchanges the refresh token of the session for a new access token and reseals the session.
+    ///
+    /// The new access token is scoped to the organization of the session, if there is one.
+    pub async fn refresh(&self) -> Result<RefreshedSession, RefreshSessionError> {
+        let data = SessionData::unseal(self.sealed_session, self.cookie_password)?;
+
+        let response = self
+            .verifier
+            .refresh(&data.refresh_token, data.organization_id.as_ref())
+            .await
+            .map_err(RefreshSessionError::Refresh)?;
+
+        let claims = self.verifier.verify(&response.access_token).await?;
+        let data = SessionData::from(response);
+
+        Ok(RefreshedSession {
+            sealed_session: data.seal(self.cookie_password)?,
+            session: AuthenticatedSession { data, claims },
+        })
+    }
+
+    /// Returns the WorkOS logout URL that ends the session.
+    ///
+    /// The session ID is read from the `sid` claim of the access token without verifying it, so sessions with an
+    /// expired access token can be ended as well. The sealed session itself is still integrity-checked.
+    pub fn logout_url(&self, return_to: Option<&Url>) -> Result<Url, LogoutSessionError> {
+        #[derive(Deserialize)]
+        struct SessionIdClaim {
+            sid: SessionId,
+        }
+
+        let data = SessionData::unseal(self.sealed_session, self.cookie_password)?;
+
+        let mut validation = Validation::default();
+        validation.insecure_disable_signature_validation();
+        validation.validate_exp = false;
+        validation.validate_aud = false;
+        validation.required_spec_claims.clear();
+
+        let token = jsonwebtoken::decode::<SessionIdClaim>(
+            &data.access_token,
+            &DecodingKey::from_secret(&[]),
+            &validation,
+        )?;
+
+        let logout_url =
+            self.verifier
+                .workos()
+                .user_management()
+                .get_logout_url(&GetLogoutUrlParams {
+                    session_id: &token.claims.sid,
+                    return_to,
+                })?;
+
+        Ok(logout_url)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+    use mockito::Matcher;
+    use serde_json::json;
+    use tokio;
+
+    use crate::sessions::testing;
+    use crate::sso::{AccessToken, ClientId};
+    use crate::user_management::{AuthenticationResponse, RefreshToken};
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    async fn server() -> mockito::ServerGuard {
+        let mut server = mockito::Server::new_async().await;
+
+        server
+            .mock("GET", "/sso/jwks/client_123456789")
+            .with_status(200)
+            .with_body(testing::jwks().to_string())
+            .create_async()
+            .await;
+
+        server
+    }
+
+    fn verifier(server: &mockito::Server) -> AccessTokenVerifier {
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        AccessTokenVerifier::new(&workos, &ClientId::from("client_123456789"))
+            .issuer("https://api.workos.com")
+    }
+
+    fn cookie_password() -> CookiePassword {
+        CookiePassword::from("DQhXs6bZPy8pYbd8bQJAVvoaXUwXSvLJ")
+    }
+
+    fn sealed_session(access_token: &AccessToken) -> String {
+        SessionData::from(
+            serde_json::from_value::<AuthenticationResponse>(testing::authentication_response(
+                access_token,
+                "Xw0NsCVXMBf7svAoIoKBmkpEK",
+            ))
+            .unwrap(),
+        )
+        .seal(&cookie_password())
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn it_authenticates_a_valid_session() {
+        let server = server().await;
+        let verifier = verifier(&server);
+        let sealed_session = sealed_session(&testing::access_token(300));
+        let cookie_password = cookie_password();
+
+        let result = verifier
+            .load_sealed_session(&sealed_session, &cookie_password)
+            .authenticate()
+            .await
+            .unwrap();
+
+        assert_matches!(
+            result,
+            SessionAuthentication::Authenticated(session)
+                if session.data.user.email == "marcelina.davis@example.com"
+                    && session.claims.has_role("admin")
+        )
+    }
+
+    #[tokio::test]
+    async fn it_requires_a_refresh_when_the_access_token_has_expired() {
+        let server = server().await;
+        let verifier = verifier(&server);
+        let sealed_session = sealed_session(&testing::access_token(-300));
+        let cookie_password = cookie_password();
+
+        let result = verifier
+            .load_sealed_session(&sealed_session, &cookie_password)
+            .authenticate()
+            .await
+            .unwrap();
+
+        assert_matches!(result, SessionAuthentication::RefreshRequired)
+    }
+
+    #[tokio::test]
+    async fn it_requires_sign_in_when_the_cookie_is_invalid() {
+        let server = server().await;
+        let verifier = verifier(&server);
+        let cookie_password = cookie_password();
+
+        let result = verifier
+            .load_sealed_session("not a sealed session", &cookie_password)
+            .authenticate()
+            .await
+            .unwrap();
+
+        assert_matches!(
+            result,
+            SessionAuthentication::SignInRequired(SignInRequiredReason::InvalidSessionCookie(_))
+        )
+    }
+
+    #[tokio::test]
+    async fn it_refreshes_and_reseals_the_session() {
+        let mut server = server().await;
+        let refreshed_access_token = testing::access_token(600);
+
+        let refresh = server
+            .mock("POST", "/user_management/authenticate")
+            .match_body(Matcher::PartialJson(json!({
+                "grant_type": "refresh_token",
+                "refresh_token": "Xw0NsCVXMBf7svAoIoKBmkpEK",
+                "organization_id": "org_01EHZNVPK3SFK441A1RGBFSHRT"
+            })))
+            .with_status(200)
+            .with_body(
+                testing::authentication_response(
+                    &refreshed_access_token,
+                    "yAjhKk123NLIjdrBdGZPf8pLIDvK",
+                )
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let verifier = verifier(&server);
+        let sealed_session = sealed_session(&testing::access_token(-300));
+        let cookie_password = cookie_password();
+
+        let refreshed = verifier
+            .load_sealed_session(&sealed_session, &cookie_password)
+            .refresh()
+            .await
+            .unwrap();
+
+        assert_eq!(refreshed.session.data.access_token, refreshed_access_token);
+
+        let unsealed = SessionData::unseal(&refreshed.sealed_session, &cookie_password).unwrap();
+        assert_eq!(
+            unsealed.refresh_token,
+            RefreshToken::from("yAjhKk123NLIjdrBdGZPf8pLIDvK")
+        );
+        refresh.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_returns_the_logout_url_of_an_expired_session() {
+        let server = mockito::Server::new_async().await;
+        let verifier = verifier(&server);
+        let sealed_session = sealed_session(&testing::access_token(-300));
+        let cookie_password = cookie_password();
+
+        let logout_url = verifier
+            .load_sealed_session(&sealed_session, &cookie_password)
+            .logout_url(Some(
+                &Url::parse("https://your-app.com/signed-out").unwrap(),
+            ))
+            .unwrap();
+
+        assert_eq!(
+            logout_url.as_str(),
+            format!(
+                "{}/user_management/sessions/logout?session_id=session_01H93ZY4F80QPBEZ1R5B2SHQG8&return_to=https://your-app.com/signed-out",
+                server.url()
+            )
+        )
+    }
+
+    #[tokio::test]
+    async fn it_rejects_logging_out_an_invalid_session() {
+        let server = mockito::Server::new_async().await;
+        let verifier = verifier(&server);
+        let cookie_password = cookie_password();
+
+        let result = verifier
+            .load_sealed_session("not a sealed session", &cookie_password)
+            .logout_url(None);
+
+        assert_matches!(result, Err(LogoutSessionError::InvalidSessionCookie(_)))
+    }
+}
@@ -0,0 +1,293 @@
+use url::Url;
+
+use crate::WorkOsError;
+use crate::sessions::{
+    AccessTokenVerifier, AuthenticateSessionError, CookiePassword, LogoutSessionError,
+    RefreshSessionError, SessionAuthentication, SessionData, VerifyAccessTokenError, WorkOsSession,
+    WorkOsSessionRejection,
+};
+use crate::sso::AccessToken;
+
+/// The credentials a request was authenticated with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SessionCredentials {
+    /// A sealed session from the session cookie.
+    Cookie(String),
+
+    /// An access token from the `Authorization` header.
+    Bearer(AccessToken),
+}
+
+impl SessionCredentials {
+    /// Extracts the credentials from the values of the `Authorization` and `Cookie` headers.
+    ///
+    /// A bearer token takes precedence over the session cookie.
+    pub fn from_headers<'a>(
+        authorization: Option<&str>,
+        cookies: impl IntoIterator<Item = &'a str>,
+        cookie_name: &str,
+    ) -> Option<Self> {
+        if let Some(token) = authorization.and_then(|authorization| {
+            authorization
+                .strip_prefix("Bearer ")
+                .or_else(|| authorization.strip_prefix("bearer "))
+        }) {
+            return Some(Self::Bearer(AccessToken::from(token.trim())));
+        }
+
+        find_cookie(cookies, cookie_name).map(|value| Self::Cookie(value.to_string()))
+    }
+}
+
+/// Returns the value of the cookie with the provided name from the values of the `Cookie` headers.
+pub(crate) fn find_cookie<'a>(
+    cookies: impl IntoIterator<Item = &'a str>,
+    cookie_name: &str,
+) -> Option<&'a str> {
+    cookies
+        .into_iter()
+        .flat_map(|header| header.split(';'))
+        .filter_map(|cookie| cookie.trim().split_once('='))
+        .find(|(name, _)| *name == cookie_name)
+        .map(|(_, value)| value)
+}
+
+/// The outcome of [`SessionAuthenticator::logout`].
+#[derive(Clone, Debug)]
+pub struct SessionLogout {
+    /// The WorkOS logout URL the user's browser should be redirected to.
+    pub logout_url: Url,
+
+    /// The value of a `Set-Cookie` header that clears the session cookie.
+    pub clear_session_cookie: String,
+}
+
+/// Authenticates requests using a session cookie or a bearer token.
+///
+/// This is the configuration shared by the session extractors and middleware of the supported web frameworks.
+///
+/// # Examples
+///
+/// ```
+/// # use workos_sdk::sessions::*;
+/// # fn run(verifier: AccessTokenVerifier) {
+/// let authenticator = SessionAuthenticator::new(
+///     verifier,
+///     &CookiePassword::from("DQhXs6bZPy8pYbd8bQJAVvoaXUwXSvLJ"),
+/// )
+/// .sign_in_url("/login");
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct SessionAuthenticator {
+    verifier: AccessTokenVerifier,
+    cookie_password: CookiePassword,
+    pub(crate) cookie_name: String,
+    pub(crate) sign_in_url: Option<String>,
+}
+
+impl SessionAuthenticator {
+    /// The default name of the session cookie, matching the other WorkOS SDKs.
+    pub const DEFAULT_COOKIE_NAME: &str = "wos-session";
+
+    /// Returns a new [`SessionAuthenticator`].
+    pub fn new(verifier: AccessTokenVerifier, cookie_password: &CookiePassword) -> Self {
+        Self {
+            verifier,
+            cookie_password: cookie_password.clone(),
+            cookie_name: Self::DEFAULT_COOKIE_NAME.to_string(),
+            sign_in_url: None,
+        }
+    }
+
+    /// Sets the name of the session cookie.
+    pub fn cookie_name(mut self, cookie_name: impl Into<String>) -> Self {
+        self.cookie_name = cookie_name.into();
+        self
+    }
+
+    /// Redirects unauthenticated requests without a bearer token to the provided URL instead of rejecting them
+    /// with 401 Unauthorized.
+    pub fn sign_in_url(mut self, sign_in_url: impl Into<String>) -> Self {
+        self.sign_in_url = Some(sign_in_url.into());
+        self
+    }
+
+    /// The verifier used to verify access tokens.
+    pub fn verifier(&self) -> &AccessTokenVerifier {
+        &self.verifier
+    }
+
+    /// The password used to seal and unseal the session cookie.
+    pub fn cookie_password(&self) -> &CookiePassword {
+        &self.cookie_password
+    }
+
+    /// Returns the value of a `Set-Cookie` header that stores the sealed session.
+    pub fn session_cookie(&self, sealed_session: &str) -> String {
+        format!(
+            "{}={sealed_session}; Path=/; HttpOnly; Secure; SameSite=Lax; Max-Age={}",
+            self.cookie_name,
+            SessionData::DEFAULT_SEAL_TTL.as_secs()
+        )
+    }
+
+    /// Returns the value of a `Set-Cookie` header that clears the session cookie.
+    pub fn clear_session_cookie(&self) -> String {
+        format!(
+            "{}=; Path=/; HttpOnly; Secure; SameSite=Lax; Max-Age=0",
+            self.cookie_name
+        )
+    }
+
+    /// Ends the sealed session, returning the WorkOS logout URL and the cookie that clears the session cookie.
+    ///
+    /// See [`SealedSession::logout_url`](crate::sessions::SealedSession::logout_url).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use url::Url;
+    /// # use workos_sdk::sessions::*;
+    /// # fn run(authenticator: &SessionAuthenticator, cookie: &str) -> Result<(), LogoutSessionError> {
+    /// let SessionLogout {
+    ///     logout_url,
+    ///     clear_session_cookie,
+    /// } = authenticator.logout(cookie, Some(&Url::parse("https://your-app.com/signed-out")?))?;
+    /// // Redirect to the logout URL and set the cleared cookie.
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn logout(
+        &self,
+        sealed_session: &str,
+        return_to: Option<&Url>,
+    ) -> Result<SessionLogout, LogoutSessionError> {
+        let logout_url = self
+            .verifier
+            .load_sealed_session(sealed_session, &self.cookie_password)
+            .logout_url(return_to)?;
+
+        Ok(SessionLogout {
+            logout_url,
+            clear_session_cookie: self.clear_session_cookie(),
+        })
+    }
+
+    /// Authenticates the request with the provided credentials.
+    ///
+    /// If `refresh` is set, an expired session cookie is refreshed and the returned session contains the new
+    /// sealed session, which must be stored with [`SessionAuthenticator::session_cookie`], as the previous
+    /// refresh token may no longer be used. Otherwise, expired sessions are rejected.
+    pub async fn authenticate(
+        &self,
+        credentials: Option<SessionCredentials>,
+        refresh: bool,
+    ) -> Result<WorkOsSession, WorkOsSessionRejection> {
+        match credentials.ok_or(WorkOsSessionRejection::MissingCredentials)? {
+            SessionCredentials::Bearer(access_token) => {
+                let claims =
+                    self.verifier
+                        .verify(&access_token)
+                        .await
+                        .map_err(|err| match err {
+                            VerifyAccessTokenError::Jwks(err) => WorkOsSessionRejection::Jwks(err),
+                            err if err.is_expired() => WorkOsSessionRejection::SessionExpired,
+                            _ => WorkOsSessionRejection::InvalidSession,
+                        })?;
+
+                Ok(WorkOsSession {
+                    access_token,
+                    claims,
+                    data: None,
+                    refreshed_session: None,
+                })
+            }
+            SessionCredentials::Cookie(sealed_session) => {
+                let session = self
+                    .verifier
+                    .load_sealed_session(&sealed_session, &self.cookie_password);
+
+                match session.authenticate().await {
+                    Ok(SessionAuthentication::Authenticated(session)) => Ok(WorkOsSession {
+                        access_token: session.data.access_token.clone(),
+                        claims: session.claims,
+                        data: Some(session.data),
+                        refreshed_session: None,
+                    }),
+                    Ok(SessionAuthentication::RefreshRequired) if refresh => {
+                        // Only a session rejected by WorkOS is invalid; other failures must not log the user out.
+                        let refreshed = session.refresh().await.map_err(|err| match err {
+                            RefreshSessionError::InvalidSessionCookie(_)
+                            | RefreshSessionError::Refresh(WorkOsError::Operation(_)) => {
+                                WorkOsSessionRejection::InvalidSession
+                            }
+                            RefreshSessionError::Verification(VerifyAccessTokenError::Jwks(
+                                err,
+                            )) => WorkOsSessionRejection::Jwks(err),
+                            err => WorkOsSessionRejection::Refresh(err),
+                        })?;
+
+                        Ok(WorkOsSession {
+                            access_token: refreshed.session.data.access_token.clone(),
+                            claims: refreshed.session.claims,
+                            data: Some(refreshed.session.data),
+                            refreshed_session: Some(refreshed.sealed_session),
+                        })
+                    }
+                    Ok(SessionAuthentication::RefreshRequired) => {
+                        Err(WorkOsSessionRejection::SessionExpired)
+                    }
+                    Ok(SessionAuthentication::SignInRequired(_)) => {
+                        Err(WorkOsSessionRejection::InvalidSession)
+                    }
+                    Err(AuthenticateSessionError::Jwks(err)) => {
+                        Err(WorkOsSessionRejection::Jwks(err))
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_extracts_a_bearer_token() {
+        let credentials = SessionCredentials::from_headers(
+            Some("Bearer eyJhbGciOiJSUzI1NiJ9"),
+            ["wos-session=Fe26.2*1*sealed"],
+            "wos-session",
+        );
+
+        assert_eq!(
+            credentials,
+            Some(SessionCredentials::Bearer(AccessToken::from(
+                "eyJhbGciOiJSUzI1NiJ9"
+            )))
+        )
+    }
+
+    #[test]
+    fn it_extracts_the_session_cookie() {
+        let credentials = SessionCredentials::from_headers(
+            None,
+            ["theme=dark; wos-session=Fe26.2*1*sealed~2", "lang=en"],
+            "wos-session",
+        );
+
+        assert_eq!(
+            credentials,
+            Some(SessionCredentials::Cookie("Fe26.2*1*sealed~2".to_string()))
+        )
+    }
+
+    #[test]
+    fn it_returns_none_without_credentials() {
+        let credentials = SessionCredentials::from_headers(None, ["theme=dark"], "wos-session");
+
+        assert_eq!(credentials, None)
+    }
+}
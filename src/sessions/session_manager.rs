@@ -0,0 +1,695 @@
+use std::future::Future;
+use std::sync::Arc;
+
+use thiserror::Error;
+use tokio::sync::Mutex;
+
+use crate::WorkOsError;
+use crate::sessions::{
+    AccessTokenClaims, AccessTokenVerifier, CookiePassword, OnTokensRefreshed, SealSessionError,
+    SessionData, TokenStore, TokenStoreError, TokensRefreshedError, UnsealSessionError,
+    VerifyAccessTokenError,
+};
+use crate::sso::AccessToken;
+use crate::user_management::{AuthenticateError, RefreshToken, SessionId};
+
+/// An error returned from [`SessionManager`].
+#[derive(Debug, Error)]
+pub enum SessionManagerError {
+    /// The access token could not be verified.
+    #[error("failed to verify access token")]
+    Verification(#[from] VerifyAccessTokenError),
+
+    /// The expired access token could not be refreshed.
+    #[error("failed to refresh access token")]
+    Refresh(#[source] WorkOsError<AuthenticateError>),
+
+    /// The sealed session could not be read from or written to the [`TokenStore`].
+    #[error("token store error")]
+    Store(#[source] TokenStoreError),
+
+    /// The refreshed session could not be sealed.
+    #[error(transparent)]
+    Seal(#[from] SealSessionError),
+
+    /// The stored session could not be unsealed.
+    #[error(transparent)]
+    Unseal(#[from] UnsealSessionError),
+
+    /// The [`SessionManager::on_tokens_refreshed`] hook failed to persist the refreshed tokens.
+    ///
+    /// The manager already holds the refreshed tokens and calls the hook with them again on every later call, until it
+    /// succeeds.
+    #[error("failed to persist refreshed tokens")]
+    TokensRefreshed(#[source] TokensRefreshedError),
+}
+
+/// The access and refresh token of a session.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SessionTokens {
+    /// The access token of the session.
+    pub access_token: AccessToken,
+
+    /// The refresh token of the session.
+    pub refresh_token: RefreshToken,
+}
+
+impl From<&SessionData> for SessionTokens {
+    fn from(session: &SessionData) -> Self {
+        Self {
+            access_token: session.access_token.clone(),
+            refresh_token: session.refresh_token.clone(),
+        }
+    }
+}
+
+struct SessionState {
+    tokens: SessionTokens,
+
+    /// A refreshed session that has not been written to the [`TokenStore`] yet.
+    unpersisted: Option<SessionData>,
+
+    /// Whether the refreshed tokens have not been passed to the [`OnTokensRefreshed`] hook successfully yet.
+    unnotified: bool,
+}
+
+#[derive(Clone)]
+struct StoreConfig {
+    store: Arc<dyn TokenStore>,
+    cookie_password: CookiePassword,
+}
+
+/// Holds the tokens of a session and refreshes the access token when it expires.
+///
+/// The access token is verified locally on every use. Once it has expired, it is exchanged for a new one using the
+/// refresh token. Concurrent callers share a single refresh, so a rotated refresh token is never used twice.
+///
+/// The manager is cheap to clone and clones share the tokens.
+///
+/// # Examples
+///
+/// ```
+/// # use workos_sdk::sessions::*;
+/// # async fn run(verifier: &AccessTokenVerifier, tokens: SessionTokens) -> Result<(), SessionManagerError> {
+/// let session = SessionManager::new(verifier, tokens).on_tokens_refreshed(
+///     |tokens: SessionTokens| async move {
+///         // Persist the refreshed tokens.
+///         Ok(())
+///     },
+/// );
+///
+/// let (access_token, claims) = session.access_token().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct SessionManager {
+    verifier: AccessTokenVerifier,
+    state: Arc<Mutex<SessionState>>,
+    on_tokens_refreshed: Option<OnTokensRefreshed>,
+    store: Option<StoreConfig>,
+}
+
+impl SessionManager {
+    /// Returns a new [`SessionManager`] holding the provided tokens.
+    pub fn new(verifier: &AccessTokenVerifier, tokens: SessionTokens) -> Self {
+        Self {
+            verifier: verifier.clone(),
+            state: Arc::new(Mutex::new(SessionState {
+                tokens,
+                unpersisted: None,
+                unnotified: false,
+            })),
+            on_tokens_refreshed: None,
+            store: None,
+        }
+    }
+
+    /// Loads the session with the provided ID from the [`TokenStore`].
+    ///
+    /// Refreshed sessions are sealed and written back to the store, so other instances pick up the rotated tokens.
+    /// Returns `None` if the store does not contain the session.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use workos_sdk::sessions::*;
+    /// # use workos_sdk::user_management::SessionId;
+    /// # async fn run(verifier: &AccessTokenVerifier, session_id: &SessionId) -> Result<(), SessionManagerError> {
+    /// let store = Arc::new(InMemoryTokenStore::new());
+    /// let cookie_password = CookiePassword::from("DQhXs6bZPy8pYbd8bQJAVvoaXUwXSvLJ");
+    ///
+    /// if let Some(session) = SessionManager::load(verifier, store, session_id, &cookie_password).await? {
+    ///     let claims = session.claims().await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn load(
+        verifier: &AccessTokenVerifier,
+        store: Arc<dyn TokenStore>,
+        session_id: &SessionId,
+        cookie_password: &CookiePassword,
+    ) -> Result<Option<Self>, SessionManagerError> {
+        let Some(sealed_session) = store
+            .get(session_id)
+            .await
+            .map_err(SessionManagerError::Store)?
+        else {
+            return Ok(None);
+        };
+
+        let session = SessionData::unseal(&sealed_session, cookie_password)?;
+
+        Ok(Some(
+            Self::new(verifier, SessionTokens::from(&session)).store(store, cookie_password),
+        ))
+    }
+
+    /// Writes refreshed sessions to the [`TokenStore`], sealed with the provided password.
+    ///
+    /// Sessions are stored under the `sid` claim of their access token. If a refreshed session cannot be written,
+    /// [`SessionManagerError::Store`] is returned and the write is retried on the next call.
+    pub fn store(mut self, store: Arc<dyn TokenStore>, cookie_password: &CookiePassword) -> Self {
+        self.store = Some(StoreConfig {
+            store,
+            cookie_password: cookie_password.clone(),
+        });
+        self
+    }
+
+    /// Registers an asynchronous hook that persists the new tokens after every refresh.
+    ///
+    /// The hook is awaited before the refreshed access token is returned, while concurrent callers wait for the
+    /// refresh, so no caller can use the new tokens before they have been persisted. If the hook fails,
+    /// [`SessionManagerError::TokensRefreshed`] is returned and the hook is retried on the next call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos_sdk::sessions::*;
+    /// # fn run(verifier: &AccessTokenVerifier, tokens: SessionTokens) {
+    /// let session = SessionManager::new(verifier, tokens).on_tokens_refreshed(
+    ///     |tokens: SessionTokens| async move {
+    ///         // Persist the rotated refresh token.
+    ///         Ok(())
+    ///     },
+    /// );
+    /// # }
+    /// ```
+    pub fn on_tokens_refreshed<F, Fut>(mut self, hook: F) -> Self
+    where
+        F: Fn(SessionTokens) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), TokensRefreshedError>> + Send + 'static,
+    {
+        self.on_tokens_refreshed = Some(OnTokensRefreshed::new(hook));
+        self
+    }
+
+    /// Returns the current tokens without verifying or refreshing them.
+    pub async fn tokens(&self) -> SessionTokens {
+        self.state.lock().await.tokens.clone()
+    }
+
+    /// Returns a valid access token and its claims, refreshing the access token if it has expired.
+    pub async fn access_token(
+        &self,
+    ) -> Result<(AccessToken, AccessTokenClaims), SessionManagerError> {
+        let access_token = self.state.lock().await.tokens.access_token.clone();
+
+        match self.verifier.verify(&access_token).await {
+            Ok(claims) => {
+                let mut state = self.state.lock().await;
+
+                if state.tokens.access_token == access_token {
+                    self.notify(&mut state).await?;
+                    self.persist(&mut state, &claims).await?;
+                }
+
+                return Ok((access_token, claims));
+            }
+            Err(err) if err.is_expired() => {}
+            Err(err) => return Err(err.into()),
+        }
+
+        let mut state = self.state.lock().await;
+
+        // Another caller may have refreshed the access token while we were waiting for the lock.
+        if state.tokens.access_token != access_token {
+            let claims = self.verifier.verify(&state.tokens.access_token).await?;
+
+            self.notify(&mut state).await?;
+            self.persist(&mut state, &claims).await?;
+
+            return Ok((state.tokens.access_token.clone(), claims));
+        }
+
+        let response = self
+            .verifier
+            .refresh(&state.tokens.refresh_token, None)
+            .await
+            .map_err(SessionManagerError::Refresh)?;
+
+        // The previous refresh token has been rotated, so the new tokens are kept before anything else can fail.
+        let session = SessionData::from(response);
+        state.tokens = SessionTokens::from(&session);
+        state.unpersisted = self.store.is_some().then_some(session);
+        state.unnotified = self.on_tokens_refreshed.is_some();
+
+        self.notify(&mut state).await?;
+
+        let claims: AccessTokenClaims = self.verifier.verify(&state.tokens.access_token).await?;
+
+        self.persist(&mut state, &claims).await?;
+
+        Ok((state.tokens.access_token.clone(), claims))
+    }
+
+    /// Calls the [`OnTokensRefreshed`] hook with the refreshed tokens, if it has not succeeded for them yet.
+    async fn notify(&self, state: &mut SessionState) -> Result<(), SessionManagerError> {
+        let (Some(on_tokens_refreshed), true) = (&self.on_tokens_refreshed, state.unnotified)
+        else {
+            return Ok(());
+        };
+
+        on_tokens_refreshed
+            .call(&state.tokens)
+            .await
+            .map_err(SessionManagerError::TokensRefreshed)?;
+
+        state.unnotified = false;
+
+        Ok(())
+    }
+
+    /// Writes the refreshed session to the [`TokenStore`], if it has not been written yet.
+    async fn persist(
+        &self,
+        state: &mut SessionState,
+        claims: &AccessTokenClaims,
+    ) -> Result<(), SessionManagerError> {
+        let (
+            Some(StoreConfig {
+                store,
+                cookie_password,
+            }),
+            Some(session),
+        ) = (&self.store, &state.unpersisted)
+        else {
+            return Ok(());
+        };
+
+        store
+            .set(&claims.sid, &session.seal(cookie_password)?)
+            .await
+            .map_err(SessionManagerError::Store)?;
+
+        state.unpersisted = None;
+
+        Ok(())
+    }
+
+    /// Returns the claims of a valid access token, refreshing the access token if it has expired.
+    pub async fn claims(&self) -> Result<AccessTokenClaims, SessionManagerError> {
+        let (_, claims) = self.access_token().await?;
+
+        Ok(claims)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use async_trait::async_trait;
+    use matches::assert_matches;
+    use mockito::Matcher;
+    use serde_json::json;
+    use tokio;
+
+    use crate::sessions::{InMemoryTokenStore, testing};
+    use crate::sso::ClientId;
+    use crate::user_management::AuthenticationResponse;
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    async fn server() -> mockito::ServerGuard {
+        let mut server = mockito::Server::new_async().await;
+
+        server
+            .mock("GET", "/sso/jwks/client_123456789")
+            .with_status(200)
+            .with_body(testing::jwks().to_string())
+            .create_async()
+            .await;
+
+        server
+    }
+
+    fn verifier(server: &mockito::Server) -> AccessTokenVerifier {
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        AccessTokenVerifier::new(&workos, &ClientId::from("client_123456789"))
+            .issuer("https://api.workos.com")
+    }
+
+    fn tokens(exp_offset: i64) -> SessionTokens {
+        SessionTokens {
+            access_token: testing::access_token(exp_offset),
+            refresh_token: RefreshToken::from("Xw0NsCVXMBf7svAoIoKBmkpEK"),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_returns_a_valid_access_token_without_refreshing() {
+        let mut server = server().await;
+
+        let refresh = server
+            .mock("POST", "/user_management/authenticate")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let tokens = tokens(300);
+        let session = SessionManager::new(&verifier(&server), tokens.clone());
+
+        let (access_token, claims) = session.access_token().await.unwrap();
+
+        assert_eq!(access_token, tokens.access_token);
+        assert_eq!(claims.sub.to_string(), "user_01E4ZCR3C56J083X43JQXF3JK5");
+        refresh.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_refreshes_an_expired_access_token_once_for_concurrent_callers() {
+        let mut server = server().await;
+        let refreshed_access_token = testing::access_token(600);
+
+        let refresh = server
+            .mock("POST", "/user_management/authenticate")
+            .match_body(Matcher::PartialJson(json!({
+                "grant_type": "refresh_token",
+                "refresh_token": "Xw0NsCVXMBf7svAoIoKBmkpEK"
+            })))
+            .with_status(200)
+            .with_body(
+                testing::authentication_response(
+                    &refreshed_access_token,
+                    "yAjhKk123NLIjdrBdGZPf8pLIDvK",
+                )
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let refreshes = Arc::new(AtomicUsize::new(0));
+        let session = SessionManager::new(&verifier(&server), tokens(-300)).on_tokens_refreshed({
+            let refreshes = refreshes.clone();
+            move |tokens| {
+                assert_eq!(
+                    tokens.refresh_token,
+                    RefreshToken::from("yAjhKk123NLIjdrBdGZPf8pLIDvK")
+                );
+                refreshes.fetch_add(1, Ordering::SeqCst);
+                async { Ok(()) }
+            }
+        });
+
+        let (first, second) = tokio::join!(session.access_token(), session.access_token());
+
+        assert_eq!(first.unwrap().0, refreshed_access_token);
+        assert_eq!(second.unwrap().0, refreshed_access_token);
+        assert_eq!(
+            session.tokens().await.refresh_token,
+            RefreshToken::from("yAjhKk123NLIjdrBdGZPf8pLIDvK")
+        );
+        assert_eq!(refreshes.load(Ordering::SeqCst), 1);
+        refresh.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_awaits_the_hook_before_returning_the_refreshed_access_token() {
+        let mut server = server().await;
+        let refreshed_access_token = testing::access_token(600);
+
+        server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(200)
+            .with_body(
+                testing::authentication_response(
+                    &refreshed_access_token,
+                    "yAjhKk123NLIjdrBdGZPf8pLIDvK",
+                )
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let session = SessionManager::new(&verifier(&server), tokens(-300))
+            .on_tokens_refreshed(|_| async { Err("database unavailable".into()) });
+
+        let result = session.access_token().await;
+        assert_matches!(result, Err(SessionManagerError::TokensRefreshed(_)));
+
+        let result = session.access_token().await;
+        assert_matches!(result, Err(SessionManagerError::TokensRefreshed(_)));
+        assert_eq!(session.tokens().await.access_token, refreshed_access_token)
+    }
+
+    #[tokio::test]
+    async fn it_retries_the_hook_until_it_succeeds() {
+        let mut server = server().await;
+        let refreshed_access_token = testing::access_token(600);
+
+        let refresh = server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(200)
+            .with_body(
+                testing::authentication_response(
+                    &refreshed_access_token,
+                    "yAjhKk123NLIjdrBdGZPf8pLIDvK",
+                )
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let session = SessionManager::new(&verifier(&server), tokens(-300)).on_tokens_refreshed({
+            let calls = calls.clone();
+            move |tokens| {
+                assert_eq!(
+                    tokens.refresh_token,
+                    RefreshToken::from("yAjhKk123NLIjdrBdGZPf8pLIDvK")
+                );
+                let failed = calls.fetch_add(1, Ordering::SeqCst) == 0;
+                async move {
+                    if failed {
+                        return Err("database unavailable".into());
+                    }
+
+                    Ok(())
+                }
+            }
+        });
+
+        let result = session.access_token().await;
+        assert_matches!(result, Err(SessionManagerError::TokensRefreshed(_)));
+
+        let (access_token, _) = session.access_token().await.unwrap();
+        assert_eq!(access_token, refreshed_access_token);
+
+        session.access_token().await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        refresh.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_loads_a_session_from_the_store_and_writes_back_refreshed_sessions() {
+        let mut server = server().await;
+        let refreshed_access_token = testing::access_token(600);
+
+        server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(200)
+            .with_body(
+                testing::authentication_response(
+                    &refreshed_access_token,
+                    "yAjhKk123NLIjdrBdGZPf8pLIDvK",
+                )
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let store = Arc::new(InMemoryTokenStore::new());
+        let session_id = SessionId::from("session_01H93ZY4F80QPBEZ1R5B2SHQG8");
+        let cookie_password = CookiePassword::from("DQhXs6bZPy8pYbd8bQJAVvoaXUwXSvLJ");
+
+        let session = SessionData::from(
+            serde_json::from_value::<AuthenticationResponse>(testing::authentication_response(
+                &testing::access_token(-300),
+                "Xw0NsCVXMBf7svAoIoKBmkpEK",
+            ))
+            .unwrap(),
+        );
+        store
+            .set(&session_id, &session.seal(&cookie_password).unwrap())
+            .await
+            .unwrap();
+
+        let manager = SessionManager::load(
+            &verifier(&server),
+            store.clone(),
+            &session_id,
+            &cookie_password,
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        let (access_token, _) = manager.access_token().await.unwrap();
+        assert_eq!(access_token, refreshed_access_token);
+
+        let stored = SessionData::unseal(
+            &store.get(&session_id).await.unwrap().unwrap(),
+            &cookie_password,
+        )
+        .unwrap();
+        assert_eq!(stored.access_token, refreshed_access_token);
+        assert_eq!(
+            stored.refresh_token,
+            RefreshToken::from("yAjhKk123NLIjdrBdGZPf8pLIDvK")
+        )
+    }
+
+    #[tokio::test]
+    async fn it_keeps_the_refreshed_tokens_when_the_store_fails() {
+        #[derive(Default)]
+        struct FlakyTokenStore {
+            failures: AtomicUsize,
+            store: InMemoryTokenStore,
+        }
+
+        #[async_trait]
+        impl TokenStore for FlakyTokenStore {
+            async fn get(&self, session_id: &SessionId) -> Result<Option<String>, TokenStoreError> {
+                self.store.get(session_id).await
+            }
+
+            async fn set(
+                &self,
+                session_id: &SessionId,
+                sealed_session: &str,
+            ) -> Result<(), TokenStoreError> {
+                if self.failures.fetch_add(1, Ordering::SeqCst) == 0 {
+                    return Err("connection reset".into());
+                }
+
+                self.store.set(session_id, sealed_session).await
+            }
+
+            async fn delete(&self, session_id: &SessionId) -> Result<(), TokenStoreError> {
+                self.store.delete(session_id).await
+            }
+        }
+
+        let mut server = server().await;
+        let refreshed_access_token = testing::access_token(600);
+
+        let refresh = server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(200)
+            .with_body(
+                testing::authentication_response(
+                    &refreshed_access_token,
+                    "yAjhKk123NLIjdrBdGZPf8pLIDvK",
+                )
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let store = Arc::new(FlakyTokenStore::default());
+        let cookie_password = CookiePassword::from("DQhXs6bZPy8pYbd8bQJAVvoaXUwXSvLJ");
+
+        let session = SessionManager::new(&verifier(&server), tokens(-300))
+            .store(store.clone(), &cookie_password);
+
+        let result = session.access_token().await;
+        assert_matches!(result, Err(SessionManagerError::Store(_)));
+        assert_eq!(
+            session.tokens().await.refresh_token,
+            RefreshToken::from("yAjhKk123NLIjdrBdGZPf8pLIDvK")
+        );
+
+        let (access_token, _) = session.access_token().await.unwrap();
+        assert_eq!(access_token, refreshed_access_token);
+
+        let stored = SessionData::unseal(
+            &store
+                .get(&SessionId::from("session_01H93ZY4F80QPBEZ1R5B2SHQG8"))
+                .await
+                .unwrap()
+                .unwrap(),
+            &cookie_password,
+        )
+        .unwrap();
+        assert_eq!(
+            stored.refresh_token,
+            RefreshToken::from("yAjhKk123NLIjdrBdGZPf8pLIDvK")
+        );
+        refresh.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn it_returns_none_when_the_session_is_not_stored() {
+        let server = server().await;
+
+        let manager = SessionManager::load(
+            &verifier(&server),
+            Arc::new(InMemoryTokenStore::new()),
+            &SessionId::from("session_01H93ZY4F80QPBEZ1R5B2SHQG8"),
+            &CookiePassword::from("DQhXs6bZPy8pYbd8bQJAVvoaXUwXSvLJ"),
+        )
+        .await
+        .unwrap();
+
+        assert!(manager.is_none())
+    }
+
+    #[tokio::test]
+    async fn it_returns_an_error_when_the_refresh_fails() {
+        let mut server = server().await;
+
+        server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(400)
+            .with_body(
+                json!({
+                    "error": "invalid_grant",
+                    "error_description": "Session has already ended."
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let tokens = tokens(-300);
+        let session = SessionManager::new(&verifier(&server), tokens.clone());
+
+        let result = session.access_token().await;
+
+        assert_matches!(result, Err(SessionManagerError::Refresh(_)));
+        assert_eq!(session.tokens().await, tokens)
+    }
+}
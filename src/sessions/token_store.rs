@@ -0,0 +1,103 @@
+#[cfg(feature = "redis")]
+mod redis;
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+
+use crate::user_management::SessionId;
+
+#[cfg(feature = "redis")]
+pub use self::redis::*;
+
+/// An error returned from a [`TokenStore`].
+pub type TokenStoreError = Box<dyn Error + Send + Sync>;
+
+/// A store for sealed sessions, keyed by the ID of the session.
+///
+/// Implement this trait to share sessions across multiple instances of a service. A Redis implementation is
+/// available with the `redis` feature.
+#[async_trait]
+pub trait TokenStore: Send + Sync {
+    /// Returns the sealed session with the provided ID, if any.
+    async fn get(&self, session_id: &SessionId) -> Result<Option<String>, TokenStoreError>;
+
+    /// Stores the sealed session under the provided ID, replacing any previously stored session.
+    async fn set(
+        &self,
+        session_id: &SessionId,
+        sealed_session: &str,
+    ) -> Result<(), TokenStoreError>;
+
+    /// Deletes the sealed session with the provided ID.
+    async fn delete(&self, session_id: &SessionId) -> Result<(), TokenStoreError>;
+}
+
+/// An in-memory [`TokenStore`].
+///
+/// Sessions are only stored in the current process, so this store is not suitable for deployments with multiple
+/// instances behind a load balancer.
+#[derive(Debug, Default)]
+pub struct InMemoryTokenStore {
+    sessions: Mutex<HashMap<SessionId, String>>,
+}
+
+impl InMemoryTokenStore {
+    /// Returns a new, empty [`InMemoryTokenStore`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl TokenStore for InMemoryTokenStore {
+    async fn get(&self, session_id: &SessionId) -> Result<Option<String>, TokenStoreError> {
+        let sessions = self.sessions.lock().map_err(|err| err.to_string())?;
+
+        Ok(sessions.get(session_id).cloned())
+    }
+
+    async fn set(
+        &self,
+        session_id: &SessionId,
+        sealed_session: &str,
+    ) -> Result<(), TokenStoreError> {
+        let mut sessions = self.sessions.lock().map_err(|err| err.to_string())?;
+
+        sessions.insert(session_id.clone(), sealed_session.to_owned());
+
+        Ok(())
+    }
+
+    async fn delete(&self, session_id: &SessionId) -> Result<(), TokenStoreError> {
+        let mut sessions = self.sessions.lock().map_err(|err| err.to_string())?;
+
+        sessions.remove(session_id);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[tokio::test]
+    async fn it_stores_and_deletes_sessions() {
+        let store = InMemoryTokenStore::new();
+        let session_id = SessionId::from("session_01H93ZY4F80QPBEZ1R5B2SHQG8");
+
+        assert_eq!(store.get(&session_id).await.unwrap(), None);
+
+        store.set(&session_id, "Fe26.2*1*sealed").await.unwrap();
+        assert_eq!(
+            store.get(&session_id).await.unwrap(),
+            Some("Fe26.2*1*sealed".to_string())
+        );
+
+        store.delete(&session_id).await.unwrap();
+        assert_eq!(store.get(&session_id).await.unwrap(), None)
+    }
+}
@@ -0,0 +1,86 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use redis::AsyncCommands;
+use redis::aio::ConnectionManager;
+
+use crate::sessions::{SessionData, TokenStore, TokenStoreError};
+use crate::user_management::SessionId;
+
+/// A [`TokenStore`] backed by Redis.
+///
+/// Sessions expire after the time to live, which defaults to [`SessionData::DEFAULT_SEAL_TTL`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # use workos_sdk::sessions::RedisTokenStore;
+/// # async fn run() -> Result<(), redis::RedisError> {
+/// let client = redis::Client::open("redis://127.0.0.1/")?;
+/// let store = RedisTokenStore::new(client.get_connection_manager().await?).prefix("myapp:session:");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct RedisTokenStore {
+    connection: ConnectionManager,
+    prefix: String,
+    ttl: Duration,
+}
+
+impl RedisTokenStore {
+    /// Returns a new [`RedisTokenStore`] using the provided connection.
+    pub fn new(connection: ConnectionManager) -> Self {
+        Self {
+            connection,
+            prefix: "workos:session:".to_string(),
+            ttl: SessionData::DEFAULT_SEAL_TTL,
+        }
+    }
+
+    /// Sets the prefix of the keys sessions are stored under, which defaults to `workos:session:`.
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Sets the time to live of stored sessions.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    fn key(&self, session_id: &SessionId) -> String {
+        format!("{}{}", self.prefix, session_id)
+    }
+}
+
+#[async_trait]
+impl TokenStore for RedisTokenStore {
+    async fn get(&self, session_id: &SessionId) -> Result<Option<String>, TokenStoreError> {
+        let sealed_session: Option<String> =
+            self.connection.clone().get(self.key(session_id)).await?;
+
+        Ok(sealed_session)
+    }
+
+    async fn set(
+        &self,
+        session_id: &SessionId,
+        sealed_session: &str,
+    ) -> Result<(), TokenStoreError> {
+        let _: () = self
+            .connection
+            .clone()
+            .set_ex(self.key(session_id), sealed_session, self.ttl.as_secs())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, session_id: &SessionId) -> Result<(), TokenStoreError> {
+        let _: usize = self.connection.clone().del(self.key(session_id)).await?;
+
+        Ok(())
+    }
+}
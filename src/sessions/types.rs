@@ -0,0 +1,7 @@
+mod access_token_claims;
+mod cookie_password;
+mod session_data;
+
+pub use access_token_claims::*;
+pub use cookie_password::*;
+pub use session_data::*;
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::KnownOrUnknown;
+use crate::organizations::OrganizationId;
+use crate::user_management::{SessionId, UserId};
+
+/// The claims of an AuthKit access token.
+///
+/// [WorkOS Docs: Access token](https://workos.com/docs/user-management/sessions/access-token)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AccessTokenClaims {
+    /// The issuer of the access token.
+    pub iss: Option<String>,
+
+    /// The ID of the user.
+    pub sub: UserId,
+
+    /// The ID of the session.
+    pub sid: SessionId,
+
+    /// The ID of the organization the session is authorized for.
+    pub org_id: Option<OrganizationId>,
+
+    /// The slug of the role of the user in the organization.
+    pub role: Option<String>,
+
+    /// The permissions granted by the role of the user in the organization.
+    #[serde(default)]
+    pub permissions: Vec<String>,
+
+    /// The entitlements of the organization.
+    #[serde(default)]
+    pub entitlements: Vec<String>,
+
+    /// The feature flags enabled for the user or organization.
+    #[serde(default)]
+    pub feature_flags: Vec<String>,
+
+    /// The time the access token expires, as a Unix timestamp.
+    pub exp: i64,
+
+    /// The time the access token was issued, as a Unix timestamp.
+    pub iat: Option<i64>,
+
+    /// The unique ID of the access token.
+    pub jti: Option<String>,
+
+    /// Any other claims, such as the ones added with a JWT template.
+    #[serde(flatten)]
+    pub custom: HashMap<String, Value>,
+}
+
+impl AccessTokenClaims {
+    /// Returns the custom claims deserialized into `C`, or the raw claims if they don't match `C`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use serde::Deserialize;
+    /// # use workos_sdk::KnownOrUnknown;
+    /// # use workos_sdk::sessions::AccessTokenClaims;
+    /// #[derive(Deserialize)]
+    /// struct TenantClaims {
+    ///     tenant_id: String,
+    /// }
+    ///
+    /// # fn run(claims: &AccessTokenClaims) {
+    /// match claims.custom_claims::<TenantClaims>() {
+    ///     KnownOrUnknown::Known(TenantClaims { tenant_id }) => {}
+    ///     KnownOrUnknown::Unknown(raw) => {}
+    /// }
+    /// # }
+    /// ```
+    pub fn custom_claims<C: DeserializeOwned>(&self) -> KnownOrUnknown<C, &HashMap<String, Value>> {
+        // Round-trip through a `Value` so `C` can be deserialized from the borrowed map.
+        let value = Value::Object(
+            self.custom
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+        );
+
+        match serde_json::from_value(value) {
+            Ok(claims) => KnownOrUnknown::Known(claims),
+            Err(_) => KnownOrUnknown::Unknown(&self.custom),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct TenantClaims {
+        tenant_id: String,
+    }
+
+    fn claims(custom: Value) -> AccessTokenClaims {
+        let mut claims = json!({
+            "iss": "https://api.workos.com",
+            "sub": "user_01E4ZCR3C56J083X43JQXF3JK5",
+            "sid": "session_01H93ZY4F80QPBEZ1R5B2SHQG8",
+            "org_id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+            "role": "admin",
+            "permissions": ["reports:read", "reports:write"],
+            "exp": 1710000000,
+            "iat": 1709999700
+        });
+        claims
+            .as_object_mut()
+            .unwrap()
+            .extend(custom.as_object().unwrap().clone());
+
+        serde_json::from_value(claims).unwrap()
+    }
+
+    #[test]
+    fn it_deserializes_the_standard_claims() {
+        let claims = claims(json!({}));
+
+        assert_eq!(claims.sub, UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5"));
+        assert_eq!(
+            claims.org_id,
+            Some(OrganizationId::from("org_01EHZNVPK3SFK441A1RGBFSHRT"))
+        );
+        assert_eq!(claims.permissions, vec!["reports:read", "reports:write"]);
+        assert_eq!(claims.entitlements, Vec::<String>::new());
+        assert!(claims.custom.is_empty())
+    }
+
+    #[test]
+    fn it_deserializes_known_custom_claims() {
+        let claims = claims(json!({ "tenant_id": "tenant_123" }));
+
+        assert_matches!(
+            claims.custom_claims::<TenantClaims>(),
+            KnownOrUnknown::Known(TenantClaims { tenant_id }) if tenant_id == "tenant_123"
+        )
+    }
+
+    #[test]
+    fn it_returns_the_raw_custom_claims_when_they_are_unknown() {
+        let claims = claims(json!({ "tenant": 123 }));
+
+        assert_matches!(
+            claims.custom_claims::<TenantClaims>(),
+            KnownOrUnknown::Unknown(raw) if raw["tenant"] == json!(123)
+        )
+    }
+}
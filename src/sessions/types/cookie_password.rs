@@ -0,0 +1,8 @@
+use derive_more::{Deref, Display, From};
+
+/// A password used to seal and unseal session cookies.
+///
+/// The password must be at least 32 characters long and should be shared by every service that reads the cookie.
+#[derive(Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord)]
+#[from(forward)]
+pub struct CookiePassword(String);
@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+
+use crate::organizations::OrganizationId;
+use crate::sso::AccessToken;
+use crate::user_management::{AuthenticationResponse, Impersonator, RefreshToken, User};
+
+/// The data stored in a sealed session cookie.
+///
+/// The data is serialized the same way as by the other WorkOS SDKs, so sealed sessions can be shared between services
+/// written in different languages.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionData {
+    /// The access token of the session.
+    pub access_token: AccessToken,
+
+    /// The refresh token of the session.
+    pub refresh_token: RefreshToken,
+
+    /// The user the session belongs to.
+    #[serde(with = "camel_case_user")]
+    pub user: User,
+
+    /// The WorkOS Dashboard user who is impersonating the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub impersonator: Option<Impersonator>,
+
+    /// The organization the session is authorized for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<OrganizationId>,
+}
+
+impl From<AuthenticationResponse> for SessionData {
+    fn from(response: AuthenticationResponse) -> Self {
+        Self {
+            access_token: response.access_token,
+            refresh_token: response.refresh_token,
+            user: response.user,
+            impersonator: response.impersonator,
+            organization_id: response.organization_id,
+        }
+    }
+}
+
+/// (De)serializes the top-level fields of a [`User`] in camel case, as done by the Node SDK.
+mod camel_case_user {
+    use serde::de::Error as _;
+    use serde::ser::Error as _;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde_json::{Map, Value};
+
+    use crate::user_management::User;
+
+    pub fn serialize<S: Serializer>(user: &User, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = serde_json::to_value(user).map_err(S::Error::custom)?;
+
+        rename_keys(value, to_camel_case).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<User, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+
+        serde_json::from_value(rename_keys(value, to_snake_case)).map_err(D::Error::custom)
+    }
+
+    fn rename_keys(value: Value, rename: fn(&str) -> String) -> Value {
+        match value {
+            Value::Object(object) => Value::Object(
+                object
+                    .into_iter()
+                    .map(|(key, value)| (rename(&key), value))
+                    .collect::<Map<_, _>>(),
+            ),
+            value => value,
+        }
+    }
+
+    fn to_camel_case(key: &str) -> String {
+        let mut parts = key.split('_');
+        let mut renamed = parts.next().unwrap_or_default().to_string();
+
+        for part in parts {
+            let mut chars = part.chars();
+            if let Some(first) = chars.next() {
+                renamed.extend(first.to_uppercase());
+                renamed.push_str(chars.as_str());
+            }
+        }
+
+        renamed
+    }
+
+    fn to_snake_case(key: &str) -> String {
+        let mut renamed = String::with_capacity(key.len());
+
+        for char in key.chars() {
+            if char.is_ascii_uppercase() {
+                renamed.push('_');
+                renamed.push(char.to_ascii_lowercase());
+            } else {
+                renamed.push(char);
+            }
+        }
+
+        renamed
+    }
+}
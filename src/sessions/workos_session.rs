@@ -0,0 +1,112 @@
+#[cfg(feature = "actix")]
+mod actix;
+#[cfg(feature = "axum")]
+mod axum;
+#[cfg(any(feature = "axum", feature = "tower"))]
+pub(super) mod http;
+#[cfg(feature = "tower")]
+mod tower;
+
+#[cfg(feature = "actix")]
+pub use self::actix::*;
+#[cfg(feature = "axum")]
+pub use self::axum::*;
+#[cfg(feature = "tower")]
+pub use self::tower::*;
+
+use thiserror::Error;
+
+use crate::WorkOsError;
+use crate::sessions::{AccessTokenClaims, RefreshSessionError, Requirement, SessionData};
+use crate::sso::AccessToken;
+use crate::user_management::GetJwksError;
+
+/// A verified AuthKit session.
+///
+/// When used as an extractor, the session is authenticated with the
+/// [`SessionAuthenticator`](crate::sessions::SessionAuthenticator) from the application state, using a bearer token
+/// or the session cookie. With axum the authenticator is obtained through `FromRef`, with actix-web it must be
+/// registered as `web::Data<SessionAuthenticator>`. The extractor rejects expired sessions, as it cannot update the
+/// session cookie; use the session middleware to refresh them transparently.
+#[derive(Clone, Debug)]
+pub struct WorkOsSession {
+    /// The verified access token.
+    pub access_token: AccessToken,
+
+    /// The claims of the access token.
+    pub claims: AccessTokenClaims,
+
+    /// The data of the session, if it was authenticated with the session cookie.
+    pub data: Option<SessionData>,
+
+    /// The new sealed session, if the session was refreshed.
+    pub refreshed_session: Option<String>,
+}
+
+impl WorkOsSession {
+    /// Rejects the session with [`WorkOsSessionRejection::Forbidden`] if its claims do not satisfy the requirement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos_sdk::sessions::*;
+    /// fn delete_report(session: &WorkOsSession) -> Result<(), WorkOsSessionRejection> {
+    ///     session.require(&RequirePermission("reports:delete"))?;
+    ///
+    ///     // Delete the report.
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn require(&self, requirement: &impl Requirement) -> Result<(), WorkOsSessionRejection> {
+        if self.claims.satisfies(requirement) {
+            Ok(())
+        } else {
+            Err(WorkOsSessionRejection::Forbidden)
+        }
+    }
+}
+
+/// A rejection returned when authenticating a [`WorkOsSession`] fails.
+#[derive(Debug, Error)]
+pub enum WorkOsSessionRejection {
+    /// The request contains neither a bearer token nor a session cookie.
+    #[error("missing credentials")]
+    MissingCredentials,
+
+    /// The access token has expired.
+    #[error("session expired")]
+    SessionExpired,
+
+    /// The bearer token or session cookie is invalid.
+    #[error("invalid session")]
+    InvalidSession,
+
+    /// The session does not satisfy the [`Requirement`] of the route.
+    #[error("forbidden")]
+    Forbidden,
+
+    /// No [`SessionAuthenticator`](crate::sessions::SessionAuthenticator) was registered with the application.
+    #[error("missing session authenticator")]
+    MissingAuthenticator,
+
+    /// The JWKS could not be fetched to verify the access token.
+    #[error("failed to fetch JWKS")]
+    Jwks(#[source] WorkOsError<GetJwksError>),
+
+    /// The expired session could not be refreshed, e.g. because WorkOS is unavailable or rejected the API key.
+    ///
+    /// Refresh tokens rejected by WorkOS result in [`WorkOsSessionRejection::InvalidSession`] instead.
+    #[error("failed to refresh session")]
+    Refresh(#[source] RefreshSessionError),
+}
+
+impl WorkOsSessionRejection {
+    /// The HTTP status code that should be returned for the rejection.
+    pub fn status_code(&self) -> u16 {
+        match self {
+            Self::MissingCredentials | Self::SessionExpired | Self::InvalidSession => 401,
+            Self::Forbidden => 403,
+            Self::MissingAuthenticator | Self::Jwks(_) | Self::Refresh(_) => 500,
+        }
+    }
+}
@@ -0,0 +1,391 @@
+use std::future::{Ready, ready};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use actix_web::body::EitherBody;
+use actix_web::dev::{Payload, Service, ServiceRequest, ServiceResponse, Transform, forward_ready};
+use actix_web::http::StatusCode;
+use actix_web::http::header::{self, HeaderMap, HeaderValue};
+use actix_web::{Error, FromRequest, HttpMessage, HttpRequest, HttpResponse, ResponseError, web};
+use futures_util::future::LocalBoxFuture;
+
+use crate::sessions::{
+    SessionAuthenticator, SessionCredentials, WorkOsSession, WorkOsSessionRejection,
+};
+
+impl FromRequest for WorkOsSession {
+    type Error = WorkOsSessionRejection;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let session = req.extensions().get::<WorkOsSession>().cloned();
+        let authenticator = req.app_data::<web::Data<SessionAuthenticator>>().cloned();
+        let headers = req.headers().clone();
+
+        Box::pin(async move {
+            if let Some(session) = session {
+                return Ok(session);
+            }
+
+            let authenticator =
+                authenticator.ok_or(WorkOsSessionRejection::MissingAuthenticator)?;
+
+            authenticator
+                .authenticate(credentials(&headers, &authenticator), false)
+                .await
+        })
+    }
+}
+
+impl ResponseError for WorkOsSessionRejection {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(WorkOsSessionRejection::status_code(self))
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(ResponseError::status_code(self)).body(self.to_string())
+    }
+}
+
+type ErrorHandler = Arc<dyn Fn(&WorkOsSessionRejection, &HttpRequest) -> HttpResponse>;
+
+/// A middleware that requires requests to have a valid session.
+///
+/// The [`WorkOsSession`] is inserted into the request extensions, so handlers can extract it.
+/// Expired session cookies are refreshed and the new sealed session is set on the response.
+/// Unauthenticated requests are rejected with 401 Unauthorized, or redirected to the sign in URL of the
+/// [`SessionAuthenticator`] if there is one and the request does not contain a bearer token.
+/// Use [`SessionMiddleware::error_handler`] to customize the responses.
+///
+/// # Examples
+///
+/// ```
+/// # use actix_web::{App, web};
+/// # use workos_sdk::sessions::*;
+/// # fn run(authenticator: SessionAuthenticator) {
+/// let app = App::new()
+///     .wrap(SessionMiddleware::new(authenticator))
+///     .route("/dashboard", web::get().to(|session: WorkOsSession| async move {
+///         format!("Hello, {}!", session.claims.sub)
+///     }));
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct SessionMiddleware {
+    authenticator: SessionAuthenticator,
+    error_handler: Option<ErrorHandler>,
+}
+
+impl SessionMiddleware {
+    /// Returns a new [`SessionMiddleware`] that authenticates requests with the provided authenticator.
+    pub fn new(authenticator: SessionAuthenticator) -> Self {
+        Self {
+            authenticator,
+            error_handler: None,
+        }
+    }
+
+    /// Sets the handler that builds the response for unauthenticated requests.
+    pub fn error_handler(
+        mut self,
+        error_handler: impl Fn(&WorkOsSessionRejection, &HttpRequest) -> HttpResponse + 'static,
+    ) -> Self {
+        self.error_handler = Some(Arc::new(error_handler));
+        self
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for SessionMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = SessionMiddlewareService<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(SessionMiddlewareService {
+            service: Rc::new(service),
+            middleware: self.clone(),
+        }))
+    }
+}
+
+/// The service returned by [`SessionMiddleware`].
+pub struct SessionMiddlewareService<S> {
+    service: Rc<S>,
+    middleware: SessionMiddleware,
+}
+
+impl<S, B> Service<ServiceRequest> for SessionMiddlewareService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+        let SessionMiddleware {
+            authenticator,
+            error_handler,
+        } = self.middleware.clone();
+
+        Box::pin(async move {
+            let credentials = credentials(req.headers(), &authenticator);
+            let is_bearer = matches!(credentials, Some(SessionCredentials::Bearer(_)));
+
+            let session = match authenticator.authenticate(credentials, true).await {
+                Ok(session) => session,
+                Err(rejection) => {
+                    let response = match error_handler {
+                        Some(error_handler) => error_handler(&rejection, req.request()),
+                        None => match &authenticator.sign_in_url {
+                            Some(sign_in_url) if !is_bearer && rejection.status_code() == 401 => {
+                                HttpResponse::SeeOther()
+                                    .insert_header((header::LOCATION, sign_in_url.as_str()))
+                                    .insert_header((
+                                        header::SET_COOKIE,
+                                        authenticator.clear_session_cookie(),
+                                    ))
+                                    .finish()
+                            }
+                            _ => rejection.error_response(),
+                        },
+                    };
+
+                    return Ok(req.into_response(response).map_into_right_body());
+                }
+            };
+
+            let refreshed_session = session.refreshed_session.clone();
+            req.extensions_mut().insert(session);
+
+            let mut response = service.call(req).await?;
+
+            if let Some(refreshed_session) = refreshed_session
+                && let Ok(value) =
+                    HeaderValue::from_str(&authenticator.session_cookie(&refreshed_session))
+            {
+                response.headers_mut().append(header::SET_COOKIE, value);
+            }
+
+            Ok(response.map_into_left_body())
+        })
+    }
+}
+
+fn credentials(
+    headers: &HeaderMap,
+    authenticator: &SessionAuthenticator,
+) -> Option<SessionCredentials> {
+    SessionCredentials::from_headers(
+        headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok()),
+        headers
+            .get_all(header::COOKIE)
+            .filter_map(|value| value.to_str().ok()),
+        &authenticator.cookie_name,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use actix_web::App;
+    use actix_web::test::{TestRequest, call_service, init_service, read_body};
+    use matches::assert_matches;
+
+    use crate::sessions::{AccessTokenVerifier, CookiePassword, SessionData, testing};
+    use crate::sso::{AccessToken, ClientId};
+    use crate::user_management::AuthenticationResponse;
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    async fn server() -> mockito::ServerGuard {
+        let mut server = mockito::Server::new_async().await;
+
+        server
+            .mock("GET", "/sso/jwks/client_123456789")
+            .with_status(200)
+            .with_body(testing::jwks().to_string())
+            .create_async()
+            .await;
+
+        server
+    }
+
+    fn authenticator(server: &mockito::Server) -> SessionAuthenticator {
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        SessionAuthenticator::new(
+            AccessTokenVerifier::new(&workos, &ClientId::from("client_123456789"))
+                .issuer("https://api.workos.com"),
+            &CookiePassword::from("DQhXs6bZPy8pYbd8bQJAVvoaXUwXSvLJ"),
+        )
+    }
+
+    fn sealed_session(access_token: &AccessToken) -> String {
+        SessionData::from(
+            serde_json::from_value::<AuthenticationResponse>(testing::authentication_response(
+                access_token,
+                "Xw0NsCVXMBf7svAoIoKBmkpEK",
+            ))
+            .unwrap(),
+        )
+        .seal(&CookiePassword::from("DQhXs6bZPy8pYbd8bQJAVvoaXUwXSvLJ"))
+        .unwrap()
+    }
+
+    async fn greet(session: WorkOsSession) -> String {
+        session.claims.sub.to_string()
+    }
+
+    #[actix_rt::test]
+    async fn it_extracts_a_session_from_a_bearer_token() {
+        let server = server().await;
+
+        let (req, mut payload) = TestRequest::get()
+            .insert_header((
+                header::AUTHORIZATION,
+                format!("Bearer {}", testing::access_token(300)),
+            ))
+            .app_data(web::Data::new(authenticator(&server)))
+            .to_http_parts();
+
+        let session = WorkOsSession::from_request(&req, &mut payload)
+            .await
+            .unwrap();
+
+        assert!(session.claims.has_role("admin"))
+    }
+
+    #[actix_rt::test]
+    async fn it_rejects_a_request_without_an_authenticator_with_internal_server_error() {
+        let (req, mut payload) = TestRequest::get().to_http_parts();
+
+        let rejection = WorkOsSession::from_request(&req, &mut payload)
+            .await
+            .unwrap_err();
+
+        assert_matches!(rejection, WorkOsSessionRejection::MissingAuthenticator);
+        assert_eq!(
+            rejection.error_response().status(),
+            StatusCode::INTERNAL_SERVER_ERROR
+        )
+    }
+
+    #[actix_rt::test]
+    async fn it_authenticates_a_session_cookie_in_the_middleware() {
+        let server = server().await;
+
+        let app = init_service(
+            App::new()
+                .wrap(SessionMiddleware::new(authenticator(&server)))
+                .route("/", web::get().to(greet)),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::get()
+                .uri("/")
+                .insert_header((
+                    header::COOKIE,
+                    format!(
+                        "wos-session={}",
+                        sealed_session(&testing::access_token(300))
+                    ),
+                ))
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(read_body(response).await, "user_01E4ZCR3C56J083X43JQXF3JK5")
+    }
+
+    #[actix_rt::test]
+    async fn it_refreshes_an_expired_session_cookie_in_the_middleware() {
+        let mut server = server().await;
+
+        server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(200)
+            .with_body(
+                testing::authentication_response(
+                    &testing::access_token(600),
+                    "yAjhKk123NLIjdrBdGZPf8pLIDvK",
+                )
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let app = init_service(
+            App::new()
+                .wrap(SessionMiddleware::new(authenticator(&server)))
+                .route("/", web::get().to(greet)),
+        )
+        .await;
+
+        let response = call_service(
+            &app,
+            TestRequest::get()
+                .uri("/")
+                .insert_header((
+                    header::COOKIE,
+                    format!(
+                        "wos-session={}",
+                        sealed_session(&testing::access_token(-300))
+                    ),
+                ))
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(
+            response
+                .headers()
+                .get(header::SET_COOKIE)
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .starts_with("wos-session=Fe26.2*1*")
+        )
+    }
+
+    #[actix_rt::test]
+    async fn it_uses_the_configured_error_handler() {
+        let server = server().await;
+
+        let app = init_service(
+            App::new()
+                .wrap(
+                    SessionMiddleware::new(authenticator(&server)).error_handler(|rejection, _| {
+                        HttpResponse::Forbidden().body(format!("denied: {rejection}"))
+                    }),
+                )
+                .route("/", web::get().to(greet)),
+        )
+        .await;
+
+        let response = call_service(&app, TestRequest::get().uri("/").to_request()).await;
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert_eq!(read_body(response).await, "denied: missing credentials")
+    }
+}
@@ -0,0 +1,418 @@
+use axum::extract::{FromRef, FromRequestParts, Request, State};
+use axum::http::StatusCode;
+use axum::http::request::Parts;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Redirect, Response};
+
+use crate::sessions::workos_session::http::{credentials, set_cookie};
+use crate::sessions::{
+    SessionAuthenticator, SessionCredentials, WorkOsSession, WorkOsSessionRejection,
+};
+
+impl<S> FromRequestParts<S> for WorkOsSession
+where
+    SessionAuthenticator: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = WorkOsSessionRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        if let Some(session) = parts.extensions.get::<WorkOsSession>() {
+            return Ok(session.clone());
+        }
+
+        let authenticator = SessionAuthenticator::from_ref(state);
+
+        authenticator
+            .authenticate(credentials(&parts.headers, &authenticator), false)
+            .await
+    }
+}
+
+impl IntoResponse for WorkOsSessionRejection {
+    fn into_response(self) -> Response {
+        let status =
+            StatusCode::from_u16(self.status_code()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+        (status, self.to_string()).into_response()
+    }
+}
+
+/// A middleware that requires requests to have a valid session.
+///
+/// The [`WorkOsSession`] is inserted into the request extensions, so handlers can extract it or read its claims.
+/// Expired session cookies are refreshed and the new sealed session is set on the response.
+/// Unauthenticated requests are rejected with 401 Unauthorized, or redirected to the sign in URL of the
+/// [`SessionAuthenticator`] if there is one and the request does not contain a bearer token.
+///
+/// # Examples
+///
+/// ```
+/// # use axum::routing::get;
+/// # use axum::{Router, middleware};
+/// # use workos_sdk::sessions::*;
+/// # fn run(authenticator: SessionAuthenticator) {
+/// let app: Router = Router::new()
+///     .route("/dashboard", get(|session: WorkOsSession| async move {
+///         format!("Hello, {}!", session.claims.sub)
+///     }))
+///     .route_layer(middleware::from_fn_with_state(authenticator.clone(), require_session))
+///     .with_state(authenticator);
+/// # }
+/// ```
+pub async fn require_session(
+    State(authenticator): State<SessionAuthenticator>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let credentials = credentials(request.headers(), &authenticator);
+    let is_bearer = matches!(credentials, Some(SessionCredentials::Bearer(_)));
+
+    let session = match authenticator.authenticate(credentials, true).await {
+        Ok(session) => session,
+        Err(rejection) => {
+            return match &authenticator.sign_in_url {
+                Some(sign_in_url) if !is_bearer && rejection.status_code() == 401 => {
+                    let mut response = Redirect::to(sign_in_url).into_response();
+                    set_cookie(
+                        response.headers_mut(),
+                        &authenticator.clear_session_cookie(),
+                    );
+                    response
+                }
+                _ => rejection.into_response(),
+            };
+        }
+    };
+
+    let refreshed_session = session.refreshed_session.clone();
+    request.extensions_mut().insert(session);
+
+    let mut response = next.run(request).await;
+
+    if let Some(refreshed_session) = refreshed_session {
+        set_cookie(
+            response.headers_mut(),
+            &authenticator.session_cookie(&refreshed_session),
+        );
+    }
+
+    response
+}
+
+#[cfg(test)]
+mod test {
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::header;
+    use axum::middleware;
+    use axum::routing::get;
+    use matches::assert_matches;
+    use tower::ServiceExt;
+
+    use crate::sessions::{AccessTokenVerifier, CookiePassword, SessionData, testing};
+    use crate::sso::{AccessToken, ClientId};
+    use crate::user_management::AuthenticationResponse;
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    async fn server() -> mockito::ServerGuard {
+        let mut server = mockito::Server::new_async().await;
+
+        server
+            .mock("GET", "/sso/jwks/client_123456789")
+            .with_status(200)
+            .with_body(testing::jwks().to_string())
+            .create_async()
+            .await;
+
+        server
+    }
+
+    fn authenticator(server: &mockito::Server) -> SessionAuthenticator {
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        SessionAuthenticator::new(
+            AccessTokenVerifier::new(&workos, &ClientId::from("client_123456789"))
+                .issuer("https://api.workos.com"),
+            &CookiePassword::from("DQhXs6bZPy8pYbd8bQJAVvoaXUwXSvLJ"),
+        )
+    }
+
+    fn sealed_session(access_token: &AccessToken) -> String {
+        SessionData::from(
+            serde_json::from_value::<AuthenticationResponse>(testing::authentication_response(
+                access_token,
+                "Xw0NsCVXMBf7svAoIoKBmkpEK",
+            ))
+            .unwrap(),
+        )
+        .seal(&CookiePassword::from("DQhXs6bZPy8pYbd8bQJAVvoaXUwXSvLJ"))
+        .unwrap()
+    }
+
+    fn app(authenticator: SessionAuthenticator) -> Router {
+        Router::new()
+            .route(
+                "/",
+                get(|session: WorkOsSession| async move { session.claims.sub.to_string() }),
+            )
+            .route_layer(middleware::from_fn_with_state(
+                authenticator.clone(),
+                require_session,
+            ))
+            .with_state(authenticator)
+    }
+
+    #[tokio::test]
+    async fn it_extracts_a_session_from_a_bearer_token() {
+        let server = server().await;
+        let authenticator = authenticator(&server);
+
+        let request = Request::builder()
+            .header(
+                header::AUTHORIZATION,
+                format!("Bearer {}", testing::access_token(300)),
+            )
+            .body(Body::empty())
+            .unwrap();
+        let (mut parts, _) = request.into_parts();
+
+        let session = WorkOsSession::from_request_parts(&mut parts, &authenticator)
+            .await
+            .unwrap();
+
+        assert!(session.claims.has_role("admin"));
+        assert!(session.data.is_none())
+    }
+
+    #[tokio::test]
+    async fn it_rejects_an_expired_session_in_the_extractor() {
+        let server = server().await;
+        let authenticator = authenticator(&server);
+
+        let request = Request::builder()
+            .header(
+                header::COOKIE,
+                format!(
+                    "wos-session={}",
+                    sealed_session(&testing::access_token(-300))
+                ),
+            )
+            .body(Body::empty())
+            .unwrap();
+        let (mut parts, _) = request.into_parts();
+
+        let result = WorkOsSession::from_request_parts(&mut parts, &authenticator).await;
+
+        assert_matches!(result, Err(WorkOsSessionRejection::SessionExpired))
+    }
+
+    #[tokio::test]
+    async fn it_authenticates_a_session_cookie_in_the_middleware() {
+        let server = server().await;
+
+        let response = app(authenticator(&server))
+            .oneshot(
+                Request::builder()
+                    .uri("/")
+                    .header(
+                        header::COOKIE,
+                        format!(
+                            "wos-session={}",
+                            sealed_session(&testing::access_token(300))
+                        ),
+                    )
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().get(header::SET_COOKIE).is_none())
+    }
+
+    #[tokio::test]
+    async fn it_refreshes_an_expired_session_cookie_in_the_middleware() {
+        let mut server = server().await;
+
+        server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(200)
+            .with_body(
+                testing::authentication_response(
+                    &testing::access_token(600),
+                    "yAjhKk123NLIjdrBdGZPf8pLIDvK",
+                )
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let response = app(authenticator(&server))
+            .oneshot(
+                Request::builder()
+                    .uri("/")
+                    .header(
+                        header::COOKIE,
+                        format!(
+                            "wos-session={}",
+                            sealed_session(&testing::access_token(-300))
+                        ),
+                    )
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(
+            response
+                .headers()
+                .get(header::SET_COOKIE)
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .starts_with("wos-session=Fe26.2*1*")
+        )
+    }
+
+    #[tokio::test]
+    async fn it_keeps_the_session_cookie_when_workos_is_unavailable() {
+        let mut server = server().await;
+
+        server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(503)
+            .create_async()
+            .await;
+
+        let response = app(authenticator(&server).sign_in_url("/login"))
+            .oneshot(
+                Request::builder()
+                    .uri("/")
+                    .header(
+                        header::COOKIE,
+                        format!(
+                            "wos-session={}",
+                            sealed_session(&testing::access_token(-300))
+                        ),
+                    )
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(response.headers().get(header::SET_COOKIE).is_none())
+    }
+
+    #[tokio::test]
+    async fn it_keeps_the_session_cookie_when_the_api_key_is_rejected() {
+        let mut server = server().await;
+
+        server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(401)
+            .create_async()
+            .await;
+
+        let response = app(authenticator(&server).sign_in_url("/login"))
+            .oneshot(
+                Request::builder()
+                    .uri("/")
+                    .header(
+                        header::COOKIE,
+                        format!(
+                            "wos-session={}",
+                            sealed_session(&testing::access_token(-300))
+                        ),
+                    )
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(response.headers().get(header::SET_COOKIE).is_none())
+    }
+
+    #[tokio::test]
+    async fn it_redirects_a_session_rejected_by_workos_to_the_sign_in_url() {
+        let mut server = server().await;
+
+        server
+            .mock("POST", "/user_management/authenticate")
+            .with_status(400)
+            .with_body(
+                serde_json::json!({
+                    "error": "invalid_grant",
+                    "error_description": "Session has already ended."
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let response = app(authenticator(&server).sign_in_url("/login"))
+            .oneshot(
+                Request::builder()
+                    .uri("/")
+                    .header(
+                        header::COOKIE,
+                        format!(
+                            "wos-session={}",
+                            sealed_session(&testing::access_token(-300))
+                        ),
+                    )
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+        assert!(
+            response
+                .headers()
+                .get(header::SET_COOKIE)
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .starts_with("wos-session=;")
+        )
+    }
+
+    #[tokio::test]
+    async fn it_rejects_a_request_without_credentials() {
+        let server = server().await;
+
+        let response = app(authenticator(&server))
+            .oneshot(Request::builder().uri("/").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED)
+    }
+
+    #[tokio::test]
+    async fn it_redirects_a_request_without_credentials_to_the_sign_in_url() {
+        let server = server().await;
+
+        let response = app(authenticator(&server).sign_in_url("/login"))
+            .oneshot(Request::builder().uri("/").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+        assert_eq!(response.headers().get(header::LOCATION).unwrap(), "/login")
+    }
+}
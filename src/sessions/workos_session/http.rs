@@ -0,0 +1,41 @@
+//! Helpers shared by the integrations built on the `http` crate.
+
+use http::{HeaderMap, HeaderValue, header};
+
+use crate::sessions::{SessionAuthenticator, SessionCredentials};
+
+/// Extracts the credentials from the `Authorization` and `Cookie` headers.
+pub(crate) fn credentials(
+    headers: &HeaderMap,
+    authenticator: &SessionAuthenticator,
+) -> Option<SessionCredentials> {
+    SessionCredentials::from_headers(
+        headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok()),
+        headers
+            .get_all(header::COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok()),
+        &authenticator.cookie_name,
+    )
+}
+
+/// Returns the value of the cookie with the provided name.
+#[cfg(feature = "axum")]
+pub(crate) fn cookie<'a>(headers: &'a HeaderMap, cookie_name: &str) -> Option<&'a str> {
+    crate::sessions::session_authenticator::find_cookie(
+        headers
+            .get_all(header::COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok()),
+        cookie_name,
+    )
+}
+
+/// Appends a `Set-Cookie` header.
+pub(crate) fn set_cookie(headers: &mut HeaderMap, cookie: &str) {
+    if let Ok(value) = HeaderValue::from_str(cookie) {
+        headers.append(header::SET_COOKIE, value);
+    }
+}
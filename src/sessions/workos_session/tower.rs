@@ -0,0 +1,481 @@
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures_util::future::{BoxFuture, Either, Ready, ready};
+use http::{HeaderValue, Request, Response, StatusCode, header};
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::sessions::workos_session::http::{credentials, set_cookie};
+use crate::sessions::{
+    RequireOrganization, RequirePermission, RequireRole, Requirement, SessionAuthenticator,
+    SessionCredentials, WorkOsSession, WorkOsSessionRejection,
+};
+
+/// A [`Layer`] that requires requests to have a valid session.
+///
+/// The [`WorkOsSession`](crate::sessions::WorkOsSession) is inserted into the request extensions. Expired session
+/// cookies are refreshed and the new sealed session is set on the response.
+///
+/// Unauthenticated requests are rejected with an empty response. gRPC requests, like the ones sent with tonic, are
+/// rejected with a `grpc-status` of `UNAUTHENTICATED` and carry the access token in the `authorization` metadata.
+/// Other requests are rejected with 401 Unauthorized, or redirected to the sign in URL of the [`SessionAuthenticator`]
+/// if there is one and the request does not contain a bearer token.
+///
+/// # Examples
+///
+/// ```
+/// # use workos_sdk::sessions::*;
+/// # fn run(authenticator: SessionAuthenticator) {
+/// let layer = SessionLayer::new(authenticator);
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct SessionLayer {
+    authenticator: SessionAuthenticator,
+}
+
+impl SessionLayer {
+    /// Returns a new [`SessionLayer`] that authenticates requests with the provided authenticator.
+    pub fn new(authenticator: SessionAuthenticator) -> Self {
+        Self { authenticator }
+    }
+}
+
+impl<S> Layer<S> for SessionLayer {
+    type Service = SessionService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        SessionService {
+            inner,
+            authenticator: self.authenticator.clone(),
+        }
+    }
+}
+
+/// The [`Service`] returned by [`SessionLayer`].
+#[derive(Clone)]
+pub struct SessionService<S> {
+    inner: S,
+    authenticator: SessionAuthenticator,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for SessionService<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send,
+    ReqBody: Send + 'static,
+    ResBody: Default + Send + 'static,
+{
+    type Response = Response<ResBody>;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<ReqBody>) -> Self::Future {
+        // Take the service that was driven to readiness and leave a clone in its place.
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let authenticator = self.authenticator.clone();
+
+        Box::pin(async move {
+            let credentials = credentials(request.headers(), &authenticator);
+            let is_bearer = matches!(credentials, Some(SessionCredentials::Bearer(_)));
+
+            let session = match authenticator.authenticate(credentials, true).await {
+                Ok(session) => session,
+                Err(rejection) => {
+                    let authenticator = (!is_bearer).then_some(&authenticator);
+                    return Ok(reject(&request, &rejection, authenticator));
+                }
+            };
+
+            let refreshed_session = session.refreshed_session.clone();
+            request.extensions_mut().insert(session);
+
+            let mut response = inner.call(request).await?;
+
+            if let Some(refreshed_session) = refreshed_session {
+                set_cookie(
+                    response.headers_mut(),
+                    &authenticator.session_cookie(&refreshed_session),
+                );
+            }
+
+            Ok(response)
+        })
+    }
+}
+
+/// A [`Layer`] that rejects requests whose session does not satisfy the requirement with 403 Forbidden.
+///
+/// The layer must be applied inside a [`SessionLayer`], which provides the session. Requests without a session are
+/// rejected with 401 Unauthorized. gRPC requests are rejected with a `grpc-status` of `PERMISSION_DENIED`.
+///
+/// [`RequireRole`], [`RequirePermission`] and [`RequireOrganization`] can be used as layers directly.
+///
+/// # Examples
+///
+/// ```
+/// # use tower_layer::Stack;
+/// # use workos_sdk::sessions::*;
+/// # fn run(authenticator: SessionAuthenticator) {
+/// let layer = Stack::new(
+///     RequirementLayer::new(RequireAny(vec![
+///         RequireRole("admin").boxed(),
+///         RequirePermission("billing:write").boxed(),
+///     ])),
+///     SessionLayer::new(authenticator),
+/// );
+/// # }
+/// ```
+pub struct RequirementLayer<R> {
+    requirement: Arc<R>,
+}
+
+impl<R: Requirement> RequirementLayer<R> {
+    /// Returns a new [`RequirementLayer`] for the provided requirement.
+    pub fn new(requirement: R) -> Self {
+        Self {
+            requirement: Arc::new(requirement),
+        }
+    }
+}
+
+impl<R> Clone for RequirementLayer<R> {
+    fn clone(&self) -> Self {
+        Self {
+            requirement: self.requirement.clone(),
+        }
+    }
+}
+
+impl<S, R> Layer<S> for RequirementLayer<R> {
+    type Service = RequirementService<S, R>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequirementService {
+            inner,
+            requirement: self.requirement.clone(),
+        }
+    }
+}
+
+impl<S, T: Clone> Layer<S> for RequireRole<T> {
+    type Service = RequirementService<S, Self>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequirementService {
+            inner,
+            requirement: Arc::new(self.clone()),
+        }
+    }
+}
+
+impl<S, T: Clone> Layer<S> for RequirePermission<T> {
+    type Service = RequirementService<S, Self>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequirementService {
+            inner,
+            requirement: Arc::new(self.clone()),
+        }
+    }
+}
+
+impl<S> Layer<S> for RequireOrganization {
+    type Service = RequirementService<S, Self>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequirementService {
+            inner,
+            requirement: Arc::new(self.clone()),
+        }
+    }
+}
+
+/// The [`Service`] returned by [`RequirementLayer`] and the requirements that can be used as layers.
+pub struct RequirementService<S, R> {
+    inner: S,
+    requirement: Arc<R>,
+}
+
+impl<S: Clone, R> Clone for RequirementService<S, R> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            requirement: self.requirement.clone(),
+        }
+    }
+}
+
+impl<S, R, ReqBody, ResBody> Service<Request<ReqBody>> for RequirementService<S, R>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    R: Requirement,
+    ResBody: Default,
+{
+    type Response = Response<ResBody>;
+    type Error = S::Error;
+    type Future = Either<S::Future, Ready<Result<Self::Response, Self::Error>>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<ReqBody>) -> Self::Future {
+        let authorized = request
+            .extensions()
+            .get::<WorkOsSession>()
+            .ok_or(WorkOsSessionRejection::MissingCredentials)
+            .and_then(|session| session.require(self.requirement.as_ref()));
+
+        match authorized {
+            Ok(()) => Either::Left(self.inner.call(request)),
+            Err(rejection) => Either::Right(ready(Ok(reject(&request, &rejection, None)))),
+        }
+    }
+}
+
+/// Builds the response for a rejected request, redirecting to the sign in URL of the authenticator if possible.
+fn reject<ReqBody, ResBody: Default>(
+    request: &Request<ReqBody>,
+    rejection: &WorkOsSessionRejection,
+    authenticator: Option<&SessionAuthenticator>,
+) -> Response<ResBody> {
+    let mut response = Response::new(ResBody::default());
+
+    let is_grpc = request
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|content_type| content_type.starts_with("application/grpc"));
+
+    if is_grpc {
+        // gRPC status codes: 16 is `UNAUTHENTICATED`, 7 is `PERMISSION_DENIED`, 13 is `INTERNAL`.
+        let grpc_status = match rejection.status_code() {
+            401 => "16",
+            403 => "7",
+            _ => "13",
+        };
+
+        response.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/grpc"),
+        );
+        response
+            .headers_mut()
+            .insert("grpc-status", HeaderValue::from_static(grpc_status));
+
+        return response;
+    }
+
+    match authenticator.and_then(|authenticator| {
+        authenticator
+            .sign_in_url
+            .as_ref()
+            .map(|sign_in_url| (authenticator, sign_in_url))
+    }) {
+        Some((authenticator, sign_in_url)) if rejection.status_code() == 401 => {
+            *response.status_mut() = StatusCode::SEE_OTHER;
+
+            if let Ok(location) = HeaderValue::from_str(sign_in_url) {
+                response.headers_mut().insert(header::LOCATION, location);
+            }
+
+            set_cookie(
+                response.headers_mut(),
+                &authenticator.clear_session_cookie(),
+            );
+        }
+        _ => {
+            *response.status_mut() = StatusCode::from_u16(rejection.status_code())
+                .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    }
+
+    response
+}
+
+#[cfg(test)]
+mod test {
+    use std::convert::Infallible;
+
+    use tower::{ServiceBuilder, ServiceExt, service_fn};
+
+    use crate::sessions::{AccessTokenVerifier, CookiePassword, WorkOsSession, testing};
+    use crate::sso::ClientId;
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    async fn server() -> mockito::ServerGuard {
+        let mut server = mockito::Server::new_async().await;
+
+        server
+            .mock("GET", "/sso/jwks/client_123456789")
+            .with_status(200)
+            .with_body(testing::jwks().to_string())
+            .create_async()
+            .await;
+
+        server
+    }
+
+    fn authenticator(server: &mockito::Server) -> SessionAuthenticator {
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        SessionAuthenticator::new(
+            AccessTokenVerifier::new(&workos, &ClientId::from("client_123456789"))
+                .issuer("https://api.workos.com"),
+            &CookiePassword::from("DQhXs6bZPy8pYbd8bQJAVvoaXUwXSvLJ"),
+        )
+    }
+
+    async fn greet(request: Request<String>) -> Result<Response<String>, Infallible> {
+        let session = request.extensions().get::<WorkOsSession>().unwrap();
+
+        Ok(Response::new(session.claims.sub.to_string()))
+    }
+
+    fn service(
+        server: &mockito::Server,
+    ) -> impl Service<Request<String>, Response = Response<String>, Error = Infallible> {
+        ServiceBuilder::new()
+            .layer(SessionLayer::new(authenticator(server)))
+            .service(service_fn(greet))
+    }
+
+    fn bearer_request(content_type: &str) -> Request<String> {
+        Request::builder()
+            .header(header::CONTENT_TYPE, content_type)
+            .header(
+                header::AUTHORIZATION,
+                format!("Bearer {}", testing::access_token(300)),
+            )
+            .body(String::new())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn it_injects_the_session_into_the_request_extensions() {
+        let server = server().await;
+
+        let response = service(&server)
+            .oneshot(
+                Request::builder()
+                    .header(
+                        header::AUTHORIZATION,
+                        format!("Bearer {}", testing::access_token(300)),
+                    )
+                    .body(String::new())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.body(), "user_01E4ZCR3C56J083X43JQXF3JK5")
+    }
+
+    #[tokio::test]
+    async fn it_rejects_a_request_with_an_expired_bearer_token() {
+        let server = server().await;
+
+        let response = service(&server)
+            .oneshot(
+                Request::builder()
+                    .header(
+                        header::AUTHORIZATION,
+                        format!("Bearer {}", testing::access_token(-300)),
+                    )
+                    .body(String::new())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED)
+    }
+
+    #[tokio::test]
+    async fn it_rejects_an_unauthenticated_grpc_request_with_a_grpc_status() {
+        let server = server().await;
+
+        let response = service(&server)
+            .oneshot(
+                Request::builder()
+                    .header(header::CONTENT_TYPE, "application/grpc")
+                    .body(String::new())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers().get("grpc-status").unwrap(), "16")
+    }
+
+    #[tokio::test]
+    async fn it_allows_a_session_with_the_required_role() {
+        let server = server().await;
+
+        let response = ServiceBuilder::new()
+            .layer(SessionLayer::new(authenticator(&server)))
+            .layer(RequireRole("admin"))
+            .service(service_fn(greet))
+            .oneshot(bearer_request("text/plain"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK)
+    }
+
+    #[tokio::test]
+    async fn it_rejects_a_session_without_the_required_permission_with_forbidden() {
+        let server = server().await;
+
+        let mut service = ServiceBuilder::new()
+            .layer(SessionLayer::new(authenticator(&server)))
+            .layer(RequirePermission("billing:write"))
+            .service(service_fn(greet));
+
+        let response = service
+            .ready()
+            .await
+            .unwrap()
+            .call(bearer_request("text/plain"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let response = service
+            .ready()
+            .await
+            .unwrap()
+            .call(bearer_request("application/grpc"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.headers().get("grpc-status").unwrap(), "7")
+    }
+
+    #[tokio::test]
+    async fn it_rejects_a_request_without_a_session_with_unauthorized() {
+        let response = ServiceBuilder::new()
+            .layer(RequirementLayer::new(RequireRole("admin")))
+            .service(service_fn(greet))
+            .oneshot(Request::new(String::new()))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED)
+    }
+}
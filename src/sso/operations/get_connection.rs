@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use thiserror::Error;
 
 use crate::sso::{Connection, ConnectionId, Sso};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{RequestBuilderExt, ResponseExt, WorkOsError, WorkOsResult};
 
 /// An error returned from [`GetConnection`].
 #[derive(Debug, Error)]
@@ -56,7 +56,7 @@ impl GetConnection for Sso<'_> {
             .client()
             .get(url)
             .bearer_auth(self.workos.key())
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
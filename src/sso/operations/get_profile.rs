@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use thiserror::Error;
 
 use crate::sso::{AccessToken, Profile, Sso};
-use crate::{ResponseExt, WorkOsResult};
+use crate::{RequestBuilderExt, ResponseExt, WorkOsResult};
 
 /// An error returned from [`GetProfile`].
 #[derive(Debug, Error)]
@@ -48,7 +48,7 @@ impl GetProfile for Sso<'_> {
             .client()
             .get(url)
             .bearer_auth(access_token)
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
@@ -4,7 +4,7 @@ use serde::Deserialize;
 use thiserror::Error;
 
 use crate::sso::{AccessToken, AuthorizationCode, ClientId, Profile, Sso};
-use crate::{WorkOsError, WorkOsResult};
+use crate::{RequestBuilderExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`GetProfileAndToken`].
 #[derive(Debug)]
@@ -121,7 +121,7 @@ impl GetProfileAndToken for Sso<'_> {
             .client()
             .post(url)
             .form(&params)
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_get_profile_and_token_error()
             .await?
@@ -3,7 +3,9 @@ use serde::Serialize;
 
 use crate::organizations::OrganizationId;
 use crate::sso::{Connection, ConnectionType, Sso};
-use crate::{KnownOrUnknown, PaginatedList, PaginationParams, ResponseExt, WorkOsResult};
+use crate::{
+    KnownOrUnknown, PaginatedList, PaginationParams, RequestBuilderExt, ResponseExt, WorkOsResult,
+};
 
 /// The parameters for [`ListConnections`].
 #[derive(Debug, Default, Serialize)]
@@ -64,8 +66,9 @@ impl ListConnections for Sso<'_> {
             .client()
             .get(url)
             .query(&params)
+            .default_limit(self.workos, &params.pagination)
             .bearer_auth(self.workos.key())
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::wire_format;
+
 /// The type of a [`Connection`](crate::sso::Connection).
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ConnectionType {
@@ -15,6 +17,12 @@ pub enum ConnectionType {
     #[serde(rename = "ADPOIDC")]
     AdpOidc,
 
+    /// Apple OAuth.
+    ///
+    /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/apple)
+    #[serde(rename = "AppleOAuth")]
+    AppleOauth,
+
     /// Auth0 SAML.
     ///
     /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/auth0-saml)
@@ -69,6 +77,18 @@ pub enum ConnectionType {
     #[serde(rename = "GenericSAML")]
     GenericSaml,
 
+    /// GitHub OAuth.
+    ///
+    /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/github)
+    #[serde(rename = "GitHubOAuth")]
+    GitHubOauth,
+
+    /// GitLab OAuth.
+    ///
+    /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/gitlab)
+    #[serde(rename = "GitLabOAuth")]
+    GitLabOauth,
+
     /// Google OAuth.
     ///
     /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/g-suite-oauth)
@@ -93,6 +113,30 @@ pub enum ConnectionType {
     #[serde(rename = "KeycloakSAML")]
     KeycloakSaml,
 
+    /// LastPass SAML.
+    ///
+    /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/lastpass-saml)
+    #[serde(rename = "LastPassSAML")]
+    LastPassSaml,
+
+    /// LinkedIn OAuth.
+    ///
+    /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/linkedin)
+    #[serde(rename = "LinkedInOAuth")]
+    LinkedInOauth,
+
+    /// Login.gov OpenID Connect (OIDC).
+    ///
+    /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/login-gov-oidc)
+    #[serde(rename = "LoginGovOidc")]
+    LoginGovOidc,
+
+    /// Magic Link.
+    ///
+    /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/magic-link)
+    #[serde(rename = "MagicLink")]
+    MagicLink,
+
     /// Microsoft OAuth.
     ///
     /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/microsoft-oauth)
@@ -141,12 +185,24 @@ pub enum ConnectionType {
     #[serde(rename = "PingOneSAML")]
     PingOneSaml,
 
+    /// Rippling SAML.
+    ///
+    /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/rippling-saml)
+    #[serde(rename = "RipplingSAML")]
+    RipplingSaml,
+
     /// Salesforce SAML.
     ///
     /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/salesforce-saml)
     #[serde(rename = "SalesforceSAML")]
     SalesforceSaml,
 
+    /// Shibboleth Generic SAML.
+    ///
+    /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/shibboleth-generic-saml)
+    #[serde(rename = "ShibbolethGenericSAML")]
+    ShibbolethGenericSaml,
+
     /// Shibboleth SAML.
     ///
     /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/shibboleth)
@@ -159,6 +215,12 @@ pub enum ConnectionType {
     #[serde(rename = "SimpleSamlPhpSAML")]
     SimpleSamlPhpSaml,
 
+    /// Slack OAuth.
+    ///
+    /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/slack)
+    #[serde(rename = "SlackOAuth")]
+    SlackOauth,
+
     /// VMware SAML.
     ///
     /// [WorkOS Docs: Integration Guide](https://workos.com/docs/integrations/vmware-saml)
@@ -166,11 +228,52 @@ pub enum ConnectionType {
     VmwareSaml,
 }
 
+wire_format!(ConnectionType {
+    AdFsSaml => "ADFSSAML",
+    AdpOidc => "ADPOIDC",
+    AppleOauth => "AppleOAuth",
+    Auth0Saml => "Auth0SAML",
+    AzureSaml => "AzureSAML",
+    CasSaml => "CASSAML",
+    ClassLinkSaml => "ClassLinkSAML",
+    CloudflareSaml => "CloudflareSAML",
+    CyberArkSaml => "CyberArkSAML",
+    DuoSaml => "DuoSAML",
+    GenericOidc => "GenericOIDC",
+    GenericSaml => "GenericSAML",
+    GitHubOauth => "GitHubOAuth",
+    GitLabOauth => "GitLabOAuth",
+    GoogleOauth => "GoogleOAuth",
+    GoogleSaml => "GoogleSAML",
+    JumpCloudSaml => "JumpCloudSAML",
+    KeycloakSaml => "KeycloakSAML",
+    LastPassSaml => "LastPassSAML",
+    LinkedInOauth => "LinkedInOAuth",
+    LoginGovOidc => "LoginGovOidc",
+    MagicLink => "MagicLink",
+    MicrosoftOauth => "MicrosoftOAuth",
+    MiniOrangeSaml => "MiniOrangeSAML",
+    NetIqSaml => "NetIqSAML",
+    OktaSaml => "OktaSAML",
+    OneLoginSaml => "OneLoginSAML",
+    OracleSaml => "OracleSAML",
+    PingFederateSaml => "PingFederateSAML",
+    PingOneSaml => "PingOneSAML",
+    RipplingSaml => "RipplingSAML",
+    SalesforceSaml => "SalesforceSAML",
+    ShibbolethGenericSaml => "ShibbolethGenericSAML",
+    ShibbolethSaml => "ShibbolethSAML",
+    SimpleSamlPhpSaml => "SimpleSamlPhpSAML",
+    SlackOauth => "SlackOAuth",
+    VmwareSaml => "VMwareSAML",
+});
+
 #[cfg(test)]
 mod test {
     use serde_json::json;
 
     use super::ConnectionType;
+    use crate::ParseEnumError;
 
     #[test]
     fn it_properly_serializes_adp_oidc() {
@@ -187,4 +290,79 @@ mod test {
             ConnectionType::AdpOidc
         )
     }
+
+    #[test]
+    fn it_round_trips_all_connection_types() {
+        for (connection_type, value) in [
+            (ConnectionType::AdFsSaml, "ADFSSAML"),
+            (ConnectionType::AdpOidc, "ADPOIDC"),
+            (ConnectionType::AppleOauth, "AppleOAuth"),
+            (ConnectionType::Auth0Saml, "Auth0SAML"),
+            (ConnectionType::AzureSaml, "AzureSAML"),
+            (ConnectionType::CasSaml, "CASSAML"),
+            (ConnectionType::ClassLinkSaml, "ClassLinkSAML"),
+            (ConnectionType::CloudflareSaml, "CloudflareSAML"),
+            (ConnectionType::CyberArkSaml, "CyberArkSAML"),
+            (ConnectionType::DuoSaml, "DuoSAML"),
+            (ConnectionType::GenericOidc, "GenericOIDC"),
+            (ConnectionType::GenericSaml, "GenericSAML"),
+            (ConnectionType::GitHubOauth, "GitHubOAuth"),
+            (ConnectionType::GitLabOauth, "GitLabOAuth"),
+            (ConnectionType::GoogleOauth, "GoogleOAuth"),
+            (ConnectionType::GoogleSaml, "GoogleSAML"),
+            (ConnectionType::JumpCloudSaml, "JumpCloudSAML"),
+            (ConnectionType::KeycloakSaml, "KeycloakSAML"),
+            (ConnectionType::LastPassSaml, "LastPassSAML"),
+            (ConnectionType::LinkedInOauth, "LinkedInOAuth"),
+            (ConnectionType::LoginGovOidc, "LoginGovOidc"),
+            (ConnectionType::MagicLink, "MagicLink"),
+            (ConnectionType::MicrosoftOauth, "MicrosoftOAuth"),
+            (ConnectionType::MiniOrangeSaml, "MiniOrangeSAML"),
+            (ConnectionType::NetIqSaml, "NetIqSAML"),
+            (ConnectionType::OktaSaml, "OktaSAML"),
+            (ConnectionType::OneLoginSaml, "OneLoginSAML"),
+            (ConnectionType::OracleSaml, "OracleSAML"),
+            (ConnectionType::PingFederateSaml, "PingFederateSAML"),
+            (ConnectionType::PingOneSaml, "PingOneSAML"),
+            (ConnectionType::RipplingSaml, "RipplingSAML"),
+            (ConnectionType::SalesforceSaml, "SalesforceSAML"),
+            (
+                ConnectionType::ShibbolethGenericSaml,
+                "ShibbolethGenericSAML",
+            ),
+            (ConnectionType::ShibbolethSaml, "ShibbolethSAML"),
+            (ConnectionType::SimpleSamlPhpSaml, "SimpleSamlPhpSAML"),
+            (ConnectionType::SlackOauth, "SlackOAuth"),
+            (ConnectionType::VmwareSaml, "VMwareSAML"),
+        ] {
+            assert_eq!(serde_json::to_value(connection_type).unwrap(), json!(value));
+            assert_eq!(
+                serde_json::from_value::<ConnectionType>(json!(value)).unwrap(),
+                connection_type
+            );
+        }
+    }
+
+    #[test]
+    fn it_round_trips_the_wire_format_of_all_connection_types() {
+        for value in ConnectionType::ALL {
+            assert_eq!(serde_json::to_value(value).unwrap(), json!(value.as_str()));
+            assert_eq!(
+                serde_json::from_value::<ConnectionType>(json!(value.as_str())).unwrap(),
+                *value
+            );
+            assert_eq!(value.as_str().parse::<ConnectionType>(), Ok(*value));
+        }
+    }
+
+    #[test]
+    fn it_fails_to_parse_unknown_connection_types() {
+        assert_eq!(
+            "FooSAML".parse::<ConnectionType>(),
+            Err(ParseEnumError {
+                type_name: "ConnectionType",
+                value: "FooSAML".to_string(),
+            })
+        );
+    }
 }
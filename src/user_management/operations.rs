@@ -1,4 +1,5 @@
 mod accept_invitation;
+mod audit_memberships;
 mod authenticate_with_code;
 mod authenticate_with_email_verification;
 mod authenticate_with_magic_auth;
@@ -10,6 +11,7 @@ mod create_password_reset;
 mod create_user;
 mod delete_user;
 mod enroll_auth_factor;
+mod export_users;
 mod find_invitation_by_token;
 mod get_authorization_url;
 mod get_email_verification;
@@ -24,6 +26,7 @@ mod get_user_by_external_id;
 mod get_user_identities;
 mod list_auth_factors;
 mod list_invitations;
+mod list_organization_memberships;
 mod list_users;
 mod reset_password;
 mod revoke_invitation;
@@ -31,6 +34,7 @@ mod send_invitation;
 mod update_user;
 
 pub use accept_invitation::*;
+pub use audit_memberships::*;
 pub use authenticate_with_code::*;
 pub use authenticate_with_email_verification::*;
 pub use authenticate_with_magic_auth::*;
@@ -42,6 +46,7 @@ pub use create_password_reset::*;
 pub use create_user::*;
 pub use delete_user::*;
 pub use enroll_auth_factor::*;
+pub use export_users::*;
 pub use find_invitation_by_token::*;
 pub use get_authorization_url::*;
 pub use get_email_verification::*;
@@ -56,6 +61,7 @@ pub use get_user_by_external_id::*;
 pub use get_user_identities::*;
 pub use list_auth_factors::*;
 pub use list_invitations::*;
+pub use list_organization_memberships::*;
 pub use list_users::*;
 pub use reset_password::*;
 pub use revoke_invitation::*;
@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use thiserror::Error;
 
 use crate::user_management::{Invitation, InvitationId, UserManagement};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{RequestBuilderExt, ResponseExt, WorkOsError, WorkOsResult};
 
 /// An error returned from [`AcceptInvitation`].
 #[derive(Debug, Error)]
@@ -58,7 +58,7 @@ impl AcceptInvitation for UserManagement<'_> {
             .client()
             .post(url)
             .bearer_auth(self.workos.key())
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
@@ -0,0 +1,238 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use futures_util::TryStreamExt;
+
+use crate::organizations::OrganizationId;
+use crate::user_management::{
+    ListOrganizationMemberships, ListOrganizationMembershipsError,
+    ListOrganizationMembershipsParams, OrganizationMembership, OrganizationMembershipStatus,
+    UserId, UserManagement,
+};
+use crate::{KnownOrUnknown, PageCursor, PaginationParams, WorkOsResult, paginate};
+
+/// A membership that is expected to exist, passed to [`AuditMemberships`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExpectedMembership {
+    /// The ID of the user.
+    pub user_id: UserId,
+
+    /// The slug of the role the user is expected to have.
+    pub role_slug: String,
+}
+
+/// A membership whose role differs from the expected one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoleMismatch {
+    /// The membership in WorkOS.
+    pub membership: OrganizationMembership,
+
+    /// The slug of the role the user is expected to have.
+    pub expected_role_slug: String,
+}
+
+/// The differences between the memberships of an organization and the expected memberships.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MembershipAudit {
+    /// The expected memberships that do not exist in WorkOS.
+    pub missing: Vec<ExpectedMembership>,
+
+    /// The memberships in WorkOS that are not expected.
+    pub extra: Vec<OrganizationMembership>,
+
+    /// The memberships in WorkOS whose role differs from the expected one.
+    pub role_mismatches: Vec<RoleMismatch>,
+}
+
+impl MembershipAudit {
+    /// Whether the memberships in WorkOS match the expected memberships.
+    pub fn is_clean(&self) -> bool {
+        self.missing.is_empty() && self.extra.is_empty() && self.role_mismatches.is_empty()
+    }
+}
+
+/// Compares the memberships of an organization against the expected memberships.
+#[async_trait]
+pub trait AuditMemberships {
+    /// Lists every membership of the organization and returns how it differs from the expected
+    /// memberships, without applying any changes.
+    ///
+    /// Inactive memberships are treated as absent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos_sdk::WorkOsResult;
+    /// # use workos_sdk::user_management::*;
+    /// use workos_sdk::{ApiKey, WorkOs};
+    /// use workos_sdk::organizations::OrganizationId;
+    ///
+    /// # async fn run() -> WorkOsResult<(), ListOrganizationMembershipsError> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    ///
+    /// let audit = workos
+    ///     .user_management()
+    ///     .audit_memberships(
+    ///         &OrganizationId::from("org_01E4ZCR3C56J083X43JQXF3JK5"),
+    ///         &[ExpectedMembership {
+    ///             user_id: UserId::from("user_01E4ZCR3C56J083X43JQXF3JK5"),
+    ///             role_slug: "admin".to_string(),
+    ///         }],
+    ///     )
+    ///     .await?;
+    ///
+    /// if !audit.is_clean() {
+    ///     println!("memberships drifted: {audit:?}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn audit_memberships(
+        &self,
+        organization_id: &OrganizationId,
+        expected: &[ExpectedMembership],
+    ) -> WorkOsResult<MembershipAudit, ListOrganizationMembershipsError>;
+}
+
+#[async_trait]
+impl AuditMemberships for UserManagement<'_> {
+    async fn audit_memberships(
+        &self,
+        organization_id: &OrganizationId,
+        expected: &[ExpectedMembership],
+    ) -> WorkOsResult<MembershipAudit, ListOrganizationMembershipsError> {
+        let memberships = paginate(PageCursor::First, |cursor| async move {
+            self.list_organization_memberships(&ListOrganizationMembershipsParams {
+                organization_id: Some(organization_id),
+                user_id: None,
+                pagination: cursor.apply(PaginationParams {
+                    limit: Some(100),
+                    ..Default::default()
+                }),
+            })
+            .await
+        })
+        .try_collect::<Vec<_>>()
+        .await?;
+
+        let mut memberships = memberships
+            .into_iter()
+            .filter(|membership| {
+                membership.status != KnownOrUnknown::Known(OrganizationMembershipStatus::Inactive)
+            })
+            .map(|membership| (membership.user_id.clone(), membership))
+            .collect::<HashMap<_, _>>();
+
+        let mut audit = MembershipAudit::default();
+
+        for expected in expected {
+            match memberships.remove(&expected.user_id) {
+                None => audit.missing.push(expected.clone()),
+                Some(membership) if membership.role.slug != expected.role_slug => {
+                    audit.role_mismatches.push(RoleMismatch {
+                        membership,
+                        expected_role_slug: expected.role_slug.clone(),
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+
+        audit.extra = memberships.into_values().collect();
+        audit.extra.sort_by(|a, b| a.user_id.cmp(&b.user_id));
+
+        Ok(audit)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{Value, json};
+    use tokio;
+
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    fn membership(user_id: &str, role: &str, status: &str) -> Value {
+        json!({
+            "object": "organization_membership",
+            "id": format!("om_{user_id}"),
+            "user_id": user_id,
+            "organization_id": "org_01E4ZCR3C56J083X43JQXF3JK5",
+            "role": { "slug": role },
+            "status": status,
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z"
+        })
+    }
+
+    fn expected(user_id: &str, role_slug: &str) -> ExpectedMembership {
+        ExpectedMembership {
+            user_id: UserId::from(user_id),
+            role_slug: role_slug.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_reports_missing_extra_and_mismatched_memberships() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        server
+            .mock("GET", "/user_management/organization_memberships")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "organization_id".to_string(),
+                "org_01E4ZCR3C56J083X43JQXF3JK5".to_string(),
+            ))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": [
+                        membership("user_admin", "admin", "active"),
+                        membership("user_demoted", "admin", "active"),
+                        membership("user_extra", "member", "pending"),
+                        membership("user_inactive", "member", "inactive")
+                    ],
+                    "list_metadata": { "before": null, "after": null }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let audit = workos
+            .user_management()
+            .audit_memberships(
+                &OrganizationId::from("org_01E4ZCR3C56J083X43JQXF3JK5"),
+                &[
+                    expected("user_admin", "admin"),
+                    expected("user_demoted", "member"),
+                    expected("user_inactive", "member"),
+                ],
+            )
+            .await
+            .unwrap();
+
+        assert!(!audit.is_clean());
+        assert_eq!(audit.missing, vec![expected("user_inactive", "member")]);
+        assert_eq!(
+            audit
+                .extra
+                .iter()
+                .map(|membership| membership.user_id.to_string())
+                .collect::<Vec<_>>(),
+            vec!["user_extra"]
+        );
+        assert_eq!(audit.role_mismatches.len(), 1);
+        assert_eq!(
+            audit.role_mismatches[0].membership.user_id,
+            UserId::from("user_demoted")
+        );
+        assert_eq!(audit.role_mismatches[0].expected_role_slug, "member");
+    }
+}
@@ -7,7 +7,7 @@ use crate::sso::{AuthorizationCode, ClientId};
 use crate::user_management::{
     AuthenticateError, AuthenticationResponse, HandleAuthenticateError, UserManagement,
 };
-use crate::{ApiKey, WorkOsResult};
+use crate::{ApiKey, RequestBuilderExt, WorkOsResult};
 
 /// The parameters for [`AuthenticateWithCode`].
 #[derive(Debug, Serialize)]
@@ -105,7 +105,7 @@ impl AuthenticateWithCode for UserManagement<'_> {
             .client()
             .post(url)
             .json(&body)
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_authenticate_error()
             .await?
@@ -8,7 +8,7 @@ use crate::user_management::{
     AuthenticateError, AuthenticationResponse, EmailVerificationCode, HandleAuthenticateError,
     PendingAuthenticationToken, UserManagement,
 };
-use crate::{ApiKey, WorkOsResult};
+use crate::{ApiKey, RequestBuilderExt, WorkOsResult};
 
 /// The parameters for [`AuthenticateWithEmailVerification`].
 #[derive(Debug, Serialize)]
@@ -102,7 +102,7 @@ impl AuthenticateWithEmailVerification for UserManagement<'_> {
             .client()
             .post(url)
             .json(&body)
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_authenticate_error()
             .await?
@@ -8,7 +8,7 @@ use crate::user_management::{
     AuthenticateError, AuthenticationResponse, HandleAuthenticateError, MagicAuthCode,
     UserManagement,
 };
-use crate::{ApiKey, WorkOsResult};
+use crate::{ApiKey, RequestBuilderExt, WorkOsResult};
 
 /// The parameters for [`AuthenticateWithMagicAuth`].
 #[derive(Debug, Serialize)]
@@ -106,7 +106,7 @@ impl AuthenticateWithMagicAuth for UserManagement<'_> {
             .client()
             .post(url)
             .json(&body)
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_authenticate_error()
             .await?
@@ -9,7 +9,7 @@ use crate::user_management::{
     AuthenticateError, AuthenticationResponse, HandleAuthenticateError, RefreshToken,
     UserManagement,
 };
-use crate::{ApiKey, WorkOsResult};
+use crate::{ApiKey, RequestBuilderExt, WorkOsResult};
 
 /// The parameters for [`AuthenticateWithRefreshToken`].
 #[derive(Debug, Serialize)]
@@ -103,7 +103,7 @@ impl AuthenticateWithRefreshToken for UserManagement<'_> {
             .client()
             .post(url)
             .json(&body)
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_authenticate_error()
             .await?
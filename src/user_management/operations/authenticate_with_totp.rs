@@ -9,7 +9,7 @@ use crate::user_management::{
     AuthenticateError, AuthenticationResponse, HandleAuthenticateError, PendingAuthenticationToken,
     UserManagement,
 };
-use crate::{ApiKey, WorkOsResult};
+use crate::{ApiKey, RequestBuilderExt, WorkOsResult};
 
 /// The parameters for [`AuthenticateWithTotp`].
 #[derive(Debug, Serialize)]
@@ -108,7 +108,7 @@ impl AuthenticateWithTotp for UserManagement<'_> {
             .client()
             .post(url)
             .json(&body)
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_authenticate_error()
             .await?
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::user_management::{PasswordReset, UserManagement};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{RequestBuilderExt, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`CreatePasswordReset`].
 #[derive(Debug, Serialize)]
@@ -111,7 +111,7 @@ impl CreatePasswordReset for UserManagement<'_> {
             .post(url)
             .bearer_auth(self.workos.key())
             .json(&params)
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_error()?
             .handle_create_password_reset_error()
@@ -3,7 +3,7 @@ use serde::Serialize;
 use thiserror::Error;
 
 use crate::user_management::{PasswordParams, User, UserManagement};
-use crate::{Metadata, ResponseExt, WorkOsError, WorkOsResult};
+use crate::{Metadata, RequestBuilderExt, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`CreateUser`].
 #[derive(Debug, Serialize)]
@@ -94,7 +94,7 @@ impl CreateUser for UserManagement<'_> {
             .post(url)
             .bearer_auth(self.workos.key())
             .json(&params)
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
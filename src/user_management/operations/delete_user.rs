@@ -3,7 +3,7 @@ use serde::Serialize;
 use thiserror::Error;
 
 use crate::user_management::{UserId, UserManagement};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{RequestBuilderExt, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`DeleteUser`].
 #[derive(Debug, Serialize)]
@@ -66,7 +66,7 @@ impl DeleteUser for UserManagement<'_> {
             .client()
             .delete(url)
             .bearer_auth(self.workos.key())
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_or_generic_error()
             .await?;
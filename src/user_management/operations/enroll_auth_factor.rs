@@ -5,7 +5,7 @@ use thiserror::Error;
 
 use crate::mfa::{AuthenticationChallenge, AuthenticationFactor};
 use crate::user_management::{UserId, UserManagement};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{RequestBuilderExt, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`EnrollAuthFactor`].
 #[derive(Debug, Serialize)]
@@ -151,7 +151,7 @@ impl EnrollAuthFactor for UserManagement<'_> {
             .post(url)
             .bearer_auth(self.workos.key())
             .json(&params)
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_error()?
             .handle_enroll_auth_factor_error()
@@ -0,0 +1,442 @@
+use std::borrow::Cow;
+use std::io::{self, Write};
+
+use async_trait::async_trait;
+use serde_json::{Map, Value};
+use thiserror::Error;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::organizations::OrganizationId;
+use crate::user_management::{ListUsers, ListUsersError, ListUsersParams, User, UserManagement};
+use crate::{PaginationParams, WorkOsError};
+
+/// The format of an export created by [`ExportUsers`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UserExportFormat {
+    /// Comma-separated values, with a header row.
+    ///
+    /// Values starting with `=`, `+`, `-`, `@`, a tab or a carriage return are prefixed with `'`, so spreadsheet
+    /// applications do not evaluate them as formulas.
+    Csv,
+
+    /// One JSON object per line.
+    JsonLines,
+}
+
+/// A column of an export created by [`ExportUsers`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UserExportColumn {
+    /// The ID of the user.
+    Id,
+
+    /// The email address of the user.
+    Email,
+
+    /// The first name of the user.
+    FirstName,
+
+    /// The last name of the user.
+    LastName,
+
+    /// Whether the email address of the user has been verified.
+    EmailVerified,
+
+    /// The URL of the profile picture of the user.
+    ProfilePictureUrl,
+
+    /// The timestamp when the user last signed in.
+    LastSignInAt,
+
+    /// The external ID of the user.
+    ExternalId,
+
+    /// The metadata of the user.
+    Metadata,
+
+    /// The timestamp when the user was created.
+    CreatedAt,
+
+    /// The timestamp when the user was last updated.
+    UpdatedAt,
+}
+
+impl UserExportColumn {
+    /// All columns, in the order of the fields of a [`User`].
+    pub const ALL: &'static [Self] = &[
+        Self::Id,
+        Self::Email,
+        Self::FirstName,
+        Self::LastName,
+        Self::EmailVerified,
+        Self::ProfilePictureUrl,
+        Self::LastSignInAt,
+        Self::ExternalId,
+        Self::Metadata,
+        Self::CreatedAt,
+        Self::UpdatedAt,
+    ];
+
+    /// Returns the name of the column, matching the field name in the WorkOS API.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Id => "id",
+            Self::Email => "email",
+            Self::FirstName => "first_name",
+            Self::LastName => "last_name",
+            Self::EmailVerified => "email_verified",
+            Self::ProfilePictureUrl => "profile_picture_url",
+            Self::LastSignInAt => "last_sign_in_at",
+            Self::ExternalId => "external_id",
+            Self::Metadata => "metadata",
+            Self::CreatedAt => "created_at",
+            Self::UpdatedAt => "updated_at",
+        }
+    }
+
+    fn value(&self, user: &User) -> Result<Value, serde_json::Error> {
+        match self {
+            Self::Id => serde_json::to_value(&user.id),
+            Self::Email => serde_json::to_value(&user.email),
+            Self::FirstName => serde_json::to_value(&user.first_name),
+            Self::LastName => serde_json::to_value(&user.last_name),
+            Self::EmailVerified => serde_json::to_value(user.email_verified),
+            Self::ProfilePictureUrl => serde_json::to_value(&user.profile_picture_url),
+            Self::LastSignInAt => serde_json::to_value(&user.last_sign_in_at),
+            Self::ExternalId => serde_json::to_value(&user.external_id),
+            Self::Metadata => serde_json::to_value(&user.metadata),
+            Self::CreatedAt => serde_json::to_value(&user.timestamps.created_at),
+            Self::UpdatedAt => serde_json::to_value(&user.timestamps.updated_at),
+        }
+    }
+}
+
+/// Parameters for the [`ExportUsers`] function.
+#[derive(Debug)]
+pub struct ExportUsersParams<'a> {
+    /// The format of the export.
+    pub format: UserExportFormat,
+
+    /// The columns to include in the export, in order.
+    pub columns: &'a [UserExportColumn],
+
+    /// Only export the members of this organization.
+    pub organization_id: Option<&'a OrganizationId>,
+}
+
+/// An error returned from [`ExportUsers`].
+#[derive(Debug, Error)]
+pub enum ExportUsersError {
+    /// A page of users could not be listed.
+    #[error("failed to list users")]
+    ListUsers(#[source] WorkOsError<ListUsersError>),
+
+    /// A user could not be serialized.
+    #[error("failed to serialize user")]
+    Serialize(#[from] serde_json::Error),
+
+    /// The export could not be written.
+    #[error("failed to write export")]
+    Io(#[from] io::Error),
+}
+
+/// Exports every [`User`] to CSV or JSON Lines.
+#[async_trait]
+pub trait ExportUsers {
+    /// Lists every [`User`], following pagination, and writes the selected columns to the writer.
+    ///
+    /// Each page is written as soon as it is retrieved. Returns the number of exported users.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tokio::io::AsyncWrite;
+    /// # use workos_sdk::user_management::*;
+    /// use workos_sdk::{ApiKey, WorkOs};
+    ///
+    /// # async fn run(mut writer: impl AsyncWrite + Unpin + Send) -> Result<(), ExportUsersError> {
+    /// let workos = WorkOs::new(&ApiKey::from("sk_example_123456789"));
+    ///
+    /// let count = workos
+    ///     .user_management()
+    ///     .export_users(
+    ///         &mut writer,
+    ///         &ExportUsersParams {
+    ///             format: UserExportFormat::Csv,
+    ///             columns: &[UserExportColumn::Id, UserExportColumn::Email],
+    ///             organization_id: None,
+    ///         },
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn export_users<W: AsyncWrite + Unpin + Send>(
+        &self,
+        writer: &mut W,
+        params: &ExportUsersParams<'_>,
+    ) -> Result<usize, ExportUsersError>;
+}
+
+#[async_trait]
+impl ExportUsers for UserManagement<'_> {
+    async fn export_users<W: AsyncWrite + Unpin + Send>(
+        &self,
+        writer: &mut W,
+        params: &ExportUsersParams<'_>,
+    ) -> Result<usize, ExportUsersError> {
+        if params.format == UserExportFormat::Csv {
+            let header = params
+                .columns
+                .iter()
+                .map(|column| Cow::Borrowed(column.name()))
+                .collect::<Vec<_>>();
+
+            let mut buffer = Vec::new();
+            write_csv_row(&mut buffer, &header)?;
+            writer.write_all(&buffer).await?;
+        }
+
+        let mut count = 0;
+        let mut after = None;
+
+        loop {
+            let page = self
+                .list_users(&ListUsersParams {
+                    pagination: PaginationParams {
+                        after: after.as_deref(),
+                        limit: Some(100),
+                        ..Default::default()
+                    },
+                    email: None,
+                    organization_id: params.organization_id,
+                })
+                .await
+                .map_err(ExportUsersError::ListUsers)?;
+
+            let mut buffer = Vec::new();
+
+            for user in &page.data {
+                let values = params
+                    .columns
+                    .iter()
+                    .map(|column| column.value(user))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                match params.format {
+                    UserExportFormat::Csv => {
+                        let values = values.iter().map(csv_value).collect::<Vec<_>>();
+                        write_csv_row(&mut buffer, &values)?;
+                    }
+                    UserExportFormat::JsonLines => {
+                        let object = params
+                            .columns
+                            .iter()
+                            .map(|column| column.name().to_string())
+                            .zip(values)
+                            .collect::<Map<_, _>>();
+                        serde_json::to_writer(&mut buffer, &object)?;
+                        buffer.push(b'\n');
+                    }
+                }
+            }
+
+            writer.write_all(&buffer).await?;
+
+            count += page.data.len();
+
+            match page.metadata.after {
+                Some(cursor) => after = Some(cursor),
+                None => break,
+            }
+        }
+
+        writer.flush().await?;
+
+        Ok(count)
+    }
+}
+
+/// Returns the CSV representation of a value, leaving missing values empty and escaping formulas.
+fn csv_value(value: &Value) -> Cow<'_, str> {
+    let value = match value {
+        Value::Null => Cow::Borrowed(""),
+        Value::String(value) => Cow::Borrowed(value.as_str()),
+        value => Cow::Owned(value.to_string()),
+    };
+
+    if value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        Cow::Owned(format!("'{value}"))
+    } else {
+        value
+    }
+}
+
+fn write_csv_row<W: Write>(writer: &mut W, values: &[Cow<'_, str>]) -> io::Result<()> {
+    for (index, value) in values.iter().enumerate() {
+        if index > 0 {
+            writer.write_all(b",")?;
+        }
+
+        if value.contains([',', '"', '\n', '\r']) {
+            write!(writer, "\"{}\"", value.replace('"', "\"\""))?;
+        } else {
+            writer.write_all(value.as_bytes())?;
+        }
+    }
+
+    writer.write_all(b"\r\n")
+}
+
+#[cfg(test)]
+mod test {
+    use mockito::Matcher;
+    use serde_json::json;
+    use tokio;
+
+    use crate::{ApiKey, WorkOs};
+
+    use super::*;
+
+    fn user(id: &str, email: &str, first_name: Option<&str>) -> Value {
+        json!({
+            "object": "user",
+            "id": id,
+            "email": email,
+            "first_name": first_name,
+            "last_name": null,
+            "email_verified": true,
+            "profile_picture_url": null,
+            "last_sign_in_at": null,
+            "external_id": null,
+            "metadata": null,
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z"
+        })
+    }
+
+    async fn mock_users(server: &mut mockito::Server) {
+        server
+            .mock("GET", "/user_management/users")
+            .match_query(Matcher::UrlEncoded("limit".to_string(), "100".to_string()))
+            .expect(1)
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": [user("user_01E4ZCR3C56J083X43JQXF3JK5", "marcelina@example.com", Some("Marcelina, Jr."))],
+                    "list_metadata": { "before": null, "after": "user_01E4ZCR3C56J083X43JQXF3JK5" }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/user_management/users")
+            .match_query(Matcher::UrlEncoded(
+                "after".to_string(),
+                "user_01E4ZCR3C56J083X43JQXF3JK5".to_string(),
+            ))
+            .expect(1)
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": [user("user_01E4ZCR3C56J083X43JQXF3JK6", "bob@example.com", None)],
+                    "list_metadata": { "before": "user_01E4ZCR3C56J083X43JQXF3JK6", "after": null }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+    }
+
+    #[tokio::test]
+    async fn it_exports_every_user_to_csv() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        mock_users(&mut server).await;
+
+        let mut export = Vec::new();
+        let count = workos
+            .user_management()
+            .export_users(
+                &mut export,
+                &ExportUsersParams {
+                    format: UserExportFormat::Csv,
+                    columns: &[
+                        UserExportColumn::Id,
+                        UserExportColumn::Email,
+                        UserExportColumn::FirstName,
+                        UserExportColumn::EmailVerified,
+                    ],
+                    organization_id: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(count, 2);
+        assert_eq!(
+            String::from_utf8(export).unwrap(),
+            "id,email,first_name,email_verified\r\n\
+             user_01E4ZCR3C56J083X43JQXF3JK5,marcelina@example.com,\"Marcelina, Jr.\",true\r\n\
+             user_01E4ZCR3C56J083X43JQXF3JK6,bob@example.com,,true\r\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn it_exports_every_user_to_json_lines() {
+        let mut server = mockito::Server::new_async().await;
+
+        let workos = WorkOs::builder(&ApiKey::from("sk_example_123456789"))
+            .base_url(&server.url())
+            .unwrap()
+            .build();
+
+        mock_users(&mut server).await;
+
+        let mut export = Vec::new();
+        workos
+            .user_management()
+            .export_users(
+                &mut export,
+                &ExportUsersParams {
+                    format: UserExportFormat::JsonLines,
+                    columns: &[UserExportColumn::Id, UserExportColumn::FirstName],
+                    organization_id: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        let lines = String::from_utf8(export)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str::<Value>(line).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            lines,
+            vec![
+                json!({ "id": "user_01E4ZCR3C56J083X43JQXF3JK5", "first_name": "Marcelina, Jr." }),
+                json!({ "id": "user_01E4ZCR3C56J083X43JQXF3JK6", "first_name": null }),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_escapes_formulas_in_csv_values() {
+        assert_eq!(
+            csv_value(&json!("=HYPERLINK(\"https://evil.example.com\")")),
+            "'=HYPERLINK(\"https://evil.example.com\")"
+        );
+        assert_eq!(csv_value(&json!("+1")), "'+1");
+        assert_eq!(csv_value(&json!("-1")), "'-1");
+        assert_eq!(csv_value(&json!("@SUM(A1)")), "'@SUM(A1)");
+        assert_eq!(csv_value(&json!("Marcelina")), "Marcelina");
+        assert_eq!(csv_value(&json!(null)), "");
+    }
+}
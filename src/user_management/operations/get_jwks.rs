@@ -4,7 +4,7 @@ use thiserror::Error;
 
 use crate::sso::ClientId;
 use crate::user_management::UserManagement;
-use crate::{ResponseExt, WorkOsResult};
+use crate::{RequestBuilderExt, ResponseExt, WorkOsResult};
 
 use super::GetJwksUrl;
 
@@ -49,7 +49,7 @@ impl GetJwks for UserManagement<'_> {
             .workos
             .client()
             .get(url)
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
@@ -126,6 +126,6 @@ mod test {
             .get_jwks(&ClientId::from("client_123456789"))
             .await;
 
-        assert_matches!(result, Err(WorkOsError::RequestError(_)))
+        assert_matches!(result, Err(WorkOsError::NotFound { body: None }))
     }
 }
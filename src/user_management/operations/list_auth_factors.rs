@@ -4,7 +4,9 @@ use thiserror::Error;
 
 use crate::mfa::AuthenticationFactor;
 use crate::user_management::{UserId, UserManagement};
-use crate::{PaginatedList, PaginationParams, ResponseExt, WorkOsError, WorkOsResult};
+use crate::{
+    PaginatedList, PaginationParams, RequestBuilderExt, ResponseExt, WorkOsError, WorkOsResult,
+};
 
 /// Parameters for the [`ListAuthFactors`] function.
 #[derive(Debug, Serialize)]
@@ -77,8 +79,9 @@ impl ListAuthFactors for UserManagement<'_> {
             .client()
             .get(url)
             .query(&params)
+            .default_limit(self.workos, &params.pagination)
             .bearer_auth(self.workos.key())
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
@@ -4,7 +4,9 @@ use thiserror::Error;
 
 use crate::organizations::OrganizationId;
 use crate::user_management::{User, UserManagement};
-use crate::{PaginatedList, PaginationParams, ResponseExt, WorkOsError, WorkOsResult};
+use crate::{
+    PaginatedList, PaginationParams, RequestBuilderExt, ResponseExt, WorkOsError, WorkOsResult,
+};
 
 /// Parameters for the [`ListUsers`] function.
 #[derive(Debug, Default, Serialize)]
@@ -75,8 +77,9 @@ impl ListUsers for UserManagement<'_> {
             .client()
             .get(url)
             .query(&params)
+            .default_limit(self.workos, &params.pagination)
             .bearer_auth(self.workos.key())
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
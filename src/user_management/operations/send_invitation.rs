@@ -4,7 +4,7 @@ use thiserror::Error;
 
 use crate::organizations::OrganizationId;
 use crate::user_management::{Invitation, UserId, UserManagement};
-use crate::{ResponseExt, WorkOsError, WorkOsResult};
+use crate::{RequestBuilderExt, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`SendInvitation`].
 #[derive(Debug, Serialize)]
@@ -89,7 +89,7 @@ impl SendInvitation for UserManagement<'_> {
             .post(url)
             .bearer_auth(self.workos.key())
             .json(&params)
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
@@ -3,7 +3,7 @@ use serde::Serialize;
 use thiserror::Error;
 
 use crate::user_management::{PasswordParams, User, UserId, UserManagement};
-use crate::{Metadata, ResponseExt, WorkOsError, WorkOsResult};
+use crate::{Metadata, RequestBuilderExt, ResponseExt, WorkOsError, WorkOsResult};
 
 /// The parameters for [`UpdateUser`].
 #[derive(Debug, Serialize)]
@@ -105,7 +105,7 @@ impl UpdateUser for UserManagement<'_> {
             .put(url)
             .bearer_auth(self.workos.key())
             .json(&params)
-            .send()
+            .send_with(self.workos)
             .await?
             .handle_unauthorized_or_generic_error()
             .await?
@@ -1,11 +1,13 @@
+use std::fmt::{self, Display};
+
 use async_trait::async_trait;
 use reqwest::{Response, StatusCode};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::{
-    WorkOsError, WorkOsResult, mfa::AuthenticationFactorIdAndType,
-    organizations::OrganizationIdAndName, sso::ConnectionId,
+    KnownOrUnknown, WorkOsError, WorkOsResult, mfa::AuthenticationFactorIdAndType,
+    organizations::OrganizationIdAndName, sso::ConnectionId, wire_format,
 };
 
 use super::{AuthenticateMethods, EmailVerificationId, PendingAuthenticationToken, User};
@@ -22,6 +24,130 @@ pub enum AuthenticateError {
     WithError(AuthenticateErrorWithError),
 }
 
+impl AuthenticateError {
+    /// The string constant that distinguishes the error type, taken from the `code` or `error` field.
+    pub fn code(&self) -> &str {
+        match self {
+            AuthenticateError::WithCode(error) => error.code(),
+            AuthenticateError::WithError(error) => error.error(),
+        }
+    }
+
+    /// The human-readable message describing the error.
+    pub fn message(&self) -> &str {
+        match self {
+            AuthenticateError::WithCode(error) => error.message(),
+            AuthenticateError::WithError(error) => error.error_description(),
+        }
+    }
+
+    /// The [`AuthenticateErrorCode`] of the error, or the raw code if it is not documented.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos_sdk::KnownOrUnknown;
+    /// # use workos_sdk::user_management::*;
+    /// fn should_retry(error: &AuthenticateError) -> bool {
+    ///     matches!(
+    ///         error.code_enum(),
+    ///         KnownOrUnknown::Known(
+    ///             AuthenticateErrorCode::InvalidOneTimeCode
+    ///                 | AuthenticateErrorCode::OneTimeCodeExpired
+    ///         )
+    ///     )
+    /// }
+    /// ```
+    pub fn code_enum(&self) -> KnownOrUnknown<AuthenticateErrorCode, &str> {
+        let code = self.code();
+
+        match code.parse() {
+            Ok(code) => KnownOrUnknown::Known(code),
+            Err(_) => KnownOrUnknown::Unknown(code),
+        }
+    }
+}
+
+/// A documented error code of an [`AuthenticateError`].
+///
+/// [WorkOS Docs: Authentication errors](https://workos.com/docs/reference/user-management/authentication-errors)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthenticateErrorCode {
+    /// `email_not_verified`
+    EmailNotVerified,
+
+    /// `email_verification_required`
+    EmailVerificationRequired,
+
+    /// `invalid_client`
+    InvalidClient,
+
+    /// `invalid_credentials`
+    InvalidCredentials,
+
+    /// `invalid_grant`
+    InvalidGrant,
+
+    /// `invalid_one_time_code`
+    InvalidOneTimeCode,
+
+    /// `invalid_pending_authentication_token`
+    InvalidPendingAuthenticationToken,
+
+    /// `mfa_challenge`
+    MfaChallenge,
+
+    /// `mfa_enrollment`
+    MfaEnrollment,
+
+    /// `one_time_code_expired`
+    OneTimeCodeExpired,
+
+    /// `one_time_code_too_many_attempts`
+    OneTimeCodeTooManyAttempts,
+
+    /// `organization_authentication_methods_required`
+    OrganizationAuthenticationMethodsRequired,
+
+    /// `organization_selection_required`
+    OrganizationSelectionRequired,
+
+    /// `sso_required`
+    SsoRequired,
+
+    /// `unauthorized_client`
+    UnauthorizedClient,
+
+    /// `user_not_found`
+    UserNotFound,
+}
+
+wire_format!(AuthenticateErrorCode {
+    EmailNotVerified => "email_not_verified",
+    EmailVerificationRequired => "email_verification_required",
+    InvalidClient => "invalid_client",
+    InvalidCredentials => "invalid_credentials",
+    InvalidGrant => "invalid_grant",
+    InvalidOneTimeCode => "invalid_one_time_code",
+    InvalidPendingAuthenticationToken => "invalid_pending_authentication_token",
+    MfaChallenge => "mfa_challenge",
+    MfaEnrollment => "mfa_enrollment",
+    OneTimeCodeExpired => "one_time_code_expired",
+    OneTimeCodeTooManyAttempts => "one_time_code_too_many_attempts",
+    OrganizationAuthenticationMethodsRequired => "organization_authentication_methods_required",
+    OrganizationSelectionRequired => "organization_selection_required",
+    SsoRequired => "sso_required",
+    UnauthorizedClient => "unauthorized_client",
+    UserNotFound => "user_not_found",
+});
+
+impl Display for AuthenticateErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// An error returned from authenticate requests tagged with a `code` field.
 #[derive(Debug, Deserialize, Error)]
 #[serde(tag = "code", rename_all = "snake_case")]
@@ -151,21 +277,31 @@ impl AuthenticateErrorWithCode {
     pub fn code(&self) -> &str {
         match self {
             AuthenticateErrorWithCode::EmailVerificationRequired { .. } => {
-                "email_verification_requried"
+                AuthenticateErrorCode::EmailVerificationRequired.as_str()
+            }
+            AuthenticateErrorWithCode::InvalidCredentials { .. } => {
+                AuthenticateErrorCode::InvalidCredentials.as_str()
+            }
+            AuthenticateErrorWithCode::InvalidOneTimeCode { .. } => {
+                AuthenticateErrorCode::InvalidOneTimeCode.as_str()
             }
-            AuthenticateErrorWithCode::InvalidCredentials { .. } => "invalid_credentials",
-            AuthenticateErrorWithCode::InvalidOneTimeCode { .. } => "invalid_one_time_code",
             AuthenticateErrorWithCode::InvalidPendingAuthenticationToken { .. } => {
-                "invalid_pending_authentication_token"
+                AuthenticateErrorCode::InvalidPendingAuthenticationToken.as_str()
+            }
+            AuthenticateErrorWithCode::MfaEnrollment { .. } => {
+                AuthenticateErrorCode::MfaEnrollment.as_str()
+            }
+            AuthenticateErrorWithCode::MfaChallenge { .. } => {
+                AuthenticateErrorCode::MfaChallenge.as_str()
+            }
+            AuthenticateErrorWithCode::OneTimeCodeExpired { .. } => {
+                AuthenticateErrorCode::OneTimeCodeExpired.as_str()
             }
-            AuthenticateErrorWithCode::MfaEnrollment { .. } => "mfa_enrollment",
-            AuthenticateErrorWithCode::MfaChallenge { .. } => "mfa_challenge",
-            AuthenticateErrorWithCode::OneTimeCodeExpired { .. } => "one_time_code_expired",
             AuthenticateErrorWithCode::OneTimeCodeTooManyAttempts { .. } => {
-                "one_time_code_too_many_attempts"
+                AuthenticateErrorCode::OneTimeCodeTooManyAttempts.as_str()
             }
             AuthenticateErrorWithCode::OrganizationSelectionRequired { .. } => {
-                "organization_selection_required"
+                AuthenticateErrorCode::OrganizationSelectionRequired.as_str()
             }
             AuthenticateErrorWithCode::Other { code, .. } => code,
         }
@@ -293,8 +429,11 @@ impl HandleAuthenticateError for Response {
                         AuthenticateError::WithError(AuthenticateErrorWithError::Other {
                             error,
                             ..
-                        }) => match error.as_str() {
-                            "invalid_client" | "unauthorized_client" => WorkOsError::Unauthorized,
+                        }) => match error.parse() {
+                            Ok(
+                                AuthenticateErrorCode::InvalidClient
+                                | AuthenticateErrorCode::UnauthorizedClient,
+                            ) => WorkOsError::Unauthorized,
                             _ => WorkOsError::Operation(authenticate_error),
                         },
                         _ => WorkOsError::Operation(authenticate_error),
@@ -310,3 +449,63 @@ impl HandleAuthenticateError for Response {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn it_returns_the_code_enum_of_errors_with_a_code() {
+        let error: AuthenticateError = serde_json::from_value(json!({
+            "code": "invalid_credentials",
+            "message": "Invalid credentials."
+        }))
+        .unwrap();
+
+        assert_eq!(
+            error.code_enum(),
+            KnownOrUnknown::Known(AuthenticateErrorCode::InvalidCredentials)
+        );
+        assert_eq!(error.message(), "Invalid credentials.");
+    }
+
+    #[test]
+    fn it_returns_the_code_enum_of_errors_with_an_error() {
+        let error: AuthenticateError = serde_json::from_value(json!({
+            "error": "invalid_grant",
+            "error_description": "The code has expired."
+        }))
+        .unwrap();
+
+        assert_eq!(
+            error.code_enum(),
+            KnownOrUnknown::Known(AuthenticateErrorCode::InvalidGrant)
+        );
+        assert_eq!(error.message(), "The code has expired.");
+    }
+
+    #[test]
+    fn it_returns_unknown_codes_as_is() {
+        let error: AuthenticateError = serde_json::from_value(json!({
+            "code": "something_new",
+            "message": "Something new happened."
+        }))
+        .unwrap();
+
+        assert_eq!(error.code_enum(), KnownOrUnknown::Unknown("something_new"));
+    }
+
+    #[test]
+    fn it_round_trips_every_code() {
+        for code in AuthenticateErrorCode::ALL {
+            assert_eq!(
+                code.as_str().parse::<AuthenticateErrorCode>().unwrap(),
+                *code
+            );
+            assert_eq!(code.to_string(), code.as_str());
+            assert_eq!(serde_json::to_value(code).unwrap(), json!(code.as_str()));
+        }
+    }
+}
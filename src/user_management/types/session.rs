@@ -5,11 +5,12 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     KnownOrUnknown, Timestamp, Timestamps, organizations::OrganizationId, user_management::UserId,
+    wire_format,
 };
 
 /// The ID of a [`Session`].
 #[derive(
-    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
 )]
 #[from(forward)]
 pub struct SessionId(String);
@@ -36,6 +37,7 @@ pub enum SessionAuthMethod {
     ExternalAuth,
 
     /// The session was authenticated using impersenation.
+    #[serde(rename = "impersonation")]
     Impersenation,
 
     /// The session was authenticated using a magic code.
@@ -54,12 +56,25 @@ pub enum SessionAuthMethod {
     Password,
 
     /// The session was authenticated using SSO.
+    #[serde(rename = "sso")]
     SSO,
 
     /// The session was authenticated using an unknown method.
     Unknown,
 }
 
+wire_format!(SessionAuthMethod {
+    ExternalAuth => "external_auth",
+    Impersenation => "impersonation",
+    MagicCode => "magic_code",
+    MigratedSession => "migrated_session",
+    Oauth => "oauth",
+    Passkey => "passkey",
+    Password => "password",
+    SSO => "sso",
+    Unknown => "unknown",
+});
+
 /// [WorkOS Docs: Session](https://workos.com/docs/reference/user-management/session)
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Session {
@@ -94,3 +109,34 @@ pub struct Session {
     #[serde(flatten)]
     pub timestamps: Timestamps,
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn it_round_trips_the_wire_format_of_all_auth_methods() {
+        for value in SessionAuthMethod::ALL {
+            assert_eq!(serde_json::to_value(value).unwrap(), json!(value.as_str()));
+            assert_eq!(
+                serde_json::from_value::<SessionAuthMethod>(json!(value.as_str())).unwrap(),
+                *value
+            );
+            assert_eq!(value.as_str().parse::<SessionAuthMethod>(), Ok(*value));
+        }
+    }
+
+    #[test]
+    fn it_deserializes_the_sso_and_impersonation_auth_methods() {
+        assert_eq!(
+            serde_json::from_value::<SessionAuthMethod>(json!("sso")).unwrap(),
+            SessionAuthMethod::SSO
+        );
+        assert_eq!(
+            serde_json::from_value::<SessionAuthMethod>(json!("impersonation")).unwrap(),
+            SessionAuthMethod::Impersenation
+        );
+    }
+}
@@ -6,7 +6,7 @@ use crate::{Metadata, Timestamp, Timestamps};
 
 /// The ID of a [`User`].
 #[derive(
-    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
 )]
 #[from(forward)]
 pub struct UserId(String);
@@ -0,0 +1,25 @@
+//! A module for interacting with the WorkOS Vault API.
+//!
+//! [WorkOS Docs: Vault](https://workos.com/docs/vault)
+
+mod operations;
+mod types;
+
+pub use operations::*;
+pub use types::*;
+
+use crate::WorkOs;
+
+/// Vault.
+///
+/// [WorkOS Docs: Vault](https://workos.com/docs/vault)
+pub struct Vault<'a> {
+    workos: &'a WorkOs,
+}
+
+impl<'a> Vault<'a> {
+    /// Returns a new [`Vault`] instance for the provided WorkOS client.
+    pub fn new(workos: &'a WorkOs) -> Self {
+        Self { workos }
+    }
+}
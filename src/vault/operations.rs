@@ -0,0 +1,5 @@
+mod create_data_key;
+mod decrypt_data_key;
+
+pub use create_data_key::*;
+pub use decrypt_data_key::*;
@@ -0,0 +1,5 @@
+mod data_key;
+mod key_context;
+
+pub use data_key::*;
+pub use key_context::*;
@@ -0,0 +1,89 @@
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use derive_more::{Deref, Display, From};
+use serde::{Deserialize, Serialize};
+
+use crate::vault::KeyContext;
+
+/// The ID of a [`DataKey`].
+#[derive(
+    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[from(forward)]
+pub struct DataKeyId(String);
+
+/// A base64-encoded data key that may be used to encrypt data locally.
+///
+/// The data key should only be held in memory for as long as it is needed and never be persisted.
+/// Persist the corresponding [`EncryptedKey`] instead.
+#[derive(
+    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[from(forward)]
+pub struct DataKey(String);
+
+impl DataKey {
+    /// Decodes the data key into its raw bytes, e.g. to use it as an AES-256 key.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, base64::DecodeError> {
+        BASE64_STANDARD.decode(&self.0)
+    }
+}
+
+/// A [`DataKey`] encrypted by WorkOS Vault.
+///
+/// The encrypted key is safe to store alongside the data it protects
+/// and may be exchanged for the original data key using [`DecryptDataKey`](crate::vault::DecryptDataKey).
+#[derive(
+    Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[from(forward)]
+pub struct EncryptedKey(String);
+
+/// A newly created [`DataKey`] along with its encrypted form.
+///
+/// [WorkOS Docs: Create data key](https://workos.com/docs/reference/vault/key/create-data-key)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DataKeyPair {
+    /// The ID of the data key.
+    pub id: DataKeyId,
+
+    /// The context used to select the key encryption key.
+    pub context: KeyContext,
+
+    /// The plaintext data key.
+    pub data_key: DataKey,
+
+    /// The encrypted data key.
+    pub encrypted_keys: EncryptedKey,
+}
+
+/// A [`DataKey`] recovered from an [`EncryptedKey`].
+///
+/// [WorkOS Docs: Decrypt data key](https://workos.com/docs/reference/vault/key/decrypt-data-key)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecryptedDataKey {
+    /// The ID of the data key.
+    pub id: DataKeyId,
+
+    /// The plaintext data key.
+    pub data_key: DataKey,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_decodes_a_data_key_into_bytes() {
+        let data_key = DataKey::from("AAECAwQFBgcICQoLDA0ODw==");
+
+        assert_eq!(data_key.to_bytes().unwrap(), (0..16).collect::<Vec<u8>>())
+    }
+
+    #[test]
+    fn it_returns_an_error_for_an_invalid_data_key() {
+        let data_key = DataKey::from("not base64!");
+
+        assert!(data_key.to_bytes().is_err())
+    }
+}
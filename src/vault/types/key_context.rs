@@ -0,0 +1,10 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// The key/value pairs used to select the encryption key for a [`DataKey`](crate::vault::DataKey).
+///
+/// Data keys created with the same context are protected by the same key encryption key,
+/// e.g. a context of `{ "organization_id": "org_01EHZNVPK3SFK441A1RGBFSHRT" }` isolates the keys of a single tenant.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyContext(pub HashMap<String, String>);
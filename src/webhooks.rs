@@ -0,0 +1,24 @@
+//! A module for verifying webhooks sent by WorkOS.
+//!
+//! [WorkOS Docs: Webhooks](https://workos.com/docs/events/data-syncing/webhooks)
+
+mod replay_store;
+mod signed_webhook;
+mod types;
+mod webhook;
+mod webhook_router;
+mod webhook_sink;
+mod webhook_verifier;
+mod workos_webhook;
+
+#[cfg(test)]
+pub(crate) mod testing;
+
+pub use replay_store::*;
+pub use signed_webhook::*;
+pub use types::*;
+pub use webhook::*;
+pub use webhook_router::*;
+pub use webhook_sink::*;
+pub use webhook_verifier::*;
+pub use workos_webhook::*;
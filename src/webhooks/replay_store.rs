@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+
+/// An error returned from a [`ReplayStore`].
+pub type ReplayStoreError = Box<dyn Error + Send + Sync>;
+
+/// A store that remembers which webhook deliveries have already been seen.
+///
+/// Implement this trait to share replay protection across multiple instances, e.g. using Redis.
+#[async_trait]
+pub trait ReplayStore: Send + Sync {
+    /// Records the key for the provided duration.
+    ///
+    /// Returns `false` if the key has already been recorded and has not expired yet.
+    async fn insert(&self, key: &str, ttl: Duration) -> Result<bool, ReplayStoreError>;
+}
+
+/// An in-memory [`ReplayStore`].
+///
+/// Keys are only remembered by the current process, so this store is not suitable for deployments
+/// with multiple instances behind a load balancer.
+#[derive(Debug, Default)]
+pub struct InMemoryReplayStore {
+    keys: Mutex<HashMap<String, Instant>>,
+}
+
+impl InMemoryReplayStore {
+    /// Returns a new, empty [`InMemoryReplayStore`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ReplayStore for InMemoryReplayStore {
+    async fn insert(&self, key: &str, ttl: Duration) -> Result<bool, ReplayStoreError> {
+        let now = Instant::now();
+        let mut keys = self.keys.lock().map_err(|err| err.to_string())?;
+
+        keys.retain(|_, expires_at| *expires_at > now);
+
+        if keys.contains_key(key) {
+            return Ok(false);
+        }
+
+        keys.insert(key.to_owned(), now + ttl);
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[tokio::test]
+    async fn it_rejects_a_key_that_has_already_been_seen() {
+        let store = InMemoryReplayStore::new();
+
+        assert!(store.insert("key", Duration::from_secs(60)).await.unwrap());
+        assert!(!store.insert("key", Duration::from_secs(60)).await.unwrap());
+        assert!(
+            store
+                .insert("another_key", Duration::from_secs(60))
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn it_accepts_a_key_again_after_it_expired() {
+        let store = InMemoryReplayStore::new();
+
+        assert!(store.insert("key", Duration::ZERO).await.unwrap());
+        assert!(store.insert("key", Duration::ZERO).await.unwrap());
+    }
+}
@@ -0,0 +1,114 @@
+use chrono::Utc;
+
+use crate::Timestamp;
+use crate::events::Event;
+use crate::webhooks::{WebhookSecret, WebhookSignature};
+
+/// A webhook body along with a valid `WorkOS-Signature` header.
+///
+/// This is intended for exercising webhook endpoints in tests without sending real events from WorkOS.
+///
+/// # Examples
+///
+/// ```
+/// # use workos_sdk::webhooks::*;
+/// let secret = WebhookSecret::from("whsec_123456789");
+///
+/// let SignedWebhook {
+///     signature_header,
+///     body,
+/// } = SignedWebhook::from_json(
+///     r#"{"id":"event_01H2GNQD5D7ZE06FDDS75NFPHY","event":"user.deleted","data":{}}"#,
+///     &secret,
+/// );
+///
+/// // Send a request with the `WorkOS-Signature` header set to `signature_header`.
+/// assert!(Webhook::verify(&body, &signature_header, &secret, Webhook::DEFAULT_TOLERANCE).is_ok());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignedWebhook {
+    /// The value of the `WorkOS-Signature` header.
+    pub signature_header: String,
+
+    /// The raw request body.
+    pub body: String,
+}
+
+impl SignedWebhook {
+    /// Serializes the event and signs it with the current timestamp.
+    pub fn from_event(event: &Event, secret: &WebhookSecret) -> Result<Self, serde_json::Error> {
+        Ok(Self::from_json(serde_json::to_string(event)?, secret))
+    }
+
+    /// Signs the raw JSON body with the current timestamp.
+    pub fn from_json(body: impl Into<String>, secret: &WebhookSecret) -> Self {
+        Self::from_json_at(body, secret, &Timestamp(Utc::now().fixed_offset()))
+    }
+
+    /// Signs the raw JSON body with the provided timestamp.
+    ///
+    /// This can be used to test the rejection of webhooks outside of the tolerance zone.
+    pub fn from_json_at(
+        body: impl Into<String>,
+        secret: &WebhookSecret,
+        timestamp: &Timestamp,
+    ) -> Self {
+        let body = body.into();
+        let signature = WebhookSignature::compute(timestamp, &body, secret);
+
+        Self {
+            signature_header: signature.to_string(),
+            body,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::TimeDelta;
+    use matches::assert_matches;
+
+    use crate::webhooks::{VerifyWebhookError, Webhook};
+
+    use crate::webhooks::testing::event;
+
+    use super::*;
+
+    #[test]
+    fn it_signs_an_event_that_can_be_verified() {
+        let secret = WebhookSecret::from("whsec_123456789");
+
+        let signed_webhook = SignedWebhook::from_event(&event(), &secret).unwrap();
+
+        let verified_event = Webhook::construct_event(
+            &signed_webhook.body,
+            &signed_webhook.signature_header,
+            &secret,
+            Webhook::DEFAULT_TOLERANCE,
+        )
+        .unwrap();
+
+        assert_eq!(verified_event, event())
+    }
+
+    #[test]
+    fn it_signs_a_payload_with_the_provided_timestamp() {
+        let secret = WebhookSecret::from("whsec_123456789");
+
+        let signed_webhook = SignedWebhook::from_json_at(
+            "{}",
+            &secret,
+            &Timestamp((Utc::now() - TimeDelta::hours(1)).fixed_offset()),
+        );
+
+        assert_matches!(
+            Webhook::verify(
+                &signed_webhook.body,
+                &signed_webhook.signature_header,
+                &secret,
+                Webhook::DEFAULT_TOLERANCE,
+            ),
+            Err(VerifyWebhookError::TimestampOutsideTolerance)
+        )
+    }
+}
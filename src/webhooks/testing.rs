@@ -0,0 +1,36 @@
+//! Fixtures for testing webhooks.
+
+use serde_json::{Value, json};
+
+use crate::events::Event;
+
+/// The ID of the event returned by [`event`] and [`payload`].
+pub(crate) const EVENT_ID: &str = "event_01H2GNQD5D7ZE06FDDS75NFPHY";
+
+/// Returns the JSON of an event with the provided ID and name, carrying the data of a deleted organization.
+pub(crate) fn event_json(id: &str, name: &str) -> Value {
+    json!({
+        "id": id,
+        "event": name,
+        "data": {
+            "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+            "object": "organization",
+            "name": "Foo Corp",
+            "allow_profiles_outside_organization": false,
+            "domains": [],
+            "created_at": "2021-06-25T19:07:33.155Z",
+            "updated_at": "2021-06-25T19:07:33.155Z"
+        },
+        "created_at": "2023-06-09T18:12:01.837Z"
+    })
+}
+
+/// Returns the raw body of an `organization.deleted` webhook.
+pub(crate) fn payload() -> String {
+    event_json(EVENT_ID, "organization.deleted").to_string()
+}
+
+/// Returns an `organization.deleted` event.
+pub(crate) fn event() -> Event {
+    serde_json::from_value(event_json(EVENT_ID, "organization.deleted")).unwrap()
+}
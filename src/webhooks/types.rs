@@ -0,0 +1,5 @@
+mod webhook_secret;
+mod webhook_signature;
+
+pub use webhook_secret::*;
+pub use webhook_signature::*;
@@ -0,0 +1,8 @@
+use derive_more::{Deref, Display, From};
+
+/// A secret used to verify the signature of webhooks sent by WorkOS.
+///
+/// The secret can be found in the webhook settings of the WorkOS Dashboard.
+#[derive(Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord)]
+#[from(forward)]
+pub struct WebhookSecret(String);
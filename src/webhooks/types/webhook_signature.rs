@@ -0,0 +1,171 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use chrono::DateTime;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use thiserror::Error;
+
+use crate::Timestamp;
+use crate::webhooks::WebhookSecret;
+
+/// An error returned when parsing a [`WebhookSignature`].
+#[derive(Debug, Error)]
+pub enum ParseWebhookSignatureError {
+    /// The header does not contain a `t` timestamp.
+    #[error("missing timestamp")]
+    MissingTimestamp,
+
+    /// The `t` timestamp is not a valid number of milliseconds since the Unix epoch.
+    #[error("invalid timestamp")]
+    InvalidTimestamp,
+
+    /// The header does not contain a `v1` signature.
+    #[error("missing signature")]
+    MissingSignature,
+
+    /// The `v1` signature is not valid hex.
+    #[error("invalid signature")]
+    InvalidSignature(#[from] hex::FromHexError),
+}
+
+/// The contents of the `WorkOS-Signature` header sent with a webhook.
+///
+/// The header has the form `t=<timestamp>, v1=<signature>`, where the timestamp is the number of milliseconds
+/// since the Unix epoch and the signature is the hex-encoded HMAC-SHA256 of `<timestamp>.<payload>`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WebhookSignature {
+    /// The timestamp at which the webhook was signed.
+    pub timestamp: Timestamp,
+
+    /// The HMAC-SHA256 signature of the webhook.
+    pub signature: Vec<u8>,
+}
+
+impl WebhookSignature {
+    /// Computes the signature of the payload at the given timestamp using the provided secret.
+    pub fn compute(timestamp: &Timestamp, payload: &str, secret: &WebhookSecret) -> Self {
+        Self {
+            timestamp: timestamp.clone(),
+            signature: Self::mac(timestamp, payload, secret)
+                .finalize()
+                .into_bytes()
+                .to_vec(),
+        }
+    }
+
+    /// Checks whether the signature matches the payload using a constant-time comparison.
+    pub(crate) fn matches(&self, payload: &str, secret: &WebhookSecret) -> bool {
+        Self::mac(&self.timestamp, payload, secret)
+            .verify_slice(&self.signature)
+            .is_ok()
+    }
+
+    fn mac(timestamp: &Timestamp, payload: &str, secret: &WebhookSecret) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+            .expect("HMAC can take a key of any size");
+        mac.update(timestamp.0.timestamp_millis().to_string().as_bytes());
+        mac.update(b".");
+        mac.update(payload.as_bytes());
+        mac
+    }
+}
+
+impl FromStr for WebhookSignature {
+    type Err = ParseWebhookSignatureError;
+
+    fn from_str(header: &str) -> Result<Self, Self::Err> {
+        let mut timestamp = None;
+        let mut signature = None;
+
+        for (key, value) in header
+            .split(',')
+            .filter_map(|part| part.trim().split_once('='))
+        {
+            match key.trim() {
+                "t" => timestamp = Some(value.trim()),
+                "v1" => signature = Some(value.trim()),
+                _ => {}
+            }
+        }
+
+        let timestamp = timestamp
+            .ok_or(ParseWebhookSignatureError::MissingTimestamp)?
+            .parse::<i64>()
+            .ok()
+            .and_then(DateTime::from_timestamp_millis)
+            .ok_or(ParseWebhookSignatureError::InvalidTimestamp)?;
+        let signature =
+            hex::decode(signature.ok_or(ParseWebhookSignatureError::MissingSignature)?)?;
+
+        Ok(Self {
+            timestamp: Timestamp(timestamp.fixed_offset()),
+            signature,
+        })
+    }
+}
+
+impl Display for WebhookSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "t={}, v1={}",
+            self.timestamp.0.timestamp_millis(),
+            hex::encode(&self.signature)
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+
+    use super::*;
+
+    #[test]
+    fn it_parses_a_signature_header() {
+        let signature = WebhookSignature::from_str("t=1696543210000, v1=0a1b2c").unwrap();
+
+        assert_eq!(
+            signature,
+            WebhookSignature {
+                timestamp: Timestamp::try_from("2023-10-05T22:00:10Z").unwrap(),
+                signature: vec![0x0a, 0x1b, 0x2c],
+            }
+        )
+    }
+
+    #[test]
+    fn it_formats_a_signature_header() {
+        let signature = WebhookSignature {
+            timestamp: Timestamp::try_from("2023-10-05T22:00:10Z").unwrap(),
+            signature: vec![0x0a, 0x1b, 0x2c],
+        };
+
+        assert_eq!(signature.to_string(), "t=1696543210000, v1=0a1b2c")
+    }
+
+    #[test]
+    fn it_returns_an_error_when_the_timestamp_is_missing() {
+        assert_matches!(
+            WebhookSignature::from_str("v1=0a1b2c"),
+            Err(ParseWebhookSignatureError::MissingTimestamp)
+        )
+    }
+
+    #[test]
+    fn it_returns_an_error_when_the_signature_is_missing() {
+        assert_matches!(
+            WebhookSignature::from_str("t=1696543210000"),
+            Err(ParseWebhookSignatureError::MissingSignature)
+        )
+    }
+
+    #[test]
+    fn it_returns_an_error_when_the_signature_is_not_hex() {
+        assert_matches!(
+            WebhookSignature::from_str("t=1696543210000, v1=not-hex"),
+            Err(ParseWebhookSignatureError::InvalidSignature(_))
+        )
+    }
+}
@@ -0,0 +1,409 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use chrono::Utc;
+use thiserror::Error;
+
+use crate::events::{AnyEvent, Event};
+use crate::webhooks::{
+    ParseWebhookSignatureError, ReplayStoreError, WebhookSecret, WebhookSignature,
+};
+
+/// An error returned from [`Webhook::verify`] and [`WebhookVerifier::verify`](crate::webhooks::WebhookVerifier::verify).
+#[derive(Debug, Error)]
+pub enum VerifyWebhookError {
+    /// The `WorkOS-Signature` header could not be parsed.
+    #[error("invalid signature header")]
+    InvalidSignatureHeader(#[from] ParseWebhookSignatureError),
+
+    /// The timestamp of the signature is outside of the tolerance zone.
+    #[error("timestamp outside the tolerance zone")]
+    TimestampOutsideTolerance,
+
+    /// The signature does not match the payload.
+    #[error("signature mismatch")]
+    SignatureMismatch,
+
+    /// The webhook has already been delivered.
+    #[error("replayed webhook")]
+    Replayed,
+
+    /// The replay store failed to record the webhook.
+    #[error("replay store error")]
+    ReplayStore(#[source] ReplayStoreError),
+}
+
+/// An error returned from [`Webhook::construct_event`].
+#[derive(Debug, Error)]
+pub enum ConstructWebhookEventError {
+    /// The webhook could not be verified.
+    #[error(transparent)]
+    Verification(#[from] VerifyWebhookError),
+
+    /// The payload could not be deserialized into an [`Event`].
+    #[error("invalid payload")]
+    InvalidPayload(#[from] serde_json::Error),
+}
+
+/// Verification of webhooks sent by WorkOS.
+///
+/// [WorkOS Docs: Webhooks](https://workos.com/docs/events/data-syncing/webhooks)
+pub struct Webhook;
+
+impl Webhook {
+    /// The default tolerance for the timestamp of a webhook signature.
+    pub const DEFAULT_TOLERANCE: Duration = Duration::from_secs(180);
+
+    /// Verifies that the payload was signed by WorkOS using the provided secret.
+    ///
+    /// The payload must be the raw request body, as any re-serialization will invalidate the signature.
+    /// Signatures with a timestamp more than `tolerance` away from the current time are rejected.
+    ///
+    /// [WorkOS Docs: Validating webhooks](https://workos.com/docs/events/data-syncing/webhooks/3-process-the-events/b-validate-the-requests-manually)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos_sdk::webhooks::*;
+    /// # fn run(payload: &str, signature_header: &str) -> Result<(), VerifyWebhookError> {
+    /// Webhook::verify(
+    ///     payload,
+    ///     signature_header,
+    ///     &WebhookSecret::from("whsec_123456789"),
+    ///     Webhook::DEFAULT_TOLERANCE,
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn verify(
+        payload: &str,
+        signature_header: &str,
+        secret: &WebhookSecret,
+        tolerance: Duration,
+    ) -> Result<(), VerifyWebhookError> {
+        Self::verify_with_secrets(
+            payload,
+            signature_header,
+            std::slice::from_ref(secret),
+            tolerance,
+        )
+    }
+
+    /// Verifies that the payload was signed by WorkOS using any of the provided secrets.
+    ///
+    /// This allows a webhook secret to be rotated in the WorkOS Dashboard without rejecting deliveries
+    /// that are still signed with the previous secret.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos_sdk::webhooks::*;
+    /// # fn run(payload: &str, signature_header: &str) -> Result<(), VerifyWebhookError> {
+    /// Webhook::verify_with_secrets(
+    ///     payload,
+    ///     signature_header,
+    ///     &[
+    ///         WebhookSecret::from("whsec_current"),
+    ///         WebhookSecret::from("whsec_previous"),
+    ///     ],
+    ///     Webhook::DEFAULT_TOLERANCE,
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn verify_with_secrets(
+        payload: &str,
+        signature_header: &str,
+        secrets: &[WebhookSecret],
+        tolerance: Duration,
+    ) -> Result<(), VerifyWebhookError> {
+        let signature = WebhookSignature::from_str(signature_header)?;
+
+        let age = Utc::now().signed_duration_since(signature.timestamp.0);
+        if age.abs().to_std().map_or(true, |age| age > tolerance) {
+            return Err(VerifyWebhookError::TimestampOutsideTolerance);
+        }
+
+        if !secrets
+            .iter()
+            .any(|secret| signature.matches(payload, secret))
+        {
+            return Err(VerifyWebhookError::SignatureMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// Verifies the payload using [`Webhook::verify`] and deserializes it into an [`Event`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos_sdk::webhooks::*;
+    /// # fn run(payload: &str, signature_header: &str) -> Result<(), ConstructWebhookEventError> {
+    /// let event = Webhook::construct_event(
+    ///     payload,
+    ///     signature_header,
+    ///     &WebhookSecret::from("whsec_123456789"),
+    ///     Webhook::DEFAULT_TOLERANCE,
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn construct_event(
+        payload: &str,
+        signature_header: &str,
+        secret: &WebhookSecret,
+        tolerance: Duration,
+    ) -> Result<Event, ConstructWebhookEventError> {
+        Self::verify(payload, signature_header, secret, tolerance)?;
+
+        Ok(serde_json::from_str(payload)?)
+    }
+
+    /// Verifies the payload using [`Webhook::verify`] and deserializes it into an [`AnyEvent`]
+    /// that records the signature of the delivery.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos_sdk::webhooks::*;
+    /// # fn run(payload: &str, signature_header: &str) -> Result<(), ConstructWebhookEventError> {
+    /// let event = Webhook::construct_any_event(
+    ///     payload,
+    ///     signature_header,
+    ///     &WebhookSecret::from("whsec_123456789"),
+    ///     Webhook::DEFAULT_TOLERANCE,
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn construct_any_event(
+        payload: &str,
+        signature_header: &str,
+        secret: &WebhookSecret,
+        tolerance: Duration,
+    ) -> Result<AnyEvent, ConstructWebhookEventError> {
+        let event = Self::construct_event(payload, signature_header, secret, tolerance)?;
+
+        any_event(event, signature_header)
+    }
+}
+
+/// Wraps an event whose delivery has been verified with the signature header in an [`AnyEvent`].
+pub(crate) fn any_event(
+    event: Event,
+    signature_header: &str,
+) -> Result<AnyEvent, ConstructWebhookEventError> {
+    let signature =
+        WebhookSignature::from_str(signature_header).map_err(VerifyWebhookError::from)?;
+
+    Ok(AnyEvent::from_webhook(event, signature))
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::TimeDelta;
+    use matches::assert_matches;
+    use serde_json::json;
+
+    use crate::Timestamp;
+    use crate::events::{EventData, EventId, EventSource, UnknownEvent};
+
+    use crate::webhooks::testing::payload;
+
+    use super::*;
+
+    fn sign(payload: &str, secret: &str, age: TimeDelta) -> String {
+        WebhookSignature::compute(
+            &Timestamp((Utc::now() - age).fixed_offset()),
+            payload,
+            &WebhookSecret::from(secret),
+        )
+        .to_string()
+    }
+
+    #[test]
+    fn it_verifies_a_valid_signature() {
+        let payload = payload();
+        let signature_header = sign(&payload, "whsec_123456789", TimeDelta::seconds(5));
+
+        let result = Webhook::verify(
+            &payload,
+            &signature_header,
+            &WebhookSecret::from("whsec_123456789"),
+            Webhook::DEFAULT_TOLERANCE,
+        );
+
+        assert_matches!(result, Ok(()))
+    }
+
+    #[test]
+    fn it_rejects_a_signature_created_with_a_different_secret() {
+        let payload = payload();
+        let signature_header = sign(&payload, "whsec_another_secret", TimeDelta::seconds(5));
+
+        let result = Webhook::verify(
+            &payload,
+            &signature_header,
+            &WebhookSecret::from("whsec_123456789"),
+            Webhook::DEFAULT_TOLERANCE,
+        );
+
+        assert_matches!(result, Err(VerifyWebhookError::SignatureMismatch))
+    }
+
+    #[test]
+    fn it_rejects_a_tampered_payload() {
+        let signature_header = sign(&payload(), "whsec_123456789", TimeDelta::seconds(5));
+
+        let result = Webhook::verify(
+            &payload().replace("Foo Corp", "Evil Corp"),
+            &signature_header,
+            &WebhookSecret::from("whsec_123456789"),
+            Webhook::DEFAULT_TOLERANCE,
+        );
+
+        assert_matches!(result, Err(VerifyWebhookError::SignatureMismatch))
+    }
+
+    #[test]
+    fn it_verifies_a_signature_created_with_any_of_the_secrets() {
+        let payload = payload();
+        let signature_header = sign(&payload, "whsec_previous", TimeDelta::seconds(5));
+
+        let result = Webhook::verify_with_secrets(
+            &payload,
+            &signature_header,
+            &[
+                WebhookSecret::from("whsec_current"),
+                WebhookSecret::from("whsec_previous"),
+            ],
+            Webhook::DEFAULT_TOLERANCE,
+        );
+
+        assert_matches!(result, Ok(()))
+    }
+
+    #[test]
+    fn it_rejects_a_signature_when_no_secrets_are_provided() {
+        let payload = payload();
+        let signature_header = sign(&payload, "whsec_123456789", TimeDelta::seconds(5));
+
+        let result = Webhook::verify_with_secrets(
+            &payload,
+            &signature_header,
+            &[],
+            Webhook::DEFAULT_TOLERANCE,
+        );
+
+        assert_matches!(result, Err(VerifyWebhookError::SignatureMismatch))
+    }
+
+    #[test]
+    fn it_rejects_a_signature_outside_the_tolerance_zone() {
+        let payload = payload();
+        let signature_header = sign(&payload, "whsec_123456789", TimeDelta::minutes(5));
+
+        let result = Webhook::verify(
+            &payload,
+            &signature_header,
+            &WebhookSecret::from("whsec_123456789"),
+            Webhook::DEFAULT_TOLERANCE,
+        );
+
+        assert_matches!(result, Err(VerifyWebhookError::TimestampOutsideTolerance))
+    }
+
+    #[test]
+    fn it_rejects_a_malformed_signature_header() {
+        let result = Webhook::verify(
+            &payload(),
+            "v1=abc",
+            &WebhookSecret::from("whsec_123456789"),
+            Webhook::DEFAULT_TOLERANCE,
+        );
+
+        assert_matches!(result, Err(VerifyWebhookError::InvalidSignatureHeader(_)))
+    }
+
+    #[test]
+    fn it_constructs_an_event_from_a_verified_payload() {
+        let payload = payload();
+        let signature_header = sign(&payload, "whsec_123456789", TimeDelta::seconds(5));
+
+        let event = Webhook::construct_event(
+            &payload,
+            &signature_header,
+            &WebhookSecret::from("whsec_123456789"),
+            Webhook::DEFAULT_TOLERANCE,
+        )
+        .unwrap();
+
+        assert_eq!(event.id, EventId::from("event_01H2GNQD5D7ZE06FDDS75NFPHY"));
+        assert_matches!(event.data, EventData::OrganizationDeleted(_))
+    }
+
+    #[test]
+    fn it_constructs_an_unknown_event_from_a_verified_payload() {
+        let payload = json!({
+            "id": "event_01H2GNQD5D7ZE06FDDS75NFPHY",
+            "event": "organization.renamed",
+            "data": {
+                "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                "name": "Foo Corp"
+            },
+            "created_at": "2023-06-09T18:12:01.837Z"
+        })
+        .to_string();
+        let signature_header = sign(&payload, "whsec_123456789", TimeDelta::seconds(5));
+
+        let event = Webhook::construct_event(
+            &payload,
+            &signature_header,
+            &WebhookSecret::from("whsec_123456789"),
+            Webhook::DEFAULT_TOLERANCE,
+        )
+        .unwrap();
+
+        assert_eq!(
+            event.data,
+            EventData::Unknown(UnknownEvent {
+                event: "organization.renamed".to_string(),
+                data: json!({
+                    "id": "org_01EHZNVPK3SFK441A1RGBFSHRT",
+                    "name": "Foo Corp"
+                }),
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<Event>(serde_json::to_value(&event).unwrap()).unwrap(),
+            event
+        )
+    }
+
+    #[test]
+    fn it_constructs_an_any_event_with_the_signature_of_the_delivery() {
+        let payload = payload();
+        let signature_header = sign(&payload, "whsec_123456789", TimeDelta::seconds(5));
+
+        let any_event = Webhook::construct_any_event(
+            &payload,
+            &signature_header,
+            &WebhookSecret::from("whsec_123456789"),
+            Webhook::DEFAULT_TOLERANCE,
+        )
+        .unwrap();
+
+        assert_eq!(
+            any_event.source,
+            EventSource::Webhook {
+                signature: WebhookSignature::from_str(&signature_header).unwrap()
+            }
+        );
+        assert_eq!(
+            any_event.event.id,
+            EventId::from("event_01H2GNQD5D7ZE06FDDS75NFPHY")
+        )
+    }
+}
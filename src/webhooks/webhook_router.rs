@@ -0,0 +1,304 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::error::Error;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::sync::Arc;
+
+use futures_util::FutureExt;
+use futures_util::future::BoxFuture;
+
+use crate::KnownOrUnknown;
+use crate::events::{AnyEvent, Event, EventName};
+
+/// An error returned from a webhook handler.
+pub type WebhookHandlerError = Box<dyn Error + Send + Sync>;
+
+type WebhookHandler<E> =
+    Arc<dyn Fn(E) -> BoxFuture<'static, Result<(), WebhookHandlerError>> + Send + Sync>;
+
+/// An event that can be dispatched with a [`WebhookRouter`].
+pub trait RoutableEvent: Send + 'static {
+    /// The name used to select the handler of the event, or the raw event name for an
+    /// [`UnknownEvent`](crate::events::UnknownEvent).
+    fn name(&self) -> KnownOrUnknown<EventName, &str>;
+}
+
+impl RoutableEvent for Event {
+    fn name(&self) -> KnownOrUnknown<EventName, &str> {
+        self.data.name()
+    }
+}
+
+impl RoutableEvent for AnyEvent {
+    fn name(&self) -> KnownOrUnknown<EventName, &str> {
+        self.event.data.name()
+    }
+}
+
+/// The outcome of dispatching an [`Event`] with a [`WebhookRouter`].
+#[derive(Debug)]
+pub enum WebhookOutcome {
+    /// The event was handled successfully.
+    Handled,
+
+    /// No handler or fallback was registered for the event.
+    Unhandled,
+
+    /// The handler returned an error.
+    Failed(WebhookHandlerError),
+
+    /// The handler panicked.
+    Panicked(String),
+}
+
+impl WebhookOutcome {
+    /// Whether the delivery should be acknowledged.
+    ///
+    /// Unhandled events are acknowledged, since retrying them will not change the outcome.
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::Handled | Self::Unhandled)
+    }
+
+    /// The HTTP status code that should be returned to WorkOS.
+    ///
+    /// WorkOS retries deliveries that receive a non-2xx response.
+    pub fn status_code(&self) -> u16 {
+        if self.is_success() { 200 } else { 500 }
+    }
+}
+
+/// A router that dispatches webhook events to handlers registered per [`EventName`].
+///
+/// Handlers are isolated from each other: an error or panic in a handler is reported as a [`WebhookOutcome`]
+/// instead of being propagated to the caller.
+///
+/// Handlers receive an [`Event`] by default. Use a `WebhookRouter<AnyEvent>` to dispatch [`AnyEvent`]s, so
+/// handlers also receive how and when the event was received.
+///
+/// # Examples
+///
+/// ```
+/// # use workos_sdk::events::{Event, EventData, EventName};
+/// # use workos_sdk::webhooks::*;
+/// # async fn run(event: Event) {
+/// let router = WebhookRouter::new()
+///     .on(EventName::UserCreated, |event: Event| async move {
+///         if let EventData::UserCreated(user) = event.data {
+///             // Provision the user.
+///         }
+///
+///         Ok(())
+///     })
+///     .fallback(|_event: Event| async { Ok(()) });
+///
+/// let outcome = router.dispatch(event).await;
+/// let status_code = outcome.status_code();
+/// # }
+/// ```
+pub struct WebhookRouter<E = Event> {
+    handlers: HashMap<EventName, WebhookHandler<E>>,
+    fallback: Option<WebhookHandler<E>>,
+}
+
+impl<E> Clone for WebhookRouter<E> {
+    fn clone(&self) -> Self {
+        Self {
+            handlers: self.handlers.clone(),
+            fallback: self.fallback.clone(),
+        }
+    }
+}
+
+impl<E> Default for WebhookRouter<E> {
+    fn default() -> Self {
+        Self {
+            handlers: HashMap::new(),
+            fallback: None,
+        }
+    }
+}
+
+impl WebhookRouter {
+    /// Returns a new [`WebhookRouter`] without any handlers, dispatching [`Event`]s.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<E: RoutableEvent> WebhookRouter<E> {
+    /// Registers a handler for events with the provided name, replacing any previously registered handler.
+    pub fn on<F, Fut>(mut self, name: EventName, handler: F) -> Self
+    where
+        F: Fn(E) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), WebhookHandlerError>> + Send + 'static,
+    {
+        self.handlers.insert(name, boxed(handler));
+        self
+    }
+
+    /// Registers a handler for events that have no handler of their own, including [`UnknownEvent`](crate::events::UnknownEvent)s.
+    pub fn fallback<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(E) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), WebhookHandlerError>> + Send + 'static,
+    {
+        self.fallback = Some(boxed(handler));
+        self
+    }
+
+    /// Dispatches the event to the matching handler.
+    pub async fn dispatch(&self, event: E) -> WebhookOutcome {
+        let handler = match event.name() {
+            KnownOrUnknown::Known(name) => self.handlers.get(&name),
+            KnownOrUnknown::Unknown(_) => None,
+        };
+
+        let Some(handler) = handler.or(self.fallback.as_ref()) else {
+            return WebhookOutcome::Unhandled;
+        };
+
+        let result = match std::panic::catch_unwind(AssertUnwindSafe(|| handler(event))) {
+            Ok(future) => AssertUnwindSafe(future).catch_unwind().await,
+            Err(panic) => Err(panic),
+        };
+
+        match result {
+            Ok(Ok(())) => WebhookOutcome::Handled,
+            Ok(Err(err)) => WebhookOutcome::Failed(err),
+            Err(panic) => WebhookOutcome::Panicked(panic_message(panic)),
+        }
+    }
+}
+
+fn boxed<E, F, Fut>(handler: F) -> WebhookHandler<E>
+where
+    F: Fn(E) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), WebhookHandlerError>> + Send + 'static,
+{
+    Arc::new(move |event| handler(event).boxed())
+}
+
+fn panic_message(panic: Box<dyn Any + Send>) -> String {
+    panic
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| panic.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "handler panicked".to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use matches::assert_matches;
+
+    use crate::events::{EventData, EventSource};
+    use crate::webhooks::testing;
+
+    use super::*;
+
+    fn event(name: &str) -> Event {
+        serde_json::from_value(testing::event_json(testing::EVENT_ID, name)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn it_dispatches_an_event_to_the_matching_handler() {
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let router = WebhookRouter::new()
+            .on(EventName::OrganizationCreated, |_| async {
+                Err("wrong handler".into())
+            })
+            .on(EventName::OrganizationDeleted, {
+                let calls = calls.clone();
+                move |_| {
+                    let calls = calls.clone();
+                    async move {
+                        calls.fetch_add(1, Ordering::SeqCst);
+                        Ok(())
+                    }
+                }
+            });
+
+        let outcome = router.dispatch(event("organization.deleted")).await;
+
+        assert_matches!(outcome, WebhookOutcome::Handled);
+        assert_eq!(outcome.status_code(), 200);
+        assert_eq!(calls.load(Ordering::SeqCst), 1)
+    }
+
+    #[tokio::test]
+    async fn it_dispatches_an_event_without_a_handler_to_the_fallback() {
+        let router = WebhookRouter::new()
+            .on(EventName::OrganizationCreated, |_| async { Ok(()) })
+            .fallback(|_| async { Err("fallback".into()) });
+
+        let outcome = router.dispatch(event("organization.deleted")).await;
+
+        assert_matches!(outcome, WebhookOutcome::Failed(ref err) if err.to_string() == "fallback")
+    }
+
+    #[tokio::test]
+    async fn it_dispatches_an_unknown_event_to_the_fallback() {
+        let router = WebhookRouter::new().fallback(|event: Event| async move {
+            assert_matches!(event.data, EventData::Unknown(ref unknown) if unknown.event == "organization.renamed");
+            Ok(())
+        });
+
+        let outcome = router.dispatch(event("organization.renamed")).await;
+
+        assert_matches!(outcome, WebhookOutcome::Handled)
+    }
+
+    #[tokio::test]
+    async fn it_acknowledges_an_event_without_a_handler() {
+        let outcome = WebhookRouter::new()
+            .dispatch(event("organization.deleted"))
+            .await;
+
+        assert_matches!(outcome, WebhookOutcome::Unhandled);
+        assert_eq!(outcome.status_code(), 200)
+    }
+
+    #[tokio::test]
+    async fn it_reports_a_failed_handler_as_a_server_error() {
+        let router = WebhookRouter::new().on(EventName::OrganizationDeleted, |_| async {
+            Err("database unavailable".into())
+        });
+
+        let outcome = router.dispatch(event("organization.deleted")).await;
+
+        assert_matches!(outcome, WebhookOutcome::Failed(_));
+        assert_eq!(outcome.status_code(), 500)
+    }
+
+    #[tokio::test]
+    async fn it_isolates_a_panicking_handler() {
+        let router = WebhookRouter::new().on(EventName::OrganizationDeleted, |_| async {
+            panic!("handler exploded")
+        });
+
+        let outcome = router.dispatch(event("organization.deleted")).await;
+
+        assert_matches!(outcome, WebhookOutcome::Panicked(ref message) if message == "handler exploded");
+        assert_eq!(outcome.status_code(), 500)
+    }
+
+    #[tokio::test]
+    async fn it_dispatches_an_event_from_the_events_api() {
+        let router = WebhookRouter::<AnyEvent>::default().on(
+            EventName::OrganizationDeleted,
+            |event: AnyEvent| async move {
+                assert_matches!(event.source, EventSource::EventsApi { .. });
+                Ok(())
+            },
+        );
+
+        let outcome = router
+            .dispatch(AnyEvent::from_events_api(event("organization.deleted")))
+            .await;
+
+        assert_matches!(outcome, WebhookOutcome::Handled)
+    }
+}
@@ -0,0 +1,425 @@
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::sync::{Semaphore, mpsc};
+use tokio::task::{JoinHandle, JoinSet};
+
+use crate::events::Event;
+use crate::webhooks::{WebhookOutcome, WebhookRouter};
+
+/// An error returned from a [`WebhookSink`].
+pub type WebhookSinkError = Box<dyn Error + Send + Sync>;
+
+/// A sink that accepts verified events for processing outside of the request that delivered them.
+///
+/// WorkOS expects deliveries to be acknowledged quickly, so handlers should only enqueue the event
+/// and respond with a 2xx status code once [`WebhookSink::accept`] returns.
+///
+/// Implement this trait to enqueue events in a durable queue, e.g. using SQS or a database table.
+#[async_trait]
+pub trait WebhookSink: Send + Sync {
+    /// Enqueues the event for processing.
+    ///
+    /// Returns an error if the event could not be enqueued, in which case the delivery should not be
+    /// acknowledged so WorkOS retries it.
+    async fn accept(&self, event: Event) -> Result<(), WebhookSinkError>;
+}
+
+/// An in-memory [`WebhookSink`] that queues events until they are taken with [`InMemoryWebhookSink::pop`].
+///
+/// Queued events are lost when the process exits, so this sink is mostly useful for tests.
+#[derive(Debug, Default)]
+pub struct InMemoryWebhookSink {
+    events: Mutex<VecDeque<Event>>,
+}
+
+impl InMemoryWebhookSink {
+    /// Returns a new, empty [`InMemoryWebhookSink`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Takes the oldest queued event.
+    pub fn pop(&self) -> Option<Event> {
+        self.events
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .pop_front()
+    }
+
+    /// The number of queued events.
+    pub fn len(&self) -> usize {
+        self.events
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .len()
+    }
+
+    /// Whether there are no queued events.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[async_trait]
+impl WebhookSink for InMemoryWebhookSink {
+    async fn accept(&self, event: Event) -> Result<(), WebhookSinkError> {
+        self.events
+            .lock()
+            .map_err(|err| err.to_string())?
+            .push_back(event);
+
+        Ok(())
+    }
+}
+
+type ExhaustedHook = Arc<dyn Fn(Event, WebhookOutcome) + Send + Sync>;
+
+/// The retry policy of a [`ChannelWebhookSink`].
+#[derive(Clone)]
+pub struct WebhookRetryPolicy {
+    /// The maximum number of times an event is dispatched, including the first attempt.
+    pub max_attempts: u32,
+
+    /// The delay before the first retry, which is doubled after every further attempt.
+    pub initial_backoff: Duration,
+
+    /// The maximum delay between two attempts.
+    pub max_backoff: Duration,
+
+    on_exhausted: Option<ExhaustedHook>,
+}
+
+impl WebhookRetryPolicy {
+    /// Registers a hook that is called with events that still fail after the last attempt.
+    ///
+    /// Use this hook to move the event to a dead-letter queue instead of losing it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use workos_sdk::events::Event;
+    /// # use workos_sdk::webhooks::*;
+    /// let retry_policy = WebhookRetryPolicy::default().on_exhausted(
+    ///     |event: Event, outcome: WebhookOutcome| {
+    ///         // Store the event in a dead-letter queue.
+    ///     },
+    /// );
+    /// ```
+    pub fn on_exhausted(
+        mut self,
+        hook: impl Fn(Event, WebhookOutcome) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_exhausted = Some(Arc::new(hook));
+        self
+    }
+
+    fn exhausted(&self, event: Event, outcome: WebhookOutcome) {
+        tracing::error!(
+            target: "workos_sdk::webhooks",
+            event_id = %event.id,
+            ?outcome,
+            "dropping event after the last attempt"
+        );
+        #[cfg(feature = "log")]
+        log::error!(
+            target: "workos_sdk::webhooks",
+            "dropping event after the last attempt event_id={} outcome={outcome:?}",
+            event.id
+        );
+
+        if let Some(on_exhausted) = &self.on_exhausted {
+            on_exhausted(event, outcome);
+        }
+    }
+}
+
+impl Default for WebhookRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            on_exhausted: None,
+        }
+    }
+}
+
+impl fmt::Debug for WebhookRetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookRetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("initial_backoff", &self.initial_backoff)
+            .field("max_backoff", &self.max_backoff)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A [`WebhookSink`] that hands events to a background task, which dispatches them with a [`WebhookRouter`].
+///
+/// Events whose handler fails or panics are retried according to the [`WebhookRetryPolicy`]. Events that still
+/// fail after the last attempt are logged and passed to [`WebhookRetryPolicy::on_exhausted`], if registered.
+///
+/// Events are dispatched concurrently, each in its own task, so an event waiting for a retry does not hold up the
+/// events behind it. As a result, events are not necessarily handled in the order they were accepted.
+///
+/// Buffered events and events waiting for a retry are lost when the process exits, since their deliveries have
+/// already been acknowledged. Implement [`WebhookSink`] on top of a durable queue if events must not be lost.
+///
+/// # Examples
+///
+/// ```
+/// # use workos_sdk::events::{Event, EventName};
+/// # use workos_sdk::webhooks::*;
+/// # async fn run(event: Event) -> Result<(), WebhookSinkError> {
+/// let router = WebhookRouter::new().on(EventName::UserCreated, |_event: Event| async { Ok(()) });
+/// let (sink, _task) = ChannelWebhookSink::spawn(router, 1024, WebhookRetryPolicy::default());
+///
+/// sink.accept(event).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ChannelWebhookSink {
+    sender: mpsc::Sender<Event>,
+}
+
+impl ChannelWebhookSink {
+    /// Spawns the background task on the current Tokio runtime and returns a sink that feeds it.
+    ///
+    /// At most `capacity` events are buffered and at most `capacity` events are dispatched at the same time;
+    /// [`WebhookSink::accept`] waits for space once the buffer is full. The task exits once every clone of the sink
+    /// has been dropped and the buffered events have been processed.
+    pub fn spawn(
+        router: WebhookRouter,
+        capacity: usize,
+        retry_policy: WebhookRetryPolicy,
+    ) -> (Self, JoinHandle<()>) {
+        let (sender, mut receiver) = mpsc::channel::<Event>(capacity);
+        let permits = Arc::new(Semaphore::new(capacity));
+
+        let task = tokio::spawn(async move {
+            let mut dispatches = JoinSet::new();
+
+            while let Some(event) = receiver.recv().await {
+                let Ok(permit) = permits.clone().acquire_owned().await else {
+                    break;
+                };
+
+                // Drop the results of finished dispatches, so they do not accumulate.
+                while dispatches.try_join_next().is_some() {}
+
+                let router = router.clone();
+                let retry_policy = retry_policy.clone();
+
+                dispatches.spawn(async move {
+                    let outcome = dispatch_with_retries(&router, &event, &retry_policy).await;
+
+                    if !outcome.is_success() {
+                        retry_policy.exhausted(event, outcome);
+                    }
+
+                    drop(permit);
+                });
+            }
+
+            while dispatches.join_next().await.is_some() {}
+        });
+
+        (Self { sender }, task)
+    }
+}
+
+#[async_trait]
+impl WebhookSink for ChannelWebhookSink {
+    async fn accept(&self, event: Event) -> Result<(), WebhookSinkError> {
+        self.sender
+            .send(event)
+            .await
+            .map_err(|err| err.to_string())?;
+
+        Ok(())
+    }
+}
+
+async fn dispatch_with_retries(
+    router: &WebhookRouter,
+    event: &Event,
+    retry_policy: &WebhookRetryPolicy,
+) -> WebhookOutcome {
+    let mut backoff = retry_policy.initial_backoff;
+    let mut attempt = 1;
+
+    loop {
+        let outcome = router.dispatch(event.clone()).await;
+
+        if outcome.is_success() || attempt >= retry_policy.max_attempts {
+            return outcome;
+        }
+
+        tokio::time::sleep(backoff).await;
+        backoff = (backoff * 2).min(retry_policy.max_backoff);
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use matches::assert_matches;
+
+    use crate::events::EventName;
+
+    use crate::webhooks::testing::{event, event_json};
+
+    use super::*;
+
+    fn flaky_router(attempts: Arc<AtomicU32>, failures: u32) -> WebhookRouter {
+        WebhookRouter::new().on(EventName::OrganizationDeleted, move |_| {
+            let attempts = attempts.clone();
+            async move {
+                if attempts.fetch_add(1, Ordering::SeqCst) < failures {
+                    Err("temporarily unavailable".into())
+                } else {
+                    Ok(())
+                }
+            }
+        })
+    }
+
+    fn retry_policy() -> WebhookRetryPolicy {
+        WebhookRetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(5),
+            on_exhausted: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn it_queues_events_in_memory() {
+        let sink = InMemoryWebhookSink::new();
+
+        sink.accept(event()).await.unwrap();
+
+        assert_eq!(sink.len(), 1);
+        assert_eq!(sink.pop(), Some(event()));
+        assert!(sink.is_empty())
+    }
+
+    #[tokio::test]
+    async fn it_processes_accepted_events_in_the_background() {
+        let attempts = Arc::new(AtomicU32::new(0));
+
+        let (sink, task) =
+            ChannelWebhookSink::spawn(flaky_router(attempts.clone(), 0), 16, retry_policy());
+
+        sink.accept(event()).await.unwrap();
+        drop(sink);
+        task.await.unwrap();
+
+        assert_eq!(attempts.load(Ordering::SeqCst), 1)
+    }
+
+    #[tokio::test]
+    async fn it_does_not_hold_up_later_events_while_retrying() {
+        let (handled, received) = tokio::sync::oneshot::channel();
+        let handled = Arc::new(Mutex::new(Some(handled)));
+
+        let router = WebhookRouter::new()
+            .on(EventName::OrganizationDeleted, |_| async {
+                Err("temporarily unavailable".into())
+            })
+            .on(EventName::OrganizationCreated, move |_| {
+                let handled = handled.clone();
+                async move {
+                    if let Some(handled) = handled.lock().unwrap().take() {
+                        handled.send(()).unwrap();
+                    }
+                    Ok(())
+                }
+            });
+        let retry_policy = WebhookRetryPolicy {
+            initial_backoff: Duration::from_secs(3600),
+            ..retry_policy()
+        };
+
+        let (sink, task) = ChannelWebhookSink::spawn(router, 16, retry_policy);
+
+        sink.accept(event()).await.unwrap();
+        sink.accept(
+            serde_json::from_value(event_json(
+                "event_01H2GQNMQNH8VRXVR7AEYG9XCJ",
+                "organization.created",
+            ))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+        tokio::time::timeout(Duration::from_secs(5), received)
+            .await
+            .unwrap()
+            .unwrap();
+        task.abort();
+    }
+
+    #[tokio::test]
+    async fn it_retries_a_failed_event() {
+        let attempts = Arc::new(AtomicU32::new(0));
+
+        let outcome = dispatch_with_retries(
+            &flaky_router(attempts.clone(), 2),
+            &event(),
+            &retry_policy(),
+        )
+        .await;
+
+        assert_matches!(outcome, WebhookOutcome::Handled);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3)
+    }
+
+    #[tokio::test]
+    async fn it_gives_up_after_the_last_attempt() {
+        let attempts = Arc::new(AtomicU32::new(0));
+
+        let outcome = dispatch_with_retries(
+            &flaky_router(attempts.clone(), 5),
+            &event(),
+            &retry_policy(),
+        )
+        .await;
+
+        assert_matches!(outcome, WebhookOutcome::Failed(_));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3)
+    }
+
+    #[tokio::test]
+    async fn it_passes_exhausted_events_to_the_hook() {
+        let attempts = Arc::new(AtomicU32::new(0));
+        let exhausted = Arc::new(Mutex::new(Vec::new()));
+
+        let retry_policy = retry_policy().on_exhausted({
+            let exhausted = exhausted.clone();
+            move |event, outcome| {
+                assert_matches!(outcome, WebhookOutcome::Failed(_));
+                exhausted.lock().unwrap().push(event);
+            }
+        });
+
+        let (sink, task) =
+            ChannelWebhookSink::spawn(flaky_router(attempts.clone(), 5), 16, retry_policy);
+
+        sink.accept(event()).await.unwrap();
+        drop(sink);
+        task.await.unwrap();
+
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+        assert_eq!(*exhausted.lock().unwrap(), vec![event()])
+    }
+}
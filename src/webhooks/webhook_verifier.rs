@@ -0,0 +1,328 @@
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::events::{AnyEvent, Event, EventId};
+use crate::webhooks::{
+    ConstructWebhookEventError, ReplayStore, VerifyWebhookError, Webhook, WebhookSecret,
+    WebhookSignature, any_event,
+};
+
+/// The key used to detect replayed webhook deliveries.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReplayKey {
+    /// Deliveries with the same signature are rejected.
+    ///
+    /// This only rejects exact copies of a delivery, while retries sent by WorkOS are still accepted.
+    #[default]
+    Signature,
+
+    /// Deliveries of the same event are rejected.
+    ///
+    /// This additionally rejects retries of an event that arrive within the tolerance zone.
+    /// Falls back to the signature if the payload does not contain an event ID.
+    EventId,
+}
+
+/// A reusable verifier for webhooks sent by WorkOS.
+///
+/// In addition to [`Webhook::verify_with_secrets`], the verifier can reject deliveries that have already been seen
+/// using a [`ReplayStore`].
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// # use workos_sdk::webhooks::*;
+/// # async fn run(payload: &str, signature_header: &str) -> Result<(), VerifyWebhookError> {
+/// let verifier = WebhookVerifier::new(&WebhookSecret::from("whsec_123456789"))
+///     .tolerance(Duration::from_secs(60))
+///     .replay_store(InMemoryReplayStore::new(), ReplayKey::EventId);
+///
+/// verifier.verify(payload, signature_header).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct WebhookVerifier {
+    secrets: Vec<WebhookSecret>,
+    tolerance: Duration,
+    replay_store: Option<(Arc<dyn ReplayStore>, ReplayKey)>,
+    pub(crate) body_limit: usize,
+}
+
+impl WebhookVerifier {
+    /// The default maximum size of a webhook request body in bytes.
+    pub const DEFAULT_BODY_LIMIT: usize = 1024 * 1024;
+
+    /// Returns a new [`WebhookVerifier`] using the provided secret, the default tolerance and the default body limit.
+    pub fn new(secret: &WebhookSecret) -> Self {
+        Self {
+            secrets: vec![secret.to_owned()],
+            tolerance: Webhook::DEFAULT_TOLERANCE,
+            replay_store: None,
+            body_limit: Self::DEFAULT_BODY_LIMIT,
+        }
+    }
+
+    /// Adds a secret that signatures are also verified against.
+    ///
+    /// Use this while rotating the webhook secret in the WorkOS Dashboard, so deliveries signed with
+    /// the previous secret are still accepted until the rotation is complete.
+    pub fn additional_secret(mut self, secret: &WebhookSecret) -> Self {
+        self.secrets.push(secret.to_owned());
+        self
+    }
+
+    /// Sets the maximum allowed difference between the signature timestamp and the current time.
+    pub fn tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Sets the maximum size of a webhook request body in bytes.
+    ///
+    /// The [`WorkOsWebhook`](crate::webhooks::WorkOsWebhook) extractors and the warp filter reject larger requests
+    /// with 413 Payload Too Large before reading the whole body, since the signature can only be verified afterwards.
+    pub fn body_limit(mut self, body_limit: usize) -> Self {
+        self.body_limit = body_limit;
+        self
+    }
+
+    /// Enables replay protection using the provided store.
+    ///
+    /// Keys are recorded for twice the tolerance, since older deliveries are rejected by the timestamp check.
+    pub fn replay_store(mut self, store: impl ReplayStore + 'static, key: ReplayKey) -> Self {
+        self.replay_store = Some((Arc::new(store), key));
+        self
+    }
+
+    /// Verifies that the payload was signed by WorkOS and has not been seen before.
+    pub async fn verify(
+        &self,
+        payload: &str,
+        signature_header: &str,
+    ) -> Result<(), VerifyWebhookError> {
+        Webhook::verify_with_secrets(payload, signature_header, &self.secrets, self.tolerance)?;
+
+        if let Some((store, key)) = &self.replay_store {
+            let key = match key {
+                ReplayKey::Signature => None,
+                ReplayKey::EventId => event_id(payload).map(|id| format!("event:{id}")),
+            }
+            .map_or_else(|| signature_key(signature_header), Ok)?;
+
+            if !store
+                .insert(&key, self.tolerance * 2)
+                .await
+                .map_err(VerifyWebhookError::ReplayStore)?
+            {
+                return Err(VerifyWebhookError::Replayed);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Verifies the payload using [`WebhookVerifier::verify`] and deserializes it into an [`Event`].
+    pub async fn construct_event(
+        &self,
+        payload: &str,
+        signature_header: &str,
+    ) -> Result<Event, ConstructWebhookEventError> {
+        self.verify(payload, signature_header).await?;
+
+        Ok(serde_json::from_str(payload)?)
+    }
+
+    /// Verifies the payload using [`WebhookVerifier::verify`] and deserializes it into an [`AnyEvent`]
+    /// that records the signature of the delivery.
+    pub async fn construct_any_event(
+        &self,
+        payload: &str,
+        signature_header: &str,
+    ) -> Result<AnyEvent, ConstructWebhookEventError> {
+        let event = self.construct_event(payload, signature_header).await?;
+
+        any_event(event, signature_header)
+    }
+}
+
+fn event_id(payload: &str) -> Option<EventId> {
+    #[derive(Deserialize)]
+    struct Payload {
+        id: EventId,
+    }
+
+    serde_json::from_str::<Payload>(payload)
+        .ok()
+        .map(|payload| payload.id)
+}
+
+fn signature_key(signature_header: &str) -> Result<String, VerifyWebhookError> {
+    let signature = WebhookSignature::from_str(signature_header)?;
+
+    Ok(format!("signature:{}", hex::encode(signature.signature)))
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{TimeDelta, Utc};
+    use matches::assert_matches;
+
+    use crate::Timestamp;
+    use crate::webhooks::InMemoryReplayStore;
+
+    use crate::webhooks::testing;
+
+    use super::*;
+
+    fn payload(id: &str) -> String {
+        testing::event_json(id, "organization.deleted").to_string()
+    }
+
+    fn sign(payload: &str, age: TimeDelta) -> String {
+        sign_with_secret(payload, "whsec_123456789", age)
+    }
+
+    fn sign_with_secret(payload: &str, secret: &str, age: TimeDelta) -> String {
+        WebhookSignature::compute(
+            &Timestamp((Utc::now() - age).fixed_offset()),
+            payload,
+            &WebhookSecret::from(secret),
+        )
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn it_accepts_signatures_from_the_current_and_previous_secret() {
+        let payload = payload("event_01H2GNQD5D7ZE06FDDS75NFPHY");
+
+        let verifier = WebhookVerifier::new(&WebhookSecret::from("whsec_current"))
+            .additional_secret(&WebhookSecret::from("whsec_previous"));
+
+        assert_matches!(
+            verifier
+                .verify(
+                    &payload,
+                    &sign_with_secret(&payload, "whsec_current", TimeDelta::seconds(5))
+                )
+                .await,
+            Ok(())
+        );
+        assert_matches!(
+            verifier
+                .verify(
+                    &payload,
+                    &sign_with_secret(&payload, "whsec_previous", TimeDelta::seconds(5))
+                )
+                .await,
+            Ok(())
+        );
+        assert_matches!(
+            verifier
+                .verify(
+                    &payload,
+                    &sign_with_secret(&payload, "whsec_unknown", TimeDelta::seconds(5))
+                )
+                .await,
+            Err(VerifyWebhookError::SignatureMismatch)
+        );
+    }
+
+    #[tokio::test]
+    async fn it_uses_the_configured_tolerance() {
+        let payload = payload("event_01H2GNQD5D7ZE06FDDS75NFPHY");
+        let signature_header = sign(&payload, TimeDelta::seconds(30));
+
+        let verifier = WebhookVerifier::new(&WebhookSecret::from("whsec_123456789"))
+            .tolerance(Duration::from_secs(10));
+
+        assert_matches!(
+            verifier.verify(&payload, &signature_header).await,
+            Err(VerifyWebhookError::TimestampOutsideTolerance)
+        )
+    }
+
+    #[tokio::test]
+    async fn it_rejects_a_replayed_signature() {
+        let payload = payload("event_01H2GNQD5D7ZE06FDDS75NFPHY");
+        let signature_header = sign(&payload, TimeDelta::seconds(5));
+
+        let verifier = WebhookVerifier::new(&WebhookSecret::from("whsec_123456789"))
+            .replay_store(InMemoryReplayStore::new(), ReplayKey::Signature);
+
+        assert_matches!(verifier.verify(&payload, &signature_header).await, Ok(()));
+        assert_matches!(
+            verifier.verify(&payload, &signature_header).await,
+            Err(VerifyWebhookError::Replayed)
+        );
+        assert_matches!(
+            verifier
+                .verify(&payload, &sign(&payload, TimeDelta::seconds(1)))
+                .await,
+            Ok(())
+        );
+    }
+
+    #[tokio::test]
+    async fn it_rejects_a_replayed_event_id() {
+        let payload = payload("event_01H2GNQD5D7ZE06FDDS75NFPHY");
+
+        let verifier = WebhookVerifier::new(&WebhookSecret::from("whsec_123456789"))
+            .replay_store(InMemoryReplayStore::new(), ReplayKey::EventId);
+
+        assert_matches!(
+            verifier
+                .verify(&payload, &sign(&payload, TimeDelta::seconds(5)))
+                .await,
+            Ok(())
+        );
+        assert_matches!(
+            verifier
+                .verify(&payload, &sign(&payload, TimeDelta::seconds(1)))
+                .await,
+            Err(VerifyWebhookError::Replayed)
+        );
+
+        let another_payload = self::payload("event_01H2GQNMQNH8VRXVR7AEYG9XCJ");
+        assert_matches!(
+            verifier
+                .verify(
+                    &another_payload,
+                    &sign(&another_payload, TimeDelta::seconds(1))
+                )
+                .await,
+            Ok(())
+        );
+    }
+
+    #[tokio::test]
+    async fn it_does_not_record_deliveries_with_an_invalid_signature() {
+        let payload = payload("event_01H2GNQD5D7ZE06FDDS75NFPHY");
+
+        let verifier = WebhookVerifier::new(&WebhookSecret::from("whsec_123456789"))
+            .replay_store(InMemoryReplayStore::new(), ReplayKey::EventId);
+
+        let forged_signature_header = WebhookSignature::compute(
+            &Timestamp(Utc::now().fixed_offset()),
+            &payload,
+            &WebhookSecret::from("whsec_forged"),
+        )
+        .to_string();
+
+        assert_matches!(
+            verifier.verify(&payload, &forged_signature_header).await,
+            Err(VerifyWebhookError::SignatureMismatch)
+        );
+        assert_matches!(
+            verifier
+                .verify(&payload, &sign(&payload, TimeDelta::seconds(1)))
+                .await,
+            Ok(())
+        );
+    }
+}
@@ -0,0 +1,96 @@
+#[cfg(feature = "actix")]
+mod actix;
+#[cfg(feature = "axum")]
+mod axum;
+#[cfg(feature = "warp")]
+mod warp;
+
+#[cfg(feature = "warp")]
+pub use self::warp::*;
+
+#[cfg(any(feature = "actix", feature = "axum"))]
+use futures_util::{Stream, StreamExt};
+use thiserror::Error;
+
+use crate::events::Event;
+use crate::webhooks::VerifyWebhookError;
+
+/// The name of the header containing the webhook signature.
+pub const WORKOS_SIGNATURE_HEADER: &str = "WorkOS-Signature";
+
+/// A verified webhook sent by WorkOS.
+///
+/// When used as an extractor, the raw request body is verified using the [`WebhookVerifier`](crate::webhooks::WebhookVerifier)
+/// from the application state before it is deserialized into `T`.
+/// With axum the verifier is obtained through `FromRef`, with actix-web it must be registered as `web::Data<WebhookVerifier>`.
+/// Requests that fail verification are rejected with a [`WorkOsWebhookRejection`].
+#[derive(Clone, Debug)]
+pub struct WorkOsWebhook<T = Event>(pub T);
+
+/// A rejection returned when extracting a [`WorkOsWebhook`] fails.
+#[derive(Debug, Error)]
+pub enum WorkOsWebhookRejection {
+    /// The request does not contain a `WorkOS-Signature` header.
+    #[error("missing signature header")]
+    MissingSignatureHeader,
+
+    /// No [`WebhookVerifier`](crate::webhooks::WebhookVerifier) was registered with the application.
+    #[error("missing webhook verifier")]
+    MissingVerifier,
+
+    /// The request body could not be read as UTF-8 text.
+    #[error("invalid request body")]
+    InvalidBody,
+
+    /// The request body is larger than the body limit of the [`WebhookVerifier`](crate::webhooks::WebhookVerifier).
+    #[error("payload too large")]
+    PayloadTooLarge,
+
+    /// The webhook could not be verified.
+    #[error(transparent)]
+    Verification(#[from] VerifyWebhookError),
+
+    /// The verified payload could not be deserialized.
+    #[error("invalid payload")]
+    InvalidPayload(#[from] serde_json::Error),
+}
+
+impl WorkOsWebhookRejection {
+    /// The HTTP status code that should be returned for the rejection.
+    ///
+    /// Failures of the replay store result in a server error, so WorkOS will retry the delivery.
+    pub fn status_code(&self) -> u16 {
+        match self {
+            Self::InvalidBody | Self::InvalidPayload(_) => 400,
+            Self::MissingSignatureHeader => 401,
+            Self::PayloadTooLarge => 413,
+            Self::MissingVerifier | Self::Verification(VerifyWebhookError::ReplayStore(_)) => 500,
+            Self::Verification(_) => 401,
+        }
+    }
+}
+
+/// Reads the request body as UTF-8 text, rejecting bodies larger than the limit.
+#[cfg(any(feature = "actix", feature = "axum"))]
+pub(crate) async fn read_body<B, E>(
+    body: impl Stream<Item = Result<B, E>>,
+    limit: usize,
+) -> Result<String, WorkOsWebhookRejection>
+where
+    B: AsRef<[u8]>,
+{
+    let mut body = std::pin::pin!(body);
+    let mut payload = Vec::new();
+
+    while let Some(chunk) = body.next().await {
+        let chunk = chunk.map_err(|_| WorkOsWebhookRejection::InvalidBody)?;
+
+        if payload.len() + chunk.as_ref().len() > limit {
+            return Err(WorkOsWebhookRejection::PayloadTooLarge);
+        }
+
+        payload.extend_from_slice(chunk.as_ref());
+    }
+
+    String::from_utf8(payload).map_err(|_| WorkOsWebhookRejection::InvalidBody)
+}
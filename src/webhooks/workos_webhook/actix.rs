@@ -0,0 +1,145 @@
+use actix_web::dev::Payload;
+use actix_web::http::StatusCode;
+use actix_web::{FromRequest, HttpRequest, HttpResponse, ResponseError, web};
+use futures_util::future::LocalBoxFuture;
+use serde::de::DeserializeOwned;
+
+use crate::webhooks::workos_webhook::read_body;
+use crate::webhooks::{
+    WORKOS_SIGNATURE_HEADER, WebhookVerifier, WorkOsWebhook, WorkOsWebhookRejection,
+};
+
+impl<T> FromRequest for WorkOsWebhook<T>
+where
+    T: DeserializeOwned + 'static,
+{
+    type Error = WorkOsWebhookRejection;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let verifier = req.app_data::<web::Data<WebhookVerifier>>().cloned();
+        let signature_header = req
+            .headers()
+            .get(WORKOS_SIGNATURE_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned);
+        let body = payload.take();
+
+        Box::pin(async move {
+            let verifier = verifier.ok_or(WorkOsWebhookRejection::MissingVerifier)?;
+            let signature_header =
+                signature_header.ok_or(WorkOsWebhookRejection::MissingSignatureHeader)?;
+            let payload = read_body(body, verifier.body_limit).await?;
+
+            verifier.verify(&payload, &signature_header).await?;
+
+            Ok(Self(serde_json::from_str(&payload)?))
+        })
+    }
+}
+
+impl ResponseError for WorkOsWebhookRejection {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(WorkOsWebhookRejection::status_code(self))
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(ResponseError::status_code(self)).body(self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use actix_web::test::TestRequest;
+    use matches::assert_matches;
+
+    use crate::events::{Event, EventData};
+    use crate::webhooks::{SignedWebhook, VerifyWebhookError, WebhookSecret};
+
+    use crate::webhooks::testing::payload;
+
+    use super::*;
+
+    fn request(payload: String, secret: &str) -> TestRequest {
+        let SignedWebhook {
+            signature_header,
+            body,
+        } = SignedWebhook::from_json(payload, &WebhookSecret::from(secret));
+
+        TestRequest::post()
+            .uri("/webhooks")
+            .insert_header((WORKOS_SIGNATURE_HEADER, signature_header))
+            .app_data(web::Data::new(WebhookVerifier::new(&WebhookSecret::from(
+                "whsec_123456789",
+            ))))
+            .set_payload(body)
+    }
+
+    #[actix_rt::test]
+    async fn it_extracts_a_verified_event() {
+        let (req, mut payload) = request(payload(), "whsec_123456789").to_http_parts();
+
+        let WorkOsWebhook(event) = WorkOsWebhook::<Event>::from_request(&req, &mut payload)
+            .await
+            .unwrap();
+
+        assert_matches!(event.data, EventData::OrganizationDeleted(_))
+    }
+
+    #[actix_rt::test]
+    async fn it_rejects_an_invalid_signature_with_unauthorized() {
+        let (req, mut payload) = request(payload(), "whsec_forged").to_http_parts();
+
+        let rejection = WorkOsWebhook::<Event>::from_request(&req, &mut payload)
+            .await
+            .unwrap_err();
+
+        assert_matches!(
+            rejection,
+            WorkOsWebhookRejection::Verification(VerifyWebhookError::SignatureMismatch)
+        );
+        assert_eq!(
+            rejection.error_response().status(),
+            StatusCode::UNAUTHORIZED
+        )
+    }
+
+    #[actix_rt::test]
+    async fn it_rejects_a_body_over_the_limit_with_payload_too_large() {
+        let (req, mut payload) = request(payload(), "whsec_123456789")
+            .app_data(web::Data::new(
+                WebhookVerifier::new(&WebhookSecret::from("whsec_123456789")).body_limit(16),
+            ))
+            .to_http_parts();
+
+        let rejection = WorkOsWebhook::<Event>::from_request(&req, &mut payload)
+            .await
+            .unwrap_err();
+
+        assert_matches!(rejection, WorkOsWebhookRejection::PayloadTooLarge);
+        assert_eq!(
+            rejection.error_response().status(),
+            StatusCode::PAYLOAD_TOO_LARGE
+        )
+    }
+
+    #[actix_rt::test]
+    async fn it_rejects_a_request_without_a_verifier_with_internal_server_error() {
+        let (req, mut payload) = TestRequest::post()
+            .uri("/webhooks")
+            .insert_header((WORKOS_SIGNATURE_HEADER, "t=1696543210000, v1=0a1b2c"))
+            .set_payload(payload())
+            .to_http_parts();
+
+        let rejection = WorkOsWebhook::<Event>::from_request(&req, &mut payload)
+            .await
+            .unwrap_err();
+
+        assert_matches!(rejection, WorkOsWebhookRejection::MissingVerifier);
+        assert_eq!(
+            rejection.error_response().status(),
+            StatusCode::INTERNAL_SERVER_ERROR
+        )
+    }
+}
@@ -0,0 +1,144 @@
+use axum::extract::{FromRef, FromRequest, Request};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::de::DeserializeOwned;
+
+use crate::webhooks::workos_webhook::read_body;
+use crate::webhooks::{
+    WORKOS_SIGNATURE_HEADER, WebhookVerifier, WorkOsWebhook, WorkOsWebhookRejection,
+};
+
+impl<T, S> FromRequest<S> for WorkOsWebhook<T>
+where
+    T: DeserializeOwned,
+    WebhookVerifier: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = WorkOsWebhookRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let signature_header = req
+            .headers()
+            .get(WORKOS_SIGNATURE_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned)
+            .ok_or(WorkOsWebhookRejection::MissingSignatureHeader)?;
+
+        let verifier = WebhookVerifier::from_ref(state);
+        let payload = read_body(req.into_body().into_data_stream(), verifier.body_limit).await?;
+
+        verifier.verify(&payload, &signature_header).await?;
+
+        Ok(Self(serde_json::from_str(&payload)?))
+    }
+}
+
+impl IntoResponse for WorkOsWebhookRejection {
+    fn into_response(self) -> Response {
+        let status =
+            StatusCode::from_u16(self.status_code()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+        (status, self.to_string()).into_response()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use axum::body::Body;
+    use matches::assert_matches;
+    use serde_json::json;
+
+    use crate::events::{Event, EventData};
+    use crate::webhooks::{SignedWebhook, VerifyWebhookError, WebhookSecret};
+
+    use crate::webhooks::testing::payload;
+
+    use super::*;
+
+    fn request(payload: String, secret: &str) -> Request {
+        let SignedWebhook {
+            signature_header,
+            body,
+        } = SignedWebhook::from_json(payload, &WebhookSecret::from(secret));
+
+        Request::builder()
+            .method("POST")
+            .uri("/webhooks")
+            .header(WORKOS_SIGNATURE_HEADER, signature_header)
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    fn state() -> WebhookVerifier {
+        WebhookVerifier::new(&WebhookSecret::from("whsec_123456789"))
+    }
+
+    #[tokio::test]
+    async fn it_extracts_a_verified_event() {
+        let WorkOsWebhook(event) =
+            WorkOsWebhook::<Event>::from_request(request(payload(), "whsec_123456789"), &state())
+                .await
+                .unwrap();
+
+        assert_matches!(event.data, EventData::OrganizationDeleted(_))
+    }
+
+    #[tokio::test]
+    async fn it_rejects_an_invalid_signature_with_unauthorized() {
+        let rejection =
+            WorkOsWebhook::<Event>::from_request(request(payload(), "whsec_forged"), &state())
+                .await
+                .unwrap_err();
+
+        assert_matches!(
+            rejection,
+            WorkOsWebhookRejection::Verification(VerifyWebhookError::SignatureMismatch)
+        );
+        assert_eq!(rejection.into_response().status(), StatusCode::UNAUTHORIZED)
+    }
+
+    #[tokio::test]
+    async fn it_rejects_a_missing_signature_header_with_unauthorized() {
+        let request = Request::builder()
+            .method("POST")
+            .uri("/webhooks")
+            .body(Body::from(payload()))
+            .unwrap();
+
+        let rejection = WorkOsWebhook::<Event>::from_request(request, &state())
+            .await
+            .unwrap_err();
+
+        assert_matches!(rejection, WorkOsWebhookRejection::MissingSignatureHeader);
+        assert_eq!(rejection.into_response().status(), StatusCode::UNAUTHORIZED)
+    }
+
+    #[tokio::test]
+    async fn it_rejects_an_invalid_payload_with_bad_request() {
+        let rejection = WorkOsWebhook::<Event>::from_request(
+            request(json!({ "id": "event_123" }).to_string(), "whsec_123456789"),
+            &state(),
+        )
+        .await
+        .unwrap_err();
+
+        assert_matches!(rejection, WorkOsWebhookRejection::InvalidPayload(_));
+        assert_eq!(rejection.into_response().status(), StatusCode::BAD_REQUEST)
+    }
+
+    #[tokio::test]
+    async fn it_rejects_a_body_over_the_limit_with_payload_too_large() {
+        let rejection = WorkOsWebhook::<Event>::from_request(
+            request(payload(), "whsec_123456789"),
+            &state().body_limit(16),
+        )
+        .await
+        .unwrap_err();
+
+        assert_matches!(rejection, WorkOsWebhookRejection::PayloadTooLarge);
+        assert_eq!(
+            rejection.into_response().status(),
+            StatusCode::PAYLOAD_TOO_LARGE
+        )
+    }
+}
@@ -0,0 +1,174 @@
+use bytes::Bytes;
+use serde::de::DeserializeOwned;
+use warp::http::StatusCode;
+use warp::reject::{Reject, Rejection};
+use warp::reply::{Reply, Response};
+use warp::{Filter, filters};
+
+use crate::webhooks::{
+    WORKOS_SIGNATURE_HEADER, WebhookVerifier, WorkOsWebhook, WorkOsWebhookRejection,
+};
+
+impl Reject for WorkOsWebhookRejection {}
+
+/// Returns a [`Filter`] that verifies the webhook using the provided verifier and extracts the payload.
+///
+/// Requests that fail verification are rejected with a [`WorkOsWebhookRejection`],
+/// which can be turned into a response using [`recover_webhook_rejection`].
+/// Requests without a `Content-Length` header or with a body larger than the
+/// [body limit](WebhookVerifier::body_limit) of the verifier are rejected by warp before the body is read.
+///
+/// # Examples
+///
+/// ```
+/// # use workos_sdk::events::Event;
+/// # use workos_sdk::webhooks::*;
+/// use warp::Filter;
+///
+/// let verifier = WebhookVerifier::new(&WebhookSecret::from("whsec_123456789"));
+///
+/// let webhooks = warp::post()
+///     .and(warp::path("webhooks"))
+///     .and(webhook_filter::<Event>(verifier))
+///     .map(|WorkOsWebhook(event): WorkOsWebhook<Event>| {
+///         // Handle the event.
+///         warp::reply()
+///     })
+///     .recover(recover_webhook_rejection);
+/// ```
+pub fn webhook_filter<T>(
+    verifier: WebhookVerifier,
+) -> impl Filter<Extract = (WorkOsWebhook<T>,), Error = Rejection> + Clone
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    filters::header::optional::<String>(WORKOS_SIGNATURE_HEADER)
+        .and(filters::body::content_length_limit(
+            verifier.body_limit as u64,
+        ))
+        .and(filters::body::bytes())
+        .and_then(move |signature_header: Option<String>, body: Bytes| {
+            let verifier = verifier.clone();
+
+            async move {
+                extract(&verifier, signature_header, body)
+                    .await
+                    .map_err(warp::reject::custom)
+            }
+        })
+}
+
+async fn extract<T>(
+    verifier: &WebhookVerifier,
+    signature_header: Option<String>,
+    body: Bytes,
+) -> Result<WorkOsWebhook<T>, WorkOsWebhookRejection>
+where
+    T: DeserializeOwned,
+{
+    let signature_header =
+        signature_header.ok_or(WorkOsWebhookRejection::MissingSignatureHeader)?;
+    let payload = std::str::from_utf8(&body).map_err(|_| WorkOsWebhookRejection::InvalidBody)?;
+
+    verifier.verify(payload, &signature_header).await?;
+
+    Ok(WorkOsWebhook(serde_json::from_str(payload)?))
+}
+
+/// Turns a [`WorkOsWebhookRejection`] into a response with the appropriate status code.
+///
+/// Other rejections are passed through unchanged.
+pub async fn recover_webhook_rejection(rejection: Rejection) -> Result<Response, Rejection> {
+    match rejection.find::<WorkOsWebhookRejection>() {
+        Some(webhook_rejection) => {
+            let status = StatusCode::from_u16(webhook_rejection.status_code())
+                .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+            Ok(warp::reply::with_status(webhook_rejection.to_string(), status).into_response())
+        }
+        None => Err(rejection),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use matches::assert_matches;
+    use serde_json::json;
+
+    use crate::events::{Event, EventData};
+    use crate::webhooks::{SignedWebhook, WebhookSecret};
+
+    use crate::webhooks::testing::payload;
+
+    use super::*;
+
+    fn request(payload: String, secret: &str) -> warp::test::RequestBuilder {
+        let SignedWebhook {
+            signature_header,
+            body,
+        } = SignedWebhook::from_json(payload, &WebhookSecret::from(secret));
+
+        warp::test::request()
+            .method("POST")
+            .path("/webhooks")
+            .header(WORKOS_SIGNATURE_HEADER, signature_header)
+            .body(body)
+    }
+
+    fn filter() -> impl Filter<Extract = (WorkOsWebhook<Event>,), Error = Rejection> + Clone {
+        webhook_filter(WebhookVerifier::new(&WebhookSecret::from(
+            "whsec_123456789",
+        )))
+    }
+
+    #[tokio::test]
+    async fn it_extracts_a_verified_event() {
+        let WorkOsWebhook(event) = request(payload(), "whsec_123456789")
+            .filter(&filter())
+            .await
+            .unwrap();
+
+        assert_matches!(event.data, EventData::OrganizationDeleted(_))
+    }
+
+    #[tokio::test]
+    async fn it_rejects_an_invalid_signature_with_unauthorized() {
+        let response = request(payload(), "whsec_forged")
+            .reply(
+                &filter()
+                    .map(|_| warp::reply())
+                    .recover(recover_webhook_rejection),
+            )
+            .await;
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED)
+    }
+
+    #[tokio::test]
+    async fn it_rejects_a_body_over_the_limit_with_payload_too_large() {
+        let response = request(payload(), "whsec_123456789")
+            .reply(
+                &webhook_filter::<Event>(
+                    WebhookVerifier::new(&WebhookSecret::from("whsec_123456789")).body_limit(16),
+                )
+                .map(|_| warp::reply())
+                .recover(recover_webhook_rejection),
+            )
+            .await;
+
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE)
+    }
+
+    #[tokio::test]
+    async fn it_rejects_an_invalid_payload_with_bad_request() {
+        let response = request(json!({ "id": "event_123" }).to_string(), "whsec_123456789")
+            .reply(
+                &filter()
+                    .map(|_| warp::reply())
+                    .recover(recover_webhook_rejection),
+            )
+            .await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST)
+    }
+}
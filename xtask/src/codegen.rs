@@ -0,0 +1,829 @@
+//! Generates Rust types from the component schemas of an OpenAPI spec.
+//!
+//! The generated code follows the conventions of the crate: one module per schema, ID newtypes for objects with an
+//! `id`, `Timestamps` for `created_at` and `updated_at`, and `Option` for optional and nullable properties. Union
+//! types cannot be represented faithfully and are generated as `serde_json::Value`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use serde_json::{Map, Value};
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "box", "break", "const", "continue", "crate", "dyn", "else", "enum",
+    "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+    "mut", "pub", "ref", "return", "static", "struct", "super", "trait", "true", "type", "unsafe",
+    "use", "where", "while", "yield",
+];
+
+/// Generates a module for every component schema of the spec, and a `mod` module declaring them.
+///
+/// Returns the source of each module keyed by the module name.
+pub fn generate(spec: &Value) -> Result<BTreeMap<String, String>, String> {
+    let schemas = spec
+        .pointer("/components/schemas")
+        .and_then(Value::as_object)
+        .ok_or("the spec has no component schemas")?;
+
+    let generator = Generator::new(schemas);
+
+    let mut modules = schemas
+        .iter()
+        .map(|(name, schema)| generator.module(name, schema))
+        .collect::<Vec<_>>();
+
+    // A module can only derive `Eq` if none of the modules it references contain floating point numbers.
+    let mut has_float = modules
+        .iter()
+        .filter(|module| module.has_float)
+        .map(|module| module.schema.clone())
+        .collect::<BTreeSet<_>>();
+    loop {
+        let before = has_float.len();
+        for module in &modules {
+            if module.refs.iter().any(|schema| has_float.contains(schema)) {
+                has_float.insert(module.schema.clone());
+            }
+        }
+        if has_float.len() == before {
+            break;
+        }
+    }
+    for module in &mut modules {
+        module.has_float = has_float.contains(&module.schema);
+    }
+
+    let mut sources = modules
+        .iter()
+        .map(|module| (module.name.clone(), module.render(&generator)))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut index = String::new();
+    for module in &modules {
+        writeln!(index, "mod {};", module.name).unwrap();
+    }
+    writeln!(index).unwrap();
+    for module in &modules {
+        writeln!(index, "pub use {}::*;", module.name).unwrap();
+    }
+    sources.insert("mod".to_string(), index);
+
+    Ok(sources)
+}
+
+struct Generator<'a> {
+    schemas: &'a Map<String, Value>,
+}
+
+impl<'a> Generator<'a> {
+    fn new(schemas: &'a Map<String, Value>) -> Self {
+        Self { schemas }
+    }
+
+    /// The name of the ID newtype of a schema, if it is an object with a string `id` property.
+    fn id_type(&self, schema_name: &str) -> Option<String> {
+        let id = self.schemas.get(schema_name)?.pointer("/properties/id")?;
+        (id.get("type").and_then(Value::as_str) == Some("string"))
+            .then(|| format!("{}Id", type_name(schema_name)))
+    }
+
+    /// Finds the schema whose ID newtype should be used for a property like `organization_id`.
+    fn referenced_id(&self, property: &str) -> Option<(String, String)> {
+        let prefix = property
+            .strip_suffix("_ids")
+            .or_else(|| property.strip_suffix("_id"))?;
+        let name = type_name(prefix);
+
+        self.schemas
+            .keys()
+            .find(|schema| type_name(schema) == name)
+            .and_then(|schema| Some((schema.clone(), self.id_type(schema)?)))
+    }
+
+    fn module(&self, schema_name: &str, schema: &Value) -> Module {
+        let mut module = Module {
+            schema: schema_name.to_string(),
+            name: snake_case(&type_name(schema_name)),
+            ..Module::default()
+        };
+
+        let name = type_name(schema_name);
+        let doc = description(schema).unwrap_or_else(|| {
+            let mut doc = format!("{}.", with_article(&words(&module.name)));
+            doc[..1].make_ascii_uppercase();
+            doc
+        });
+
+        if let Some(values) = string_enum(schema) {
+            module.items.push(Item::Enum(EnumDef {
+                name,
+                doc: Some(doc),
+                values,
+            }));
+        } else if is_object(schema) {
+            let id_type = self.id_type(schema_name);
+            if let Some(id_type) = &id_type {
+                module.items.push(Item::Id(id_type.clone(), name.clone()));
+                module.uses_derive_more = true;
+            }
+            self.object(&mut module, &name, schema, id_type.as_deref(), doc);
+        } else {
+            let ty = self.rust_type(&mut module, schema, &name, "");
+            module.items.push(Item::Alias(AliasDef {
+                name,
+                doc: Some(doc),
+                ty,
+            }));
+        }
+
+        module
+    }
+
+    fn object(
+        &self,
+        module: &mut Module,
+        name: &str,
+        schema: &Value,
+        id_type: Option<&str>,
+        doc: String,
+    ) {
+        // Nested types are generated while resolving the fields, but are listed after the struct.
+        let position = module.items.len();
+
+        let empty = Map::new();
+        let properties = schema
+            .get("properties")
+            .and_then(Value::as_object)
+            .unwrap_or(&empty);
+        let required = schema
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|required| {
+                required
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        let is_timestamp = |property: &str| {
+            properties
+                .get(property)
+                .and_then(|schema| schema.get("format"))
+                .and_then(Value::as_str)
+                == Some("date-time")
+        };
+        let has_timestamps = is_timestamp("created_at") && is_timestamp("updated_at");
+
+        // The `id` is listed first, like in the hand-written types.
+        let mut property_names = properties.keys().map(String::as_str).collect::<Vec<_>>();
+        property_names.sort_by_key(|property| *property != "id");
+
+        let mut fields = vec![];
+        for property in property_names {
+            let property_schema = &properties[property];
+
+            // The crate does not model the constant `object` discriminator of API objects.
+            if property == "object" && string_enum(property_schema).is_some_and(|v| v.len() == 1) {
+                continue;
+            }
+
+            if has_timestamps && (property == "created_at" || property == "updated_at") {
+                continue;
+            }
+
+            let mut ty = match (property, id_type) {
+                ("id", Some(id_type)) => id_type.to_string(),
+                _ => match self.referenced_id(property) {
+                    Some((schema, id_type)) if is_string_or_strings(property_schema) => {
+                        if schema != module.schema {
+                            module.refs.insert(schema);
+                        }
+                        if property.ends_with("_ids") {
+                            format!("Vec<{id_type}>")
+                        } else {
+                            id_type
+                        }
+                    }
+                    _ => self.rust_type(module, property_schema, name, property),
+                },
+            };
+
+            if !required.contains(&property) || is_nullable(property_schema) {
+                ty = format!("Option<{ty}>");
+            }
+
+            let field_name = field_name(property);
+            fields.push(Field {
+                rename: (field_name.trim_start_matches("r#") != property)
+                    .then(|| property.to_string()),
+                doc: description(property_schema).unwrap_or_else(|| {
+                    format!(
+                        "The {} of the {}.",
+                        words(property),
+                        words(&snake_case(name))
+                    )
+                }),
+                name: field_name,
+                ty,
+                flatten: false,
+            });
+        }
+
+        if has_timestamps {
+            module.uses_timestamps = true;
+            fields.push(Field {
+                name: "timestamps".to_string(),
+                rename: None,
+                doc: format!("The timestamps for the {}.", words(&snake_case(name))),
+                ty: "Timestamps".to_string(),
+                flatten: true,
+            });
+        }
+
+        module.items.insert(
+            position,
+            Item::Struct(StructDef {
+                name: name.to_string(),
+                doc: Some(description(schema).unwrap_or(doc)),
+                fields,
+            }),
+        );
+    }
+
+    fn rust_type(&self, module: &mut Module, schema: &Value, parent: &str, field: &str) -> String {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            let schema_name = reference.rsplit('/').next().unwrap_or(reference);
+            if schema_name != module.schema {
+                module.refs.insert(schema_name.to_string());
+            }
+            return type_name(schema_name);
+        }
+
+        let variants = ["allOf", "oneOf", "anyOf"]
+            .iter()
+            .filter_map(|key| schema.get(*key).and_then(Value::as_array))
+            .flatten()
+            .filter(|variant| variant.get("type").and_then(Value::as_str) != Some("null"))
+            .collect::<Vec<_>>();
+        match variants.as_slice() {
+            [] => {}
+            [variant] => return self.rust_type(module, variant, parent, field),
+            _ => {
+                module.uses_value = true;
+                return "Value".to_string();
+            }
+        }
+
+        let nested_name = format!("{parent}{}", type_name(field));
+
+        match schema_type(schema) {
+            Some("string") => {
+                if let Some(values) = string_enum(schema).filter(|values| values.len() > 1) {
+                    module.items.push(Item::Enum(EnumDef {
+                        name: nested_name.clone(),
+                        doc: Some(format!(
+                            "The {} of the {}.",
+                            words(field),
+                            words(&snake_case(parent))
+                        )),
+                        values,
+                    }));
+                    return nested_name;
+                }
+
+                match schema.get("format").and_then(Value::as_str) {
+                    Some("date-time") => {
+                        module.uses_timestamp = true;
+                        "Timestamp".to_string()
+                    }
+                    _ => "String".to_string(),
+                }
+            }
+            Some("integer") => match schema.get("format").and_then(Value::as_str) {
+                Some("int32") => "i32".to_string(),
+                _ => "i64".to_string(),
+            },
+            Some("number") => {
+                module.has_float = true;
+                "f64".to_string()
+            }
+            Some("boolean") => "bool".to_string(),
+            Some("array") => {
+                let items = schema.get("items").unwrap_or(&Value::Null);
+                format!("Vec<{}>", self.rust_type(module, items, parent, field))
+            }
+            Some("object") | None if schema.get("properties").is_some() => {
+                let doc = format!(
+                    "The {} of the {}.",
+                    words(field),
+                    words(&snake_case(parent))
+                );
+                self.object(module, &nested_name, schema, None, doc);
+                nested_name
+            }
+            Some("object") | None => match schema.get("additionalProperties") {
+                Some(values) if values.is_object() => {
+                    module.uses_hash_map = true;
+                    format!(
+                        "HashMap<String, {}>",
+                        self.rust_type(module, values, parent, field)
+                    )
+                }
+                _ => {
+                    module.uses_value = true;
+                    "Value".to_string()
+                }
+            },
+            Some(_) => {
+                module.uses_value = true;
+                "Value".to_string()
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct Module {
+    schema: String,
+    name: String,
+    items: Vec<Item>,
+    refs: BTreeSet<String>,
+    has_float: bool,
+    uses_derive_more: bool,
+    uses_hash_map: bool,
+    uses_timestamp: bool,
+    uses_timestamps: bool,
+    uses_value: bool,
+}
+
+enum Item {
+    Id(String, String),
+    Struct(StructDef),
+    Enum(EnumDef),
+    Alias(AliasDef),
+}
+
+struct StructDef {
+    name: String,
+    doc: Option<String>,
+    fields: Vec<Field>,
+}
+
+struct Field {
+    name: String,
+    rename: Option<String>,
+    doc: String,
+    ty: String,
+    flatten: bool,
+}
+
+struct EnumDef {
+    name: String,
+    doc: Option<String>,
+    values: Vec<String>,
+}
+
+struct AliasDef {
+    name: String,
+    doc: Option<String>,
+    ty: String,
+}
+
+impl Module {
+    fn render(&self, generator: &Generator) -> String {
+        let mut source = String::new();
+
+        if self.uses_hash_map {
+            writeln!(source, "use std::collections::HashMap;\n").unwrap();
+        }
+        if self.uses_derive_more {
+            writeln!(source, "use derive_more::{{Deref, Display, From}};").unwrap();
+        }
+        writeln!(source, "use serde::{{Deserialize, Serialize}};").unwrap();
+        if self.uses_value {
+            writeln!(source, "use serde_json::Value;").unwrap();
+        }
+
+        let mut crate_uses = vec![];
+        if self.uses_timestamp {
+            crate_uses.push("Timestamp");
+        }
+        if self.uses_timestamps {
+            crate_uses.push("Timestamps");
+        }
+
+        let super_uses = self
+            .refs
+            .iter()
+            .filter(|schema| generator.schemas.contains_key(*schema))
+            .flat_map(|schema| {
+                let mut names = vec![];
+                if self.refers_to_type(&type_name(schema)) {
+                    names.push(type_name(schema));
+                }
+                if let Some(id_type) = generator.id_type(schema)
+                    && self.refers_to_type(&id_type)
+                {
+                    names.push(id_type);
+                }
+                names
+            })
+            .collect::<Vec<_>>();
+
+        if !crate_uses.is_empty() || !super_uses.is_empty() {
+            writeln!(source).unwrap();
+        }
+        if !crate_uses.is_empty() {
+            writeln!(source, "use crate::{};", use_list(&crate_uses)).unwrap();
+        }
+        if !crate_uses.is_empty() && !super_uses.is_empty() {
+            writeln!(source).unwrap();
+        }
+        if !super_uses.is_empty() {
+            writeln!(source, "use super::{};", use_list(&super_uses)).unwrap();
+        }
+
+        let eq = if self.has_float { "" } else { " Eq," };
+
+        for item in &self.items {
+            writeln!(source).unwrap();
+
+            match item {
+                Item::Id(id_type, name) => {
+                    writeln!(source, "/// The ID of {} [`{name}`].", article(name)).unwrap();
+                    writeln!(
+                        source,
+                        "#[derive(Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]"
+                    )
+                    .unwrap();
+                    writeln!(source, "#[from(forward)]").unwrap();
+                    writeln!(source, "pub struct {id_type}(String);").unwrap();
+                }
+                Item::Struct(def) => {
+                    write_doc(&mut source, def.doc.as_deref(), "");
+                    writeln!(
+                        source,
+                        "#[derive(Clone, Debug, PartialEq,{eq} Serialize, Deserialize)]"
+                    )
+                    .unwrap();
+                    writeln!(source, "pub struct {} {{", def.name).unwrap();
+                    for (index, field) in def.fields.iter().enumerate() {
+                        if index > 0 {
+                            writeln!(source).unwrap();
+                        }
+                        write_doc(&mut source, Some(&field.doc), "    ");
+                        if field.flatten {
+                            writeln!(source, "    #[serde(flatten)]").unwrap();
+                        }
+                        if let Some(rename) = &field.rename {
+                            writeln!(source, "    #[serde(rename = \"{rename}\")]").unwrap();
+                        }
+                        writeln!(source, "    pub {}: {},", field.name, field.ty).unwrap();
+                    }
+                    writeln!(source, "}}").unwrap();
+                }
+                Item::Enum(def) => {
+                    let variants = def
+                        .values
+                        .iter()
+                        .map(|value| (variant_name(value), value))
+                        .collect::<Vec<_>>();
+                    let snake_case_values = variants
+                        .iter()
+                        .all(|(variant, value)| snake_case(variant) == **value);
+
+                    write_doc(&mut source, def.doc.as_deref(), "");
+                    writeln!(
+                        source,
+                        "#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]"
+                    )
+                    .unwrap();
+                    if snake_case_values {
+                        writeln!(source, "#[serde(rename_all = \"snake_case\")]").unwrap();
+                    }
+                    writeln!(source, "pub enum {} {{", def.name).unwrap();
+                    for (index, (variant, value)) in variants.iter().enumerate() {
+                        if index > 0 {
+                            writeln!(source).unwrap();
+                        }
+                        writeln!(source, "    /// `{value}`").unwrap();
+                        if !snake_case_values {
+                            writeln!(source, "    #[serde(rename = \"{value}\")]").unwrap();
+                        }
+                        writeln!(source, "    {variant},").unwrap();
+                    }
+                    writeln!(source, "}}").unwrap();
+                }
+                Item::Alias(def) => {
+                    write_doc(&mut source, def.doc.as_deref(), "");
+                    writeln!(source, "pub type {} = {};", def.name, def.ty).unwrap();
+                }
+            }
+        }
+
+        source
+    }
+
+    fn refers_to_type(&self, name: &str) -> bool {
+        let contains = |ty: &str| {
+            ty.split(|c: char| !c.is_alphanumeric() && c != '_')
+                .any(|part| part == name)
+        };
+
+        self.items.iter().any(|item| match item {
+            Item::Struct(def) => def.fields.iter().any(|field| contains(&field.ty)),
+            Item::Alias(def) => contains(&def.ty),
+            Item::Id(..) | Item::Enum(_) => false,
+        })
+    }
+}
+
+fn use_list(names: &[impl AsRef<str>]) -> String {
+    match names {
+        [name] => name.as_ref().to_string(),
+        names => format!(
+            "{{{}}}",
+            names
+                .iter()
+                .map(AsRef::as_ref)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+fn write_doc(source: &mut String, doc: Option<&str>, indent: &str) {
+    let Some(doc) = doc else {
+        return;
+    };
+
+    for line in doc.trim().lines() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            writeln!(source, "{indent}///").unwrap();
+        } else {
+            writeln!(source, "{indent}/// {line}").unwrap();
+        }
+    }
+}
+
+fn description(schema: &Value) -> Option<String> {
+    schema
+        .get("description")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+}
+
+fn schema_type(schema: &Value) -> Option<&str> {
+    match schema.get("type")? {
+        Value::String(ty) => Some(ty),
+        // OpenAPI 3.1 expresses nullable types as `["string", "null"]`.
+        Value::Array(types) => types
+            .iter()
+            .filter_map(Value::as_str)
+            .find(|ty| *ty != "null"),
+        _ => None,
+    }
+}
+
+fn is_object(schema: &Value) -> bool {
+    schema.get("properties").is_some() && matches!(schema_type(schema), None | Some("object"))
+}
+
+fn is_nullable(schema: &Value) -> bool {
+    schema.get("nullable").and_then(Value::as_bool) == Some(true)
+        || schema
+            .get("type")
+            .and_then(Value::as_array)
+            .is_some_and(|types| types.iter().any(|ty| ty == "null"))
+        || ["oneOf", "anyOf"]
+            .iter()
+            .filter_map(|key| schema.get(*key).and_then(Value::as_array))
+            .flatten()
+            .any(|variant| variant.get("type").and_then(Value::as_str) == Some("null"))
+}
+
+fn is_string_or_strings(schema: &Value) -> bool {
+    match schema_type(schema) {
+        Some("string") => true,
+        Some("array") => schema
+            .get("items")
+            .is_some_and(|items| schema_type(items) == Some("string")),
+        _ => false,
+    }
+}
+
+fn string_enum(schema: &Value) -> Option<Vec<String>> {
+    if let Some(value) = schema.get("const").and_then(Value::as_str) {
+        return Some(vec![value.to_string()]);
+    }
+
+    schema
+        .get("enum")?
+        .as_array()?
+        .iter()
+        .filter(|value| !value.is_null())
+        .map(|value| value.as_str().map(str::to_string))
+        .collect()
+}
+
+/// Converts a schema or property name to a type name in PascalCase.
+fn type_name(name: &str) -> String {
+    name.split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn variant_name(value: &str) -> String {
+    let name = type_name(&value.to_lowercase().replace(['.', ':', '/'], "_"));
+    let name = if value.chars().any(char::is_uppercase) && !value.contains(['_', '-', ' ']) {
+        // Values like `GoogleOAuth` are already PascalCase.
+        type_name(value)
+    } else {
+        name
+    };
+
+    match name.chars().next() {
+        Some(first) if first.is_ascii_digit() => format!("V{name}"),
+        Some(_) => name,
+        None => "Empty".to_string(),
+    }
+}
+
+/// Converts a PascalCase or camelCase name to snake_case.
+fn snake_case(name: &str) -> String {
+    let mut snake_case = String::new();
+    let chars = name.chars().collect::<Vec<_>>();
+
+    for (index, c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let after_lowercase = index > 0 && !chars[index - 1].is_uppercase();
+            let before_lowercase = index > 0
+                && chars[index - 1].is_uppercase()
+                && chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+
+            if (after_lowercase || before_lowercase) && !snake_case.ends_with('_') {
+                snake_case.push('_');
+            }
+            snake_case.extend(c.to_lowercase());
+        } else if c.is_alphanumeric() {
+            snake_case.push(*c);
+        } else if !snake_case.ends_with('_') {
+            snake_case.push('_');
+        }
+    }
+
+    snake_case.trim_matches('_').to_string()
+}
+
+fn field_name(property: &str) -> String {
+    let name = snake_case(property);
+
+    if KEYWORDS.contains(&name.as_str()) {
+        format!("r#{name}")
+    } else {
+        name
+    }
+}
+
+fn article(word: &str) -> &'static str {
+    if word.starts_with(['A', 'E', 'I', 'O', 'U', 'a', 'e', 'i', 'o', 'u']) {
+        "an"
+    } else {
+        "a"
+    }
+}
+
+fn with_article(words: &str) -> String {
+    format!("{} {words}", article(words))
+}
+
+fn words(name: &str) -> String {
+    name.split('_')
+        .map(|word| if word == "id" { "ID" } else { word })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    fn spec() -> Value {
+        json!({
+            "openapi": "3.1.0",
+            "components": {
+                "schemas": {
+                    "Organization": {
+                        "type": "object",
+                        "description": "An organization.",
+                        "required": ["object", "id", "name", "created_at", "updated_at"],
+                        "properties": {
+                            "object": { "type": "string", "const": "organization" },
+                            "id": { "type": "string" },
+                            "name": { "type": "string", "description": "The name of the organization." },
+                            "external_id": { "type": ["string", "null"] },
+                            "created_at": { "type": "string", "format": "date-time" },
+                            "updated_at": { "type": "string", "format": "date-time" }
+                        }
+                    },
+                    "OrganizationMembership": {
+                        "type": "object",
+                        "required": ["id", "organization_id", "status", "role"],
+                        "properties": {
+                            "id": { "type": "string" },
+                            "organization_id": { "type": "string" },
+                            "status": { "type": "string", "enum": ["active", "inactive", "pending"] },
+                            "role": { "$ref": "#/components/schemas/RoleResponse" },
+                            "score": { "type": "number" }
+                        }
+                    },
+                    "RoleResponse": {
+                        "type": "object",
+                        "required": ["slug"],
+                        "properties": {
+                            "slug": { "type": "string" }
+                        }
+                    },
+                    "ConnectionType": {
+                        "type": "string",
+                        "enum": ["GoogleOAuth", "OktaSAML", "GenericOIDC"]
+                    }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn it_generates_a_struct_with_an_id_newtype_and_timestamps() {
+        let modules = generate(&spec()).unwrap();
+
+        assert_eq!(
+            modules["organization"],
+            r#"use derive_more::{Deref, Display, From};
+use serde::{Deserialize, Serialize};
+
+use crate::Timestamps;
+
+/// The ID of an [`Organization`].
+#[derive(Clone, Debug, Deref, Display, From, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[from(forward)]
+pub struct OrganizationId(String);
+
+/// An organization.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Organization {
+    /// The ID of the organization.
+    pub id: OrganizationId,
+
+    /// The external ID of the organization.
+    pub external_id: Option<String>,
+
+    /// The name of the organization.
+    pub name: String,
+
+    /// The timestamps for the organization.
+    #[serde(flatten)]
+    pub timestamps: Timestamps,
+}
+"#
+        )
+    }
+
+    #[test]
+    fn it_references_other_schemas_and_their_ids() {
+        let modules = generate(&spec()).unwrap();
+        let membership = &modules["organization_membership"];
+
+        assert!(membership.contains("use super::{OrganizationId, RoleResponse};"));
+        assert!(membership.contains("pub organization_id: OrganizationId,"));
+        assert!(membership.contains("pub role: RoleResponse,"));
+        assert!(membership.contains("pub status: OrganizationMembershipStatus,"));
+        assert!(membership.contains("#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]"));
+        assert!(membership.contains("pub score: Option<f64>,"))
+    }
+
+    #[test]
+    fn it_generates_enums_with_the_exact_wire_values() {
+        let modules = generate(&spec()).unwrap();
+
+        assert!(
+            modules["connection_type"]
+                .contains("    /// `OktaSAML`\n    #[serde(rename = \"OktaSAML\")]\n    OktaSAML,")
+        );
+        assert!(modules["organization_membership"].contains(
+            "#[serde(rename_all = \"snake_case\")]\npub enum OrganizationMembershipStatus {"
+        ));
+        assert_eq!(modules["mod"].lines().next(), Some("mod connection_type;"))
+    }
+}
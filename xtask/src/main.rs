@@ -0,0 +1,76 @@
+//! Development tasks for the WorkOS Rust SDK, run with `cargo xtask <task>`.
+
+mod codegen;
+
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, ExitCode};
+
+const USAGE: &str = "\
+Usage: cargo xtask <task>
+
+Tasks:
+    codegen <openapi.json> [--out <dir>]
+        Generates Rust types from the component schemas of a WorkOS OpenAPI spec.
+        The types are written to `target/codegen` by default, one module per schema,
+        so they can be compared with and copied into the modules of the crate.";
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    let result = match args.split_first() {
+        Some((task, args)) if task == "codegen" => codegen(args),
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn codegen(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut spec = None;
+    let mut out = PathBuf::from("target/codegen");
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--out" => out = args.next().ok_or("missing value for --out")?.into(),
+            _ if spec.is_none() => spec = Some(PathBuf::from(arg)),
+            _ => return Err(format!("unexpected argument: {arg}\n\n{USAGE}").into()),
+        }
+    }
+
+    let spec = spec.ok_or_else(|| format!("missing OpenAPI spec\n\n{USAGE}"))?;
+    let spec = serde_json::from_str(&fs::read_to_string(&spec)?)?;
+
+    let modules = codegen::generate(&spec)?;
+
+    fs::create_dir_all(&out)?;
+    for (name, source) in &modules {
+        fs::write(out.join(format!("{name}.rs")), source)?;
+    }
+
+    // Formatting is best effort, the generated code is valid without it.
+    let _ = Command::new("rustfmt")
+        .args(["--edition", "2024"])
+        .args(modules.keys().map(|name| out.join(format!("{name}.rs"))))
+        .status();
+
+    println!(
+        "Generated {} modules in {}",
+        modules.len() - 1,
+        out.display()
+    );
+
+    Ok(())
+}